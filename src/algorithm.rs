@@ -0,0 +1,313 @@
+use crate::ascon::AsconCore;
+use crate::blake2::Blake2bCore;
+use crate::md2::Md2Core;
+use crate::md4::Md4Core;
+use crate::md5::Md5Core;
+use crate::sha1::Sha1Core;
+use crate::sha2::{Sha256Core, Sha512Core};
+use crate::sha3::KeccakCore;
+use crate::tiger::TigerCore;
+use crate::{
+    ascon_hash256, blake2b, keccak_224, keccak_256, keccak_384, keccak_512, md2, md4, md5, sha1, sha2_224, sha2_256, sha2_384, sha2_512,
+    sha3_224, sha3_256, sha3_384, sha3_512, tiger, tiger2,
+};
+use core::fmt::Debug;
+use core::hash::Hash;
+
+/// Hash function with its metadata, to write code generic over the algorithm.
+///
+/// Implemented by the marker types such as [`Sha256`], which also provide the
+/// same `hash` as an inherent `const fn`.
+///
+/// # Examples
+/// ```
+/// use cthash::{HashAlgorithm, Sha256, Sha3_512};
+///
+/// fn tagged<H: HashAlgorithm>(data: &[u8]) -> (&'static str, H::Output) {
+///     (H::NAME, H::hash(data))
+/// }
+///
+/// assert_eq!(tagged::<Sha256>(b"data"), ("SHA-256", cthash::sha2_256(b"data")));
+/// assert_eq!(tagged::<Sha3_512>(b"data").1.len(), Sha3_512::OUTPUT_LEN);
+///
+/// // the inherent function is usable in constants
+/// const H: [u8; 32] = Sha256::hash(b"data");
+/// ```
+pub trait HashAlgorithm {
+    /// Length of the digest in bytes.
+    const OUTPUT_LEN: usize;
+
+    /// Length of the input block in bytes, e.g. the key block of HMAC.
+    const BLOCK_LEN: usize;
+
+    /// Name of the algorithm, e.g. `"SHA-256"`.
+    const NAME: &'static str;
+
+    /// DER encoding of the object identifier, with tag and length, as it
+    /// appears in an `AlgorithmIdentifier`, if one is assigned.
+    const OID: Option<&'static [u8]>;
+
+    /// Code of the algorithm in the multihash table, if it has one.
+    const MULTIHASH: Option<u64>;
+
+    /// Digest, always `[u8; Self::OUTPUT_LEN]`.
+    ///
+    /// Stable Rust cannot name an array sized by an associated constant in a
+    /// trait, hence the associated type.
+    type Output: Copy + Eq + Ord + Hash + Debug + AsRef<[u8]> + AsMut<[u8]> + for<'a> TryFrom<&'a [u8]>;
+
+    /// Compute the digest of `data`.
+    fn hash(data: &[u8]) -> Self::Output;
+}
+
+macro_rules! hasher {
+    (
+        $(#[$doc:meta])*
+        $name:ident / $hasher:ident, $str:literal, $hash:ident, $core:ty = $init:expr,
+        $output:literal $output_ty:ident, $block:literal $block_ty:ident,
+        $multihash:expr, $oid:expr
+    ) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl $name {
+            #[doc = concat!("Compute ", $str, " digest, same as [`", stringify!($hash), "`](crate::", stringify!($hash), ").")]
+            pub const fn hash(data: &[u8]) -> [u8; $output] {
+                $hash(data)
+            }
+        }
+
+        impl HashAlgorithm for $name {
+            const OUTPUT_LEN: usize = $output;
+            const BLOCK_LEN: usize = $block;
+            const NAME: &'static str = $str;
+            const OID: Option<&'static [u8]> = $oid;
+            const MULTIHASH: Option<u64> = $multihash;
+
+            type Output = [u8; $output];
+
+            fn hash(data: &[u8]) -> Self::Output {
+                $hash(data)
+            }
+        }
+
+        #[doc = concat!("Incremental ", $str, ", hashing input given in pieces like [`", stringify!($name), "::hash`] does in one go.")]
+        #[derive(Clone, Debug)]
+        pub struct $hasher {
+            core: $core,
+        }
+
+        impl $hasher {
+            #[doc = concat!("Start an empty ", $str, " computation.")]
+            pub const fn new() -> Self {
+                Self { core: $init }
+            }
+
+            /// Absorb more input.
+            pub const fn update(&mut self, data: &[u8]) {
+                self.core.update(data);
+            }
+
+            /// Digest of all the input absorbed so far.
+            pub const fn finalize(self) -> [u8; $output] {
+                self.core.finalize()
+            }
+        }
+
+        impl Default for $hasher {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::io::Write for $hasher {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.core.update(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "embedded-io")]
+        impl embedded_io::ErrorType for $hasher {
+            type Error = core::convert::Infallible;
+        }
+
+        #[cfg(feature = "embedded-io")]
+        impl embedded_io::Write for $hasher {
+            fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+                self.core.update(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl ::digest::HashMarker for $hasher {}
+
+        #[cfg(feature = "digest")]
+        impl ::digest::OutputSizeUser for $hasher {
+            type OutputSize = ::digest::consts::$output_ty;
+        }
+
+        #[cfg(feature = "digest")]
+        impl ::digest::core_api::BlockSizeUser for $hasher {
+            type BlockSize = ::digest::consts::$block_ty;
+        }
+
+        #[cfg(feature = "digest")]
+        impl ::digest::Update for $hasher {
+            fn update(&mut self, data: &[u8]) {
+                self.core.update(data);
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl ::digest::FixedOutput for $hasher {
+            fn finalize_into(self, out: &mut ::digest::Output<Self>) {
+                out.copy_from_slice(&self.finalize());
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl ::digest::Reset for $hasher {
+            fn reset(&mut self) {
+                *self = Self::new();
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl ::digest::FixedOutputReset for $hasher {
+            fn finalize_into_reset(&mut self, out: &mut ::digest::Output<Self>) {
+                out.copy_from_slice(&self.core.finalize::<$output>());
+                *self = Self::new();
+            }
+        }
+    };
+}
+
+hasher!(
+    /// MD2, broken, for legacy protocols only.
+    Md2 / Md2Hasher, "MD2", md2, Md2Core = Md2Core::new(), 16 U16, 16 U16,
+    None, Some(&[0x06, 0x08, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x02])
+);
+hasher!(
+    /// MD4, broken, for legacy protocols only.
+    Md4 / Md4Hasher, "MD4", md4, Md4Core = Md4Core::MD4, 16 U16, 64 U64,
+    Some(0xd4), Some(&[0x06, 0x08, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x04])
+);
+hasher!(
+    /// MD5, broken, for legacy protocols only.
+    Md5 / Md5Hasher, "MD5", md5, Md5Core = Md5Core::MD5, 16 U16, 64 U64,
+    Some(0xd5), Some(&[0x06, 0x08, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x05])
+);
+hasher!(
+    /// SHA-1, broken, for legacy protocols only.
+    Sha1 / Sha1Hasher, "SHA-1", sha1, Sha1Core = Sha1Core::SHA1, 20 U20, 64 U64,
+    Some(0x11), Some(&[0x06, 0x05, 0x2b, 0x0e, 0x03, 0x02, 0x1a])
+);
+hasher!(
+    /// SHA-224.
+    Sha224 / Sha224Hasher, "SHA-224", sha2_224, Sha256Core = Sha256Core::SHA224, 28 U28, 64 U64,
+    Some(0x1013), Some(&[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x04])
+);
+hasher!(
+    /// SHA-256.
+    ///
+    /// Input given in pieces goes through [`Sha256Hasher`], also in `const`
+    /// contexts, with the same result as the one-shot `hash`. The other
+    /// algorithms have their hashers too.
+    ///
+    /// # Examples
+    /// ```
+    /// use cthash::{Sha256, Sha256Hasher};
+    ///
+    /// const H: [u8; 32] = {
+    ///     let mut hasher = Sha256Hasher::new();
+    ///     hasher.update(b"da");
+    ///     hasher.update(b"ta");
+    ///     hasher.finalize()
+    /// };
+    /// assert_eq!(H, Sha256::hash(b"data"));
+    /// ```
+    Sha256 / Sha256Hasher, "SHA-256", sha2_256, Sha256Core = Sha256Core::SHA256, 32 U32, 64 U64,
+    Some(0x12), Some(&[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01])
+);
+hasher!(
+    /// SHA-384.
+    Sha384 / Sha384Hasher, "SHA-384", sha2_384, Sha512Core = Sha512Core::SHA384, 48 U48, 128 U128,
+    Some(0x20), Some(&[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02])
+);
+hasher!(
+    /// SHA-512.
+    Sha512 / Sha512Hasher, "SHA-512", sha2_512, Sha512Core = Sha512Core::SHA512, 64 U64, 128 U128,
+    Some(0x13), Some(&[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03])
+);
+hasher!(
+    /// SHA3-224.
+    Sha3_224 / Sha3_224Hasher, "SHA3-224", sha3_224, KeccakCore<144, 0x06> = KeccakCore::new(), 28 U28, 144 U144,
+    Some(0x17), Some(&[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x07])
+);
+hasher!(
+    /// SHA3-256.
+    Sha3_256 / Sha3_256Hasher, "SHA3-256", sha3_256, KeccakCore<136, 0x06> = KeccakCore::new(), 32 U32, 136 U136,
+    Some(0x16), Some(&[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x08])
+);
+hasher!(
+    /// SHA3-384.
+    Sha3_384 / Sha3_384Hasher, "SHA3-384", sha3_384, KeccakCore<104, 0x06> = KeccakCore::new(), 48 U48, 104 U104,
+    Some(0x15), Some(&[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x09])
+);
+hasher!(
+    /// SHA3-512.
+    Sha3_512 / Sha3_512Hasher, "SHA3-512", sha3_512, KeccakCore<72, 0x06> = KeccakCore::new(), 64 U64, 72 U72,
+    Some(0x14), Some(&[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0a])
+);
+hasher!(
+    /// Pre-NIST Keccak-224.
+    Keccak224 / Keccak224Hasher, "Keccak-224", keccak_224, KeccakCore<144, 0x01> = KeccakCore::new(), 28 U28, 144 U144,
+    Some(0x1a), None
+);
+hasher!(
+    /// Pre-NIST Keccak-256, as used in Ethereum.
+    Keccak256 / Keccak256Hasher, "Keccak-256", keccak_256, KeccakCore<136, 0x01> = KeccakCore::new(), 32 U32, 136 U136,
+    Some(0x1b), None
+);
+hasher!(
+    /// Pre-NIST Keccak-384.
+    Keccak384 / Keccak384Hasher, "Keccak-384", keccak_384, KeccakCore<104, 0x01> = KeccakCore::new(), 48 U48, 104 U104,
+    Some(0x1c), None
+);
+hasher!(
+    /// Pre-NIST Keccak-512.
+    Keccak512 / Keccak512Hasher, "Keccak-512", keccak_512, KeccakCore<72, 0x01> = KeccakCore::new(), 64 U64, 72 U72,
+    Some(0x1d), None
+);
+hasher!(
+    /// Tiger.
+    Tiger / TigerHasher, "Tiger", tiger, TigerCore = TigerCore::TIGER, 24 U24, 64 U64,
+    None, Some(&[0x06, 0x09, 0x2b, 0x06, 0x01, 0x04, 0x01, 0xda, 0x47, 0x0c, 0x02])
+);
+hasher!(
+    /// Tiger2, Tiger with the padding of MD4.
+    Tiger2 / Tiger2Hasher, "Tiger2", tiger2, TigerCore = TigerCore::TIGER2, 24 U24, 64 U64,
+    None, None
+);
+hasher!(
+    /// Ascon-Hash256.
+    AsconHash256 / AsconHash256Hasher, "Ascon-Hash256", ascon_hash256, AsconCore = AsconCore::HASH256, 32 U32, 8 U8,
+    None, None
+);
+hasher!(
+    /// BLAKE2b-512, unkeyed BLAKE2b with the 64-byte digest of `b2sum`.
+    Blake2b512 / Blake2b512Hasher, "BLAKE2b-512", blake2b, Blake2bCore = Blake2bCore::new(64), 64 U64, 128 U128,
+    Some(0xb240), Some(&[0x06, 0x0b, 0x2b, 0x06, 0x01, 0x04, 0x01, 0x8d, 0x3a, 0x0c, 0x02, 0x01, 0x10])
+);
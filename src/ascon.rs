@@ -0,0 +1,178 @@
+use crate::block_api::BlockBuffer;
+
+const ASCON_HASH256_IV: u64 = 0x0000080100cc0002;
+const ASCON_XOF128_IV: u64 = 0x0000080000cc0003;
+const ASCON_CXOF128_IV: u64 = 0x0000080000cc0004;
+
+/// Compute Ascon-Hash256 digest (NIST SP 800-232).
+/// # Examples
+/// ```
+/// use cthash::ascon_hash256;
+/// const H: [u8; 32] = ascon_hash256(b"data");
+/// ```
+#[inline]
+pub const fn ascon_hash256(input: &[u8]) -> [u8; 32] {
+    let mut state = init(ASCON_HASH256_IV);
+    absorb(&mut state, input);
+    squeeze(&mut state)
+}
+
+/// Compute `N` bytes of Ascon-XOF128 output (NIST SP 800-232).
+/// # Examples
+/// ```
+/// use cthash::ascon_xof128;
+/// const H: [u8; 64] = ascon_xof128(b"data");
+/// ```
+pub const fn ascon_xof128<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut state = init(ASCON_XOF128_IV);
+    absorb(&mut state, input);
+    squeeze(&mut state)
+}
+
+/// Compute `N` bytes of Ascon-CXOF128 output (NIST SP 800-232) with a
+/// customization string.
+///
+/// The customization string is limited to 256 bytes by the standard, which is
+/// checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::ascon_cxof128;
+/// const H: [u8; 32] = ascon_cxof128(b"data", b"sensor node");
+/// ```
+///
+/// A longer customization string fails compilation:
+///
+/// ```compile_fail
+/// const H: [u8; 32] = cthash::ascon_cxof128(b"data", &[0; 257]);
+/// ```
+pub const fn ascon_cxof128<const N: usize, const Z: usize>(input: &[u8], customization: &[u8; Z]) -> [u8; N] {
+    const { assert!(Z <= 256, "customization string must not exceed 256 bytes") }
+
+    let mut state = init(ASCON_CXOF128_IV);
+    // bit length of the customization string as its own block
+    state[0] ^= (Z as u64) * 8;
+    ascon_p12(&mut state);
+    absorb(&mut state, customization);
+    absorb(&mut state, input);
+    squeeze(&mut state)
+}
+
+/// Incremental state of Ascon-Hash256.
+#[derive(Clone, Debug)]
+pub(crate) struct AsconCore {
+    state: [u64; 5],
+    buffer: BlockBuffer<8>,
+}
+
+impl AsconCore {
+    pub(crate) const HASH256: Self = Self {
+        state: init(ASCON_HASH256_IV),
+        buffer: BlockBuffer::new(),
+    };
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let (block, data) = self.buffer.fill(data);
+        if let Some(block) = block {
+            self.state[0] ^= u64::from_le_bytes(*block);
+            ascon_p12(&mut self.state);
+        }
+
+        let (blocks, rem) = data.as_chunks::<8>();
+        let mut i = 0;
+        while i < blocks.len() {
+            self.state[0] ^= u64::from_le_bytes(blocks[i]);
+            ascon_p12(&mut self.state);
+            i += 1;
+        }
+        self.buffer.keep(rem);
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        let mut state = self.state;
+        absorb(&mut state, self.buffer.pending());
+        squeeze(&mut state)
+    }
+}
+
+#[inline(always)]
+const fn init(iv: u64) -> [u64; 5] {
+    let mut state = [iv, 0, 0, 0, 0];
+    ascon_p12(&mut state);
+    state
+}
+
+/// Absorb `input` with `0x01` padding into the 64-bit rate.
+#[inline(always)]
+const fn absorb(s: &mut [u64; 5], input: &[u8]) {
+    let (blocks, rem) = input.as_chunks::<8>();
+
+    let mut i = 0;
+    while i < blocks.len() {
+        s[0] ^= u64::from_le_bytes(blocks[i]);
+        ascon_p12(s);
+        i += 1;
+    }
+
+    let mut last = [0u8; 8];
+    last.split_at_mut(rem.len()).0.copy_from_slice(rem);
+    s[0] ^= u64::from_le_bytes(last) ^ 1 << (8 * rem.len());
+    ascon_p12(s);
+}
+
+#[inline(always)]
+const fn squeeze<const OUT: usize>(s: &mut [u64; 5]) -> [u8; OUT] {
+    let mut out = [0u8; OUT];
+    let (chunks, rem) = out.as_chunks_mut::<8>();
+
+    let mut i = 0;
+    while i < chunks.len() {
+        chunks[i] = s[0].to_le_bytes();
+        i += 1;
+        if i < chunks.len() || !rem.is_empty() {
+            ascon_p12(s);
+        }
+    }
+    if !rem.is_empty() {
+        let last = s[0].to_le_bytes();
+        rem.copy_from_slice(last.split_at(rem.len()).0);
+    }
+
+    out
+}
+
+/// Ascon-p\[12\] permutation.
+const fn ascon_p12(s: &mut [u64; 5]) {
+    let mut round = 0u64;
+    while round < 12 {
+        let [mut x0, mut x1, mut x2, mut x3, mut x4] = *s;
+
+        // round constant
+        x2 ^= 0xf0 - round * 0x0f;
+
+        // substitution layer
+        x0 ^= x4;
+        x4 ^= x3;
+        x2 ^= x1;
+        let t = [!x0 & x1, !x1 & x2, !x2 & x3, !x3 & x4, !x4 & x0];
+        x0 ^= t[1];
+        x1 ^= t[2];
+        x2 ^= t[3];
+        x3 ^= t[4];
+        x4 ^= t[0];
+        x1 ^= x0;
+        x0 ^= x4;
+        x3 ^= x2;
+        x2 = !x2;
+
+        // linear diffusion layer
+        *s = [
+            x0 ^ x0.rotate_right(19) ^ x0.rotate_right(28),
+            x1 ^ x1.rotate_right(61) ^ x1.rotate_right(39),
+            x2 ^ x2.rotate_right(1) ^ x2.rotate_right(6),
+            x3 ^ x3.rotate_right(10) ^ x3.rotate_right(17),
+            x4 ^ x4.rotate_right(7) ^ x4.rotate_right(41),
+        ];
+
+        round += 1;
+    }
+}
@@ -0,0 +1,733 @@
+//! Checksum tool on top of `cthash`, compatible with `md5sum`, `sha1sum`,
+//! `sha224sum`, `sha256sum`, `sha384sum`, `sha512sum`, `sha3sum` and `b2sum`.
+//!
+//! Invoked under one of these names, e.g. through a symlink, it computes the
+//! matching algorithm. Invoked as `cthash`, the algorithm is chosen with `-a`
+//! and defaults to SHA-256, and `cthash emit-digests` prints a Rust module of
+//! digest constants like [`cthash::build::emit_digests`].
+
+use cthash::{HashKind, hash_reader};
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, StdoutLock, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Programs emulated when invoked under their name.
+const PROGRAMS: [(&str, HashKind); 8] = [
+    ("md5sum", HashKind::Md5),
+    ("sha1sum", HashKind::Sha1),
+    ("sha224sum", HashKind::Sha224),
+    ("sha256sum", HashKind::Sha256),
+    ("sha384sum", HashKind::Sha384),
+    ("sha512sum", HashKind::Sha512),
+    ("sha3sum", HashKind::Sha3_224),
+    ("b2sum", HashKind::Blake2b512),
+];
+
+fn main() -> ExitCode {
+    let mut args = std::env::args_os().peekable();
+    let argv0 = args.next().unwrap_or_default();
+    let prog = Path::new(&argv0)
+        .file_stem()
+        .map_or("cthash".into(), |stem| stem.to_string_lossy().into_owned());
+
+    // the tools emulated under their own name have no subcommands
+    if PROGRAMS.iter().all(|(name, _)| *name != prog) && args.peek().is_some_and(|arg| arg == "emit-digests") {
+        args.next();
+        return emit_digests(&prog, args);
+    }
+
+    let opts = match Options::parse(&prog, args) {
+        Ok(Parsed::Run(opts)) => opts,
+        Ok(Parsed::Help) => {
+            print!("{}", help(&prog));
+            return ExitCode::SUCCESS;
+        }
+        Ok(Parsed::Version) => {
+            println!("{prog} (cthash) {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Err(msg) => {
+            eprintln!("{prog}: {msg}\nTry '{prog} --help' for more information.");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut cli = Cli {
+        prog,
+        out: BufWriter::new(io::stdout().lock()),
+    };
+    let result = if opts.check { cli.check_all(&opts) } else { cli.hash_all(&opts) };
+    match result.and_then(|ok| cli.out.flush().map(|()| ok)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{}: write error: {}", cli.prog, describe(&e));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// `emit-digests` subcommand, writing the module of
+/// [`cthash::build::write_digests`] to stdout or a file.
+fn emit_digests(prog: &str, args: impl Iterator<Item = OsString>) -> ExitCode {
+    let emit = match Emit::parse(prog, args) {
+        Ok(Some(emit)) => emit,
+        Ok(None) => {
+            print!("{}", emit_help(prog));
+            return ExitCode::SUCCESS;
+        }
+        Err(msg) => {
+            eprintln!("{prog}: {msg}\nTry '{prog} emit-digests --help' for more information.");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // nothing is written unless all the files could be hashed
+    let mut module = Vec::new();
+    if let Err(e) = cthash::build::write_digests(&mut module, &emit.files, &emit.kinds) {
+        eprintln!("{prog}: {e}");
+        return ExitCode::FAILURE;
+    }
+    let (name, result) = match &emit.output {
+        Some(path) => (quote(&path.to_string_lossy()).into_owned(), std::fs::write(path, &module)),
+        None => ("write error".into(), io::stdout().lock().write_all(&module)),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{prog}: {name}: {}", describe(&e));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+struct Emit {
+    kinds: Vec<HashKind>,
+    output: Option<OsString>,
+    files: Vec<OsString>,
+}
+
+impl Emit {
+    /// Options of `emit-digests`, `None` for `--help`.
+    fn parse(prog: &str, mut args: impl Iterator<Item = OsString>) -> Result<Option<Self>, String> {
+        let mut emit = Emit {
+            kinds: Vec::new(),
+            output: None,
+            files: Vec::new(),
+        };
+        let mut only_files = false;
+        while let Some(arg) = args.next() {
+            let option = match arg.to_str() {
+                Some(option) if !only_files && option.starts_with('-') && option != "-" => option,
+                _ => {
+                    emit.files.push(arg);
+                    continue;
+                }
+            };
+            // `--name[=value]` or `-x[value]`
+            let (name, inline) = match option.strip_prefix("--") {
+                Some(long) => long.split_once('=').map_or((long, None), |(name, value)| (name, Some(value))),
+                None => option
+                    .split_at_checked(2)
+                    .map_or((option, None), |(name, value)| (name, Some(value).filter(|v| !v.is_empty()))),
+            };
+            let mut value = || {
+                inline
+                    .map(OsString::from)
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("option '{option}' requires an argument"))
+            };
+
+            match name {
+                "" => only_files = true,
+                "-a" | "algorithm" => emit.kinds.push(parse_algorithm(prog, &value()?)?),
+                "-o" | "output" => emit.output = Some(value()?),
+                "-h" | "help" => return Ok(None),
+                _ => return Err(format!("unrecognized option '{option}'")),
+            }
+        }
+
+        if emit.files.is_empty() {
+            return Err("missing file operand".into());
+        }
+        if emit.kinds.is_empty() {
+            emit.kinds.push(HashKind::Sha256);
+        }
+        Ok(Some(emit))
+    }
+}
+
+fn emit_help(prog: &str) -> String {
+    format!(
+        "\
+Usage: {prog} emit-digests [OPTION]... FILE...
+Print a Rust module of `pub const` digests of the FILEs, named after the
+file and the algorithm, e.g. LOGO_SHA256 for assets/logo.png.
+
+  -a, --algorithm=NAME  hash function, may be repeated (default sha256)
+  -o, --output=FILE     write the module to FILE instead of standard output
+  -h, --help            display this help and exit
+"
+    )
+}
+
+fn help(prog: &str) -> String {
+    let algorithms: Vec<&str> = HashKind::ALL.iter().map(|kind| kind.name()).collect();
+    format!(
+        "\
+Usage: {prog} [OPTION]... [FILE]...
+  or:  {prog} emit-digests [OPTION]... FILE...
+Print or check checksums.
+
+With no FILE, or when FILE is -, read standard input.
+
+  -a, --algorithm=NAME  hash function, one of {} (default sha256;
+                          224, 256, 384 or 512 for sha3sum)
+  -b, --binary          read in binary mode
+  -c, --check           read checksums from the FILEs and check them
+      --tag             create a BSD-style checksum
+  -t, --text            read in text mode (default)
+  -z, --zero            end each output line with NUL, not newline,
+                          and disable file name escaping
+
+The following five options are useful only when verifying checksums:
+      --ignore-missing  don't fail or report status for missing files
+      --quiet           don't print OK for each successfully verified file
+      --status          don't output anything, status code shows success
+      --strict          exit non-zero for improperly formatted checksum lines
+  -w, --warn            warn about improperly formatted checksum lines
+
+  -h, --help            display this help and exit
+      --version         output version information and exit
+",
+        algorithms.join(", ")
+    )
+}
+
+#[derive(Debug)]
+struct Options {
+    kind: HashKind,
+    /// Whether `kind` was chosen by the program name or `-a`, otherwise
+    /// tagged checksum lines of any algorithm are verified.
+    explicit: bool,
+    binary: bool,
+    check: bool,
+    tag: bool,
+    zero: bool,
+    ignore_missing: bool,
+    strict: bool,
+    report: Report,
+    files: Vec<OsString>,
+}
+
+/// Output of `-c`, set by `--warn`, `--quiet` and `--status`, the last one
+/// winning as in coreutils.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Report {
+    Normal,
+    /// Also warn about improperly formatted lines.
+    Warn,
+    /// Only failures.
+    Quiet,
+    /// Nothing, only the exit code.
+    Status,
+}
+
+enum Parsed {
+    Run(Options),
+    Help,
+    Version,
+}
+
+impl Options {
+    fn parse(prog: &str, args: impl Iterator<Item = OsString>) -> Result<Parsed, String> {
+        let program = PROGRAMS.iter().find(|(name, _)| *name == prog).map(|&(_, kind)| kind);
+        // only the generic tool and `sha3sum` take an algorithm
+        let takes_algorithm = program.is_none() || prog == "sha3sum";
+        let mut opts = Options {
+            kind: program.unwrap_or(HashKind::Sha256),
+            explicit: program.is_some(),
+            binary: false,
+            check: false,
+            tag: false,
+            zero: false,
+            ignore_missing: false,
+            strict: false,
+            report: Report::Normal,
+            files: Vec::new(),
+        };
+        let mut text = false;
+
+        let mut args = args;
+        let mut only_files = false;
+        while let Some(arg) = args.next() {
+            let option = match arg.to_str() {
+                Some(option) if !only_files && option.starts_with('-') && option != "-" => option,
+                _ => {
+                    opts.files.push(arg);
+                    continue;
+                }
+            };
+
+            if let Some(long) = option.strip_prefix("--") {
+                let (name, value) = match long.split_once('=') {
+                    Some((name, value)) => (name, Some(value)),
+                    None => (long, None),
+                };
+                if name == "algorithm" && takes_algorithm {
+                    let value = match value {
+                        Some(value) => value.into(),
+                        None => args.next().ok_or("option '--algorithm' requires an argument")?,
+                    };
+                    opts.kind = parse_algorithm(prog, &value)?;
+                    opts.explicit = true;
+                    continue;
+                }
+                if value.is_some() {
+                    return Err(format!("option '--{name}' doesn't allow an argument"));
+                }
+                match name {
+                    "" => only_files = true,
+                    "binary" => opts.binary = true,
+                    "check" => opts.check = true,
+                    "tag" => opts.tag = true,
+                    "text" => text = true,
+                    "zero" => opts.zero = true,
+                    "ignore-missing" => opts.ignore_missing = true,
+                    "quiet" => opts.report = Report::Quiet,
+                    "status" => opts.report = Report::Status,
+                    "strict" => opts.strict = true,
+                    "warn" => opts.report = Report::Warn,
+                    "help" => return Ok(Parsed::Help),
+                    "version" => return Ok(Parsed::Version),
+                    _ => return Err(format!("unrecognized option '{option}'")),
+                }
+                continue;
+            }
+
+            for (i, flag) in option.char_indices().skip(1) {
+                match flag {
+                    'a' if takes_algorithm => {
+                        let value = match option.get(i + 1..) {
+                            Some(value) if !value.is_empty() => value.into(),
+                            _ => args.next().ok_or("option requires an argument -- 'a'")?,
+                        };
+                        opts.kind = parse_algorithm(prog, &value)?;
+                        opts.explicit = true;
+                        break;
+                    }
+                    'b' => opts.binary = true,
+                    'c' => opts.check = true,
+                    't' => text = true,
+                    'z' => opts.zero = true,
+                    'w' => opts.report = Report::Warn,
+                    'h' => return Ok(Parsed::Help),
+                    _ => return Err(format!("invalid option -- '{flag}'")),
+                }
+            }
+        }
+
+        if opts.files.is_empty() {
+            opts.files.push("-".into());
+        }
+
+        if opts.check {
+            if opts.tag {
+                return Err("the --tag option is meaningless when verifying checksums".into());
+            }
+            if opts.binary || text {
+                return Err("the --binary and --text options are meaningless when verifying checksums".into());
+            }
+            if opts.zero {
+                return Err("the --zero option is not supported when verifying checksums".into());
+            }
+        } else {
+            if opts.tag && text {
+                return Err("--tag does not support --text mode".into());
+            }
+            for (set, name) in [
+                (opts.ignore_missing, "--ignore-missing"),
+                (opts.report == Report::Quiet, "--quiet"),
+                (opts.report == Report::Status, "--status"),
+                (opts.strict, "--strict"),
+                (opts.report == Report::Warn, "--warn"),
+            ] {
+                if set {
+                    return Err(format!("the {name} option is meaningful only when verifying checksums"));
+                }
+            }
+        }
+        // both modes read the same bytes, they only differ in the marker printed
+        opts.binary &= !text;
+
+        Ok(Parsed::Run(opts))
+    }
+}
+
+fn parse_algorithm(prog: &str, value: &OsStr) -> Result<HashKind, String> {
+    let value = value.to_string_lossy();
+    if prog == "sha3sum" {
+        return match &*value {
+            "224" => Ok(HashKind::Sha3_224),
+            "256" => Ok(HashKind::Sha3_256),
+            "384" => Ok(HashKind::Sha3_384),
+            "512" => Ok(HashKind::Sha3_512),
+            _ => Err(format!("invalid algorithm '{value}'")),
+        };
+    }
+    value.parse().map_err(|_| format!("invalid algorithm '{value}'"))
+}
+
+/// Algorithm name of the BSD-style `--tag` lines, as printed by coreutils.
+fn tag(kind: HashKind) -> Cow<'static, str> {
+    match kind {
+        HashKind::Md5 => "MD5".into(),
+        HashKind::Sha1 => "SHA1".into(),
+        HashKind::Sha224 => "SHA224".into(),
+        HashKind::Sha256 => "SHA256".into(),
+        HashKind::Sha384 => "SHA384".into(),
+        HashKind::Sha512 => "SHA512".into(),
+        HashKind::Sha3_224 => "SHA3-224".into(),
+        HashKind::Sha3_256 => "SHA3-256".into(),
+        HashKind::Sha3_384 => "SHA3-384".into(),
+        HashKind::Sha3_512 => "SHA3-512".into(),
+        HashKind::Blake2b512 => "BLAKE2b".into(),
+        kind => kind.name().to_uppercase().into(),
+    }
+}
+
+fn tag_kind(tag: &[u8]) -> Option<HashKind> {
+    if tag == b"BLAKE2b-512" {
+        return Some(HashKind::Blake2b512);
+    }
+    HashKind::ALL.into_iter().find(|&kind| self::tag(kind).as_bytes() == tag)
+}
+
+/// Checksum line of a `-c` list.
+struct Entry<'a> {
+    kind: HashKind,
+    hex: &'a [u8],
+    name: Vec<u8>,
+}
+
+impl<'a> Entry<'a> {
+    /// Parse a GNU `<hex>  <name>` or BSD `<TAG> (<name>) = <hex>` line,
+    /// the name prefixed by a backslash if it is escaped.
+    fn parse(line: &'a [u8], opts: &Options) -> Option<Self> {
+        let line = line.trim_ascii_start();
+        let (escaped, line) = match line.strip_prefix(b"\\") {
+            Some(line) => (true, line),
+            None => (false, line),
+        };
+        let (kind, hex, name) = Self::parse_tagged(line, opts).or_else(|| Self::parse_untagged(line, opts.kind))?;
+        let name = if escaped { unescape(name)? } else { name.to_vec() };
+        if name.is_empty() {
+            return None;
+        }
+        Some(Self { kind, hex, name })
+    }
+
+    fn parse_tagged(line: &'a [u8], opts: &Options) -> Option<(HashKind, &'a [u8], &'a [u8])> {
+        let open = line.windows(2).position(|w| w == b" (")?;
+        let close = line.windows(4).rposition(|w| w == b") = ")?;
+        let kind = tag_kind(line.get(..open)?)?;
+        if opts.explicit && kind != opts.kind {
+            return None;
+        }
+        let name = line.get(open + 2..close)?;
+        let hex = line.get(close + 4..)?;
+        is_digest(hex, kind).then_some((kind, hex, name))
+    }
+
+    fn parse_untagged(line: &[u8], kind: HashKind) -> Option<(HashKind, &[u8], &[u8])> {
+        let (hex, rest) = line.split_at_checked(2 * kind.output_len())?;
+        match rest {
+            [b' ', b' ' | b'*', name @ ..] if is_digest(hex, kind) => Some((kind, hex, name)),
+            _ => None,
+        }
+    }
+}
+
+fn is_digest(hex: &[u8], kind: HashKind) -> bool {
+    hex.len() == 2 * kind.output_len() && hex.iter().all(u8::is_ascii_hexdigit)
+}
+
+/// Escape a file name holding a backslash or a line break as coreutils does,
+/// telling whether the line must start with a backslash.
+fn escape(name: &[u8]) -> (bool, Cow<'_, [u8]>) {
+    if !name.iter().any(|b| matches!(b, b'\\' | b'\n' | b'\r')) {
+        return (false, Cow::Borrowed(name));
+    }
+    let mut escaped = Vec::with_capacity(name.len() + 2);
+    for &b in name {
+        match b {
+            b'\\' => escaped.extend_from_slice(b"\\\\"),
+            b'\n' => escaped.extend_from_slice(b"\\n"),
+            b'\r' => escaped.extend_from_slice(b"\\r"),
+            b => escaped.push(b),
+        }
+    }
+    (true, Cow::Owned(escaped))
+}
+
+fn unescape(name: &[u8]) -> Option<Vec<u8>> {
+    let mut unescaped = Vec::with_capacity(name.len());
+    let mut bytes = name.iter();
+    while let Some(&b) = bytes.next() {
+        unescaped.push(match b {
+            b'\\' => match bytes.next()? {
+                b'\\' => b'\\',
+                b'n' => b'\n',
+                b'r' => b'\r',
+                _ => return None,
+            },
+            b => b,
+        });
+    }
+    Some(unescaped)
+}
+
+#[cfg(unix)]
+fn os_bytes(name: &OsStr) -> Cow<'_, [u8]> {
+    use std::os::unix::ffi::OsStrExt;
+    Cow::Borrowed(name.as_bytes())
+}
+
+#[cfg(not(unix))]
+fn os_bytes(name: &OsStr) -> Cow<'_, [u8]> {
+    Cow::Owned(name.to_string_lossy().into_owned().into_bytes())
+}
+
+#[cfg(unix)]
+fn os_string(name: &[u8]) -> OsString {
+    use std::os::unix::ffi::OsStrExt;
+    OsStr::from_bytes(name).to_owned()
+}
+
+#[cfg(not(unix))]
+fn os_string(name: &[u8]) -> OsString {
+    String::from_utf8_lossy(name).into_owned().into()
+}
+
+/// File name of a diagnostic, quoted for the shell when needed like the
+/// `quotef` of coreutils, e.g. `'b c.txt'` or `'x'$'\n''y'`.
+fn quote(name: &str) -> Cow<'_, str> {
+    let safe = |c: char| c.is_alphanumeric() || "%+,-./:=@_^".contains(c);
+    if !name.is_empty() && name.chars().all(safe) {
+        return Cow::Borrowed(name);
+    }
+    let special = |c: char| matches!(c, '"' | '$' | '`' | '\\') || c.is_control();
+    if name.contains('\'') && !name.contains(special) {
+        return Cow::Owned(format!("\"{name}\""));
+    }
+
+    let mut quoted = String::from("'");
+    for c in name.chars() {
+        match c {
+            '\'' => quoted.push_str("'\\''"),
+            '\n' => quoted.push_str("'$'\\n''"),
+            '\r' => quoted.push_str("'$'\\r''"),
+            '\t' => quoted.push_str("'$'\\t''"),
+            c if c.is_control() => quoted.push_str(&format!("'$'\\{:03o}''", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    Cow::Owned(quoted)
+}
+
+/// Error message without the `(os error N)` suffix, as coreutils prints it.
+fn describe(e: &io::Error) -> String {
+    match e.kind() {
+        io::ErrorKind::NotFound => "No such file or directory".into(),
+        io::ErrorKind::PermissionDenied => "Permission denied".into(),
+        io::ErrorKind::IsADirectory => "Is a directory".into(),
+        io::ErrorKind::BrokenPipe => "Broken pipe".into(),
+        _ => e.to_string(),
+    }
+}
+
+fn digest_file(kind: HashKind, name: &OsStr) -> io::Result<cthash::DynDigest> {
+    if name == "-" {
+        hash_reader(kind, io::stdin().lock())
+    } else {
+        hash_reader(kind, File::open(name)?)
+    }
+}
+
+/// Lines of a `-c` list, by outcome.
+#[derive(Default)]
+struct Counts {
+    formatted: usize,
+    improper: usize,
+    matched: usize,
+    mismatched: usize,
+    unreadable: usize,
+}
+
+struct Cli {
+    prog: String,
+    out: BufWriter<StdoutLock<'static>>,
+}
+
+impl Cli {
+    /// Print a diagnostic, after what was printed so far on stdout.
+    fn error(&mut self, msg: fmt::Arguments<'_>) -> io::Result<()> {
+        self.out.flush()?;
+        eprintln!("{}: {msg}", self.prog);
+        Ok(())
+    }
+
+    fn hash_all(&mut self, opts: &Options) -> io::Result<bool> {
+        let mut ok = true;
+        for name in &opts.files {
+            match digest_file(opts.kind, name) {
+                Ok(digest) => {
+                    let name = os_bytes(name);
+                    let (escaped, name) = if opts.zero { (false, name) } else { escape(&name) };
+                    if escaped {
+                        self.out.write_all(b"\\")?;
+                    }
+                    if opts.tag {
+                        write!(self.out, "{} (", tag(opts.kind))?;
+                        self.out.write_all(&name)?;
+                        write!(self.out, ") = {digest}")?;
+                    } else {
+                        write!(self.out, "{digest} {}", if opts.binary { '*' } else { ' ' })?;
+                        self.out.write_all(&name)?;
+                    }
+                    self.out.write_all(if opts.zero { b"\0" } else { b"\n" })?;
+                }
+                Err(e) => {
+                    self.error(format_args!("{}: {}", quote(&name.to_string_lossy()), describe(&e)))?;
+                    ok = false;
+                }
+            }
+        }
+        Ok(ok)
+    }
+
+    fn check_all(&mut self, opts: &Options) -> io::Result<bool> {
+        let mut ok = true;
+        for list in &opts.files {
+            ok &= self.check_list(opts, list)?;
+        }
+        Ok(ok)
+    }
+
+    fn check_list(&mut self, opts: &Options, list: &OsStr) -> io::Result<bool> {
+        let list_name = if list == "-" {
+            quote("standard input")
+        } else {
+            quote(&list.to_string_lossy()).into_owned().into()
+        };
+        let mut reader: Box<dyn BufRead> = if list == "-" {
+            Box::new(io::stdin().lock())
+        } else {
+            match File::open(list) {
+                Ok(file) => Box::new(BufReader::new(file)),
+                Err(e) => {
+                    self.error(format_args!("{list_name}: {}", describe(&e)))?;
+                    return Ok(false);
+                }
+            }
+        };
+
+        let mut counts = Counts::default();
+        let mut line = Vec::new();
+        for line_number in 1.. {
+            line.clear();
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) => {
+                    self.error(format_args!("{list_name}: {}", describe(&e)))?;
+                    return Ok(false);
+                }
+            }
+            let text = line.strip_suffix(b"\n").unwrap_or(&line);
+            let text = text.strip_suffix(b"\r").unwrap_or(text);
+            if text.starts_with(b"#") {
+                continue;
+            }
+
+            let Some(entry) = Entry::parse(text, opts) else {
+                counts.improper += 1;
+                if opts.report == Report::Warn {
+                    let tag = tag(opts.kind);
+                    self.error(format_args!("{list_name}: {line_number}: improperly formatted {tag} checksum line"))?;
+                }
+                continue;
+            };
+            counts.formatted += 1;
+
+            match digest_file(entry.kind, &os_string(&entry.name)) {
+                Err(e) if opts.ignore_missing && e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => {
+                    counts.unreadable += 1;
+                    let name = String::from_utf8_lossy(&entry.name);
+                    self.error(format_args!("{}: {}", quote(&name), describe(&e)))?;
+                    if opts.report != Report::Status {
+                        self.print_status(&entry.name, "FAILED open or read")?;
+                    }
+                }
+                Ok(digest) if digest.to_string().as_bytes().eq_ignore_ascii_case(entry.hex) => {
+                    counts.matched += 1;
+                    if matches!(opts.report, Report::Normal | Report::Warn) {
+                        self.print_status(&entry.name, "OK")?;
+                    }
+                }
+                Ok(_) => {
+                    counts.mismatched += 1;
+                    if opts.report != Report::Status {
+                        self.print_status(&entry.name, "FAILED")?;
+                    }
+                }
+            }
+        }
+
+        if counts.formatted == 0 {
+            self.error(format_args!("{list_name}: no properly formatted checksum lines found"))?;
+            return Ok(false);
+        }
+        if opts.report != Report::Status {
+            let plural = |n: usize, one: &'static str, many: &'static str| if n == 1 { one } else { many };
+            if counts.improper > 0 {
+                let n = counts.improper;
+                let what = plural(n, "line is", "lines are");
+                self.error(format_args!("WARNING: {n} {what} improperly formatted"))?;
+            }
+            if counts.unreadable > 0 {
+                let n = counts.unreadable;
+                let what = plural(n, "listed file", "listed files");
+                self.error(format_args!("WARNING: {n} {what} could not be read"))?;
+            }
+            if counts.mismatched > 0 {
+                let n = counts.mismatched;
+                let what = plural(n, "computed checksum", "computed checksums");
+                self.error(format_args!("WARNING: {n} {what} did NOT match"))?;
+            }
+        }
+        if opts.ignore_missing && counts.matched + counts.mismatched + counts.unreadable == 0 {
+            if opts.report != Report::Status {
+                self.error(format_args!("{list_name}: no file was verified"))?;
+            }
+            return Ok(false);
+        }
+
+        Ok(counts.mismatched == 0 && counts.unreadable == 0 && (!opts.strict || counts.improper == 0))
+    }
+
+    fn print_status(&mut self, name: &[u8], status: &str) -> io::Result<()> {
+        let (escaped, name) = escape(name);
+        if escaped {
+            self.out.write_all(b"\\")?;
+        }
+        self.out.write_all(&name)?;
+        writeln!(self.out, ": {status}")
+    }
+}
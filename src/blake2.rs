@@ -0,0 +1,186 @@
+/// Compute BLAKE2b digest of `N` bytes (RFC 7693), unkeyed.
+///
+/// `N` must be between 1 and 64 bytes, which is checked at compile time.
+/// `b2sum` prints the 64-byte digest.
+/// # Examples
+/// ```
+/// use cthash::blake2b;
+/// const H: [u8; 64] = blake2b(b"data");
+/// const H256: [u8; 32] = blake2b(b"data");
+/// ```
+pub const fn blake2b<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N > 0 && N <= 64, "digest size must be between 1 and 64 bytes") }
+
+    let mut core = Blake2bCore::new(N as u8);
+    core.update(input);
+    core.finalize()
+}
+
+/// Same as the SHA-512 initial hash value.
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// Message word permutations of the rounds, the last two repeat the first two.
+const SIGMA: [[usize; 16]; 12] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+/// Incremental state of BLAKE2b, the digest length being part of the
+/// parameter block hashed into the initial state.
+///
+/// Unlike Merkle-Damgård hashes, the last block is compressed with a final
+/// flag, so a full block stays pending until more input arrives.
+#[derive(Clone, Debug)]
+pub(crate) struct Blake2bCore {
+    state: [u64; 8],
+    block: [u8; 128],
+    pos: usize,
+    counter: u128,
+}
+
+impl Blake2bCore {
+    /// `out_len` must match the `OUT` given to `finalize`.
+    pub(crate) const fn new(out_len: u8) -> Self {
+        let mut state = IV;
+        // parameter block: digest length, no key, fanout and depth of 1
+        state[0] ^= 0x01010000 ^ out_len as u64;
+        Self {
+            state,
+            block: [0; 128],
+            pos: 0,
+            counter: 0,
+        }
+    }
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let mut data = data;
+        let mut pos = if self.pos < 128 { self.pos } else { 128 };
+        while !data.is_empty() {
+            if pos >= 128 {
+                self.counter = self.counter.wrapping_add(128);
+                compress(&mut self.state, &self.block, self.counter, false);
+                pos = 0;
+            }
+
+            let free = 128 - pos;
+            let taken = if data.len() < free { data.len() } else { free };
+            let (head, tail) = match data.split_at_checked(taken) {
+                Some(split) => split,
+                None => (data, &[] as &[u8]),
+            };
+            if let Some((_, dst)) = self.block.split_at_mut_checked(pos)
+                && let Some((dst, _)) = dst.split_at_mut_checked(head.len())
+            {
+                dst.copy_from_slice(head);
+            }
+            pos += taken;
+            data = tail;
+        }
+        self.pos = pos;
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        let mut state = self.state;
+        let mut block = self.block;
+        let pos = if self.pos < 128 { self.pos } else { 128 };
+        if let Some((_, tail)) = block.split_at_mut_checked(pos) {
+            let mut i = 0;
+            while i < tail.len() {
+                tail[i] = 0;
+                i += 1;
+            }
+        }
+        compress(&mut state, &block, self.counter.wrapping_add(pos as u128), true);
+
+        let mut bytes = [0u8; 64];
+        let (chunks, _) = bytes.as_chunks_mut::<8>();
+        let mut i = 0;
+        while i < 8 {
+            chunks[i] = state[i].to_le_bytes();
+            i += 1;
+        }
+        let mut out = [0u8; OUT];
+        let mut i = 0;
+        while i < OUT && i < 64 {
+            out[i] = bytes[i];
+            i += 1;
+        }
+        out
+    }
+}
+
+#[inline(always)]
+const fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+/// Compression function F of RFC 7693, `counter` being the number of bytes
+/// hashed including this block.
+const fn compress(state: &mut [u64; 8], block: &[u8; 128], counter: u128, last: bool) {
+    let mut m = [0u64; 16];
+    let (words, _) = block.as_chunks::<8>();
+    let mut i = 0;
+    while i < 16 {
+        m[i] = u64::from_le_bytes(words[i]);
+        i += 1;
+    }
+
+    let mut v = [0u64; 16];
+    let mut i = 0;
+    while i < 8 {
+        v[i] = state[i];
+        v[i + 8] = IV[i];
+        i += 1;
+    }
+    v[12] ^= counter as u64;
+    v[13] ^= (counter >> 64) as u64;
+    if last {
+        v[14] = !v[14];
+    }
+
+    let mut round = 0;
+    while round < 12 {
+        let s = &SIGMA[round];
+        g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+        g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+        g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+        g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+        g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+        g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+        g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+        g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        round += 1;
+    }
+
+    let mut i = 0;
+    while i < 8 {
+        state[i] ^= v[i] ^ v[i + 8];
+        i += 1;
+    }
+}
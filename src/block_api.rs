@@ -27,3 +27,143 @@ pub(crate) const fn eager_split_pad<'a, const BLOCK_SIZE: usize, const SFX_LEN:
 
     (blocks, pad_two_blocks)
 }
+
+/// Split a message of `bit_len` bits into whole bytes, the trailing partial
+/// byte and the number of message bits in it.
+///
+/// `bit_len` saturates at `8 * input.len()`: bits past the end of `input`
+/// don't exist, so the whole input is taken with no partial byte.
+#[inline(always)]
+pub(crate) const fn split_bits(input: &[u8], bit_len: usize) -> (&[u8], u8, u32) {
+    match input.split_at_checked(bit_len / 8) {
+        Some((bytes, [last, ..])) => (bytes, *last, (bit_len % 8) as u32),
+        _ => (input, 0, 0),
+    }
+}
+
+/// Like [`split_bits`], for hashes taking bits most significant first: the
+/// trailing message bits are merged with the `1` padding bit that follows
+/// them, and the message length in bits is returned instead.
+#[inline(always)]
+pub(crate) const fn msb_split_bits(input: &[u8], bit_len: usize) -> (&[u8], u8, u64) {
+    match input.split_at_checked(bit_len / 8) {
+        Some((bytes, [last, ..])) => {
+            let last_bits = (bit_len % 8) as u32;
+            (bytes, *last & !(0xff >> last_bits) | 0x80 >> last_bits, bit_len as u64)
+        }
+        _ => (input, 0x80, (input.len() as u64).wrapping_mul(8)),
+    }
+}
+
+/// Split a message of length `N` known at compile time into blocks, apply
+/// padding, delim, zeroes and suffix.
+///
+/// Unlike [`eager_split_pad`], whether the padding takes one or two blocks is
+/// a constant, so nested digests like `sha2_256(sha2_256(x))` pad the inner
+/// digest without any runtime length arithmetic.
+#[inline(always)]
+pub(crate) const fn fixed_split_pad<'a, const N: usize, const BLOCK_SIZE: usize, const SFX_LEN: usize>(
+    input: &'a [u8; N],
+    suffix: &[u8; SFX_LEN],
+    delim: u8,
+    b0: &mut [u8; BLOCK_SIZE], // final block with padding
+    b1: &mut [u8; BLOCK_SIZE], // optional second final block if needed
+) -> (
+    &'a [[u8; BLOCK_SIZE]], // blocks
+    bool,                   // true if two blocks is necessary for padding
+) {
+    let (blocks, rem) = input.as_chunks();
+
+    let pad_two_blocks = const { N % BLOCK_SIZE > BLOCK_SIZE - 1 - SFX_LEN };
+
+    b0.split_at_mut(N % BLOCK_SIZE).0.copy_from_slice(rem);
+    b0[N % BLOCK_SIZE] = delim;
+
+    if SFX_LEN > 0 {
+        if pad_two_blocks {
+            b1.as_chunks_mut::<SFX_LEN>().0[7].copy_from_slice(suffix);
+        } else {
+            b0.as_chunks_mut::<SFX_LEN>().0[7].copy_from_slice(suffix);
+        }
+    }
+
+    (blocks, pad_two_blocks)
+}
+
+/// Pending partial block of an incremental hasher and the number of bytes
+/// absorbed so far.
+#[derive(Clone, Debug)]
+pub(crate) struct BlockBuffer<const BLOCK_SIZE: usize> {
+    block: [u8; BLOCK_SIZE],
+    pos: usize,
+    len: u128,
+}
+
+impl<const BLOCK_SIZE: usize> BlockBuffer<BLOCK_SIZE> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            block: [0; BLOCK_SIZE],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Count `data` and top up the pending block from its front, returning
+    /// the block once complete along with the rest of `data`.
+    #[inline(always)]
+    pub(crate) const fn fill<'a>(&mut self, data: &'a [u8]) -> (Option<&[u8; BLOCK_SIZE]>, &'a [u8]) {
+        self.len = self.len.wrapping_add(data.len() as u128);
+        if self.pos == 0 {
+            return (None, data);
+        }
+
+        let tail = self.top_up(data);
+        if self.pos == BLOCK_SIZE {
+            self.pos = 0;
+            (Some(&self.block), tail)
+        } else {
+            (None, tail)
+        }
+    }
+
+    /// Append the tail of the input shorter than a block.
+    #[inline(always)]
+    pub(crate) const fn keep(&mut self, rem: &[u8]) {
+        self.top_up(rem);
+    }
+
+    /// Append as much of `data` as fits in the pending block, returning the
+    /// rest.
+    #[inline(always)]
+    const fn top_up<'a>(&mut self, data: &'a [u8]) -> &'a [u8] {
+        let pos = if self.pos < BLOCK_SIZE { self.pos } else { BLOCK_SIZE };
+        let free = BLOCK_SIZE - pos;
+        let taken = if data.len() < free { data.len() } else { free };
+        if let Some((head, tail)) = data.split_at_checked(taken)
+            && let Some((_, dst)) = self.block.split_at_mut_checked(pos)
+            && let Some((dst, _)) = dst.split_at_mut_checked(taken)
+        {
+            dst.copy_from_slice(head);
+            self.pos = pos + taken;
+            tail
+        } else {
+            data
+        }
+    }
+
+    /// Bytes of the pending block, always shorter than a block.
+    #[inline(always)]
+    pub(crate) const fn pending(&self) -> &[u8] {
+        if self.pos < BLOCK_SIZE {
+            self.block.split_at(self.pos).0
+        } else {
+            &[]
+        }
+    }
+
+    /// Number of bytes absorbed so far, modulo 2^128.
+    #[inline(always)]
+    pub(crate) const fn len(&self) -> u128 {
+        self.len
+    }
+}
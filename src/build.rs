@@ -0,0 +1,133 @@
+//! Digests of files precomputed by a build script, for assets too large to
+//! be hashed in `const` evaluation at every build.
+//!
+//! Files are streamed through [`DynHasher`](crate::DynHasher), which runs the
+//! same code as the `const fn`s and gives the same digests as
+//! [`hash`](crate::hash), so a constant emitted for `assets/logo.png` with
+//! [`HashKind::Sha256`] always equals `sha2_256(include_bytes!("assets/logo.png"))`.
+//!
+//! # Examples
+//! In `build.rs`, with `cthash` as a build dependency with the `std` feature:
+//! ```no_run
+//! use cthash::HashKind;
+//!
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("digests.rs");
+//! cthash::build::emit_digests(out, &["assets/logo.png", "assets/fw.bin"], &[HashKind::Sha256, HashKind::Md5]).unwrap();
+//! ```
+//! The crate then includes the module, which defines `LOGO_SHA256`,
+//! `LOGO_MD5`, `FW_SHA256` and `FW_MD5`:
+//! ```ignore
+//! mod digests {
+//!     include!(concat!(env!("OUT_DIR"), "/digests.rs"));
+//! }
+//! ```
+
+use crate::{DynHasher, HashKind};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::string::String;
+use std::vec::Vec;
+use std::{format, println, vec};
+
+/// Write a Rust module defining a `pub const` digest of each of `files` for
+/// each of `kinds`, and tell Cargo to rerun the build script when one of the
+/// files changes.
+///
+/// Constants are named after the file stem and the algorithm, in uppercase
+/// with other characters than letters and digits replaced by `_`:
+/// `assets/logo.png` gives `LOGO_SHA256` or `LOGO_SHA3_512`. Two files
+/// giving the same name are an [`io::ErrorKind::InvalidInput`] error.
+pub fn emit_digests(out: impl AsRef<Path>, files: &[impl AsRef<Path>], kinds: &[HashKind]) -> io::Result<()> {
+    let mut module = Vec::new();
+    write_digests(&mut module, files, kinds)?;
+    std::fs::write(out, module)?;
+    for file in files {
+        println!("cargo:rerun-if-changed={}", file.as_ref().display());
+    }
+    Ok(())
+}
+
+/// Same as [`emit_digests`] into any writer, without the Cargo directives.
+pub fn write_digests(mut out: impl Write, files: &[impl AsRef<Path>], kinds: &[HashKind]) -> io::Result<()> {
+    let mut names = Vec::new();
+    writeln!(out, "// @generated by cthash {}, do not edit.", env!("CARGO_PKG_VERSION"))?;
+    for file in files {
+        let file = file.as_ref();
+        let context = |e: io::Error| io::Error::new(e.kind(), format!("{}: {e}", file.display()));
+
+        let mut hashers: Vec<DynHasher> = kinds.iter().map(|&kind| DynHasher::new(kind)).collect();
+        let mut reader = File::open(file).map_err(context)?;
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => hashers.iter_mut().for_each(|hasher| hasher.update(buf.get(..n).unwrap_or(&buf))),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(context(e)),
+            }
+        }
+
+        let stem = file.file_stem().map(|stem| stem.to_string_lossy()).unwrap_or_default();
+        for hasher in hashers {
+            let kind = hasher.kind();
+            let name = const_name(&stem, kind);
+            if names.contains(&name) {
+                let msg = format!("{}: digest constant {name} is already defined", file.display());
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+            }
+
+            let digest = hasher.finalize();
+            writeln!(out)?;
+            // quoted and escaped, a line break in the path cannot end the comment
+            writeln!(out, "/// {} of {file:?}.", kind_doc(kind))?;
+            writeln!(out, "pub const {name}: [u8; {}] = [", kind.output_len())?;
+            for row in digest.as_bytes().chunks(16) {
+                let row: Vec<String> = row.iter().map(|b| format!("{b:#04x}")).collect();
+                writeln!(out, "    {},", row.join(", "))?;
+            }
+            writeln!(out, "];")?;
+            names.push(name);
+        }
+    }
+    Ok(())
+}
+
+fn const_name(stem: &str, kind: HashKind) -> String {
+    let mut name: String = stem
+        .chars()
+        .chain(['_'])
+        .chain(kind.name().chars())
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+/// Algorithm name in the doc comments, e.g. `SHA-256`.
+fn kind_doc(kind: HashKind) -> &'static str {
+    match kind {
+        HashKind::Md2 => "MD2",
+        HashKind::Md4 => "MD4",
+        HashKind::Md5 => "MD5",
+        HashKind::Sha1 => "SHA-1",
+        HashKind::Sha224 => "SHA-224",
+        HashKind::Sha256 => "SHA-256",
+        HashKind::Sha384 => "SHA-384",
+        HashKind::Sha512 => "SHA-512",
+        HashKind::Sha3_224 => "SHA3-224",
+        HashKind::Sha3_256 => "SHA3-256",
+        HashKind::Sha3_384 => "SHA3-384",
+        HashKind::Sha3_512 => "SHA3-512",
+        HashKind::Keccak224 => "Keccak-224",
+        HashKind::Keccak256 => "Keccak-256",
+        HashKind::Keccak384 => "Keccak-384",
+        HashKind::Keccak512 => "Keccak-512",
+        HashKind::Tiger => "Tiger",
+        HashKind::Tiger2 => "Tiger2",
+        HashKind::AsconHash256 => "Ascon-Hash256",
+        HashKind::Blake2b512 => "BLAKE2b-512",
+    }
+}
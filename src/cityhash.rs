@@ -0,0 +1,297 @@
+pub(crate) const K0: u64 = 0xc3a5c85c97cb3127;
+pub(crate) const K1: u64 = 0xb492b66fbe98f273;
+pub(crate) const K2: u64 = 0x9ae16a3b2f90404f;
+
+/// Compute 64-bit CityHash v1.1.
+/// # Examples
+/// ```
+/// use cthash::cityhash64;
+/// const H: u64 = cityhash64(b"data");
+/// assert_eq!(cityhash64(b""), 0x9ae16a3b2f90404f);
+/// ```
+pub const fn cityhash64(input: &[u8]) -> u64 {
+    let len = input.len();
+    if len <= 16 {
+        return hash_len_0_to_16(input);
+    } else if len <= 32 {
+        return hash_len_17_to_32(input);
+    } else if len <= 64 {
+        return hash_len_33_to_64(input);
+    }
+
+    let len = len as u64;
+    let end = input.len();
+    let mut x = fetch64(input, end - 40);
+    let mut y = fetch64(input, end - 16).wrapping_add(fetch64(input, end - 56));
+    let mut z = hash_len_16(fetch64(input, end - 48).wrapping_add(len), fetch64(input, end - 24));
+    let mut v = weak_hash_len_32_with_seeds(input, end - 64, len, z);
+    let mut w = weak_hash_len_32_with_seeds(input, end - 32, y.wrapping_add(K1), x);
+    x = x.wrapping_mul(K1).wrapping_add(fetch64(input, 0));
+
+    // all 64-byte blocks but the last one, which overlaps the ones above
+    let mut s = 0;
+    while s < (end - 1) / 64 * 64 {
+        (x, y, z, v, w) = block(input, s, x, y, z, v, w, K1);
+        s += 64;
+    }
+
+    hash_len_16(
+        hash_len_16(v.0, w.0).wrapping_add(shift_mix(y).wrapping_mul(K1)).wrapping_add(z),
+        hash_len_16(v.1, w.1).wrapping_add(x),
+    )
+}
+
+/// Compute 128-bit CityHash v1.1.
+///
+/// The first 64-bit half (`Uint128Low64`) of the reference implementation is
+/// in the low bits.
+/// # Examples
+/// ```
+/// use cthash::cityhash128;
+/// const H: u128 = cityhash128(b"data");
+/// ```
+pub const fn cityhash128(input: &[u8]) -> u128 {
+    match input.split_first_chunk::<16>() {
+        Some((head, rest)) => city_hash128_with_seed(rest, (fetch64(head, 0), fetch64(head, 8).wrapping_add(K0))),
+        None => city_hash128_with_seed(input, (K0, K1)),
+    }
+}
+
+const fn city_hash128_with_seed(input: &[u8], seed: (u64, u64)) -> u128 {
+    if input.len() < 128 {
+        return city_murmur(input, seed);
+    }
+
+    let (mut x, mut y) = seed;
+    let mut z = (input.len() as u64).wrapping_mul(K1);
+    let v0 = (y ^ K1).rotate_right(49).wrapping_mul(K1).wrapping_add(fetch64(input, 0));
+    let mut v = (v0, v0.rotate_right(42).wrapping_mul(K1).wrapping_add(fetch64(input, 8)));
+    let mut w = (
+        y.wrapping_add(z).rotate_right(35).wrapping_mul(K1).wrapping_add(x),
+        x.wrapping_add(fetch64(input, 88)).rotate_right(53).wrapping_mul(K1),
+    );
+
+    // two 64-byte blocks per round, as long as 128 bytes remain
+    let mut rest = input;
+    while let Some((blocks, tail)) = rest.split_first_chunk::<128>() {
+        (x, y, z, v, w) = block(blocks, 0, x, y, z, v, w, K1);
+        (x, y, z, v, w) = block(blocks, 64, x, y, z, v, w, K1);
+        rest = tail;
+    }
+
+    x = x.wrapping_add(v.0.wrapping_add(z).rotate_right(49).wrapping_mul(K0));
+    y = y.wrapping_mul(K0).wrapping_add(w.1.rotate_right(37));
+    z = z.wrapping_mul(K0).wrapping_add(w.0.rotate_right(27));
+    w.0 = w.0.wrapping_mul(9);
+    v.0 = v.0.wrapping_mul(K0);
+
+    // up to four 32-byte chunks of the tail, from its end backwards; the
+    // last one may reach back into the blocks above
+    let mut left = rest.len();
+    let mut end = input;
+    while left > 0
+        && let Some((head, chunk)) = end.split_last_chunk::<32>()
+    {
+        y = x.wrapping_add(y).rotate_right(42).wrapping_mul(K0).wrapping_add(v.1);
+        w.0 = w.0.wrapping_add(fetch64(chunk, 16));
+        x = x.wrapping_mul(K0).wrapping_add(w.0);
+        z = z.wrapping_add(w.1).wrapping_add(fetch64(chunk, 0));
+        w.1 = w.1.wrapping_add(v.0);
+        v = weak_hash_len_32_with_seeds(chunk, 0, v.0.wrapping_add(z), v.1);
+        v.0 = v.0.wrapping_mul(K0);
+        left = left.saturating_sub(32);
+        end = head;
+    }
+
+    x = hash_len_16(x, v.0);
+    y = hash_len_16(y.wrapping_add(z), w.0);
+    let low = hash_len_16(x.wrapping_add(v.1), w.1).wrapping_add(y);
+    let high = hash_len_16(x.wrapping_add(w.1), y.wrapping_add(v.1));
+    (high as u128) << 64 | low as u128
+}
+
+/// `CityMurmur`, the 128-bit hash of inputs shorter than 128 bytes.
+const fn city_murmur(input: &[u8], seed: (u64, u64)) -> u128 {
+    let len = input.len();
+    let (mut a, mut b) = seed;
+    let mut c;
+    let mut d;
+    if len <= 16 {
+        a = shift_mix(a.wrapping_mul(K1)).wrapping_mul(K1);
+        c = b.wrapping_mul(K1).wrapping_add(hash_len_0_to_16(input));
+        d = shift_mix(a.wrapping_add(if len >= 8 { fetch64(input, 0) } else { c }));
+    } else {
+        c = hash_len_16(fetch64(input, len - 8).wrapping_add(K1), a);
+        d = hash_len_16(b.wrapping_add(len as u64), c.wrapping_add(fetch64(input, len - 16)));
+        a = a.wrapping_add(d);
+        // 16-byte steps while more than 16 bytes are left
+        let mut s = 0;
+        while s + 16 < len {
+            a ^= shift_mix(fetch64(input, s).wrapping_mul(K1)).wrapping_mul(K1);
+            a = a.wrapping_mul(K1);
+            b ^= a;
+            c ^= shift_mix(fetch64(input, s + 8).wrapping_mul(K1)).wrapping_mul(K1);
+            c = c.wrapping_mul(K1);
+            d ^= c;
+            s += 16;
+        }
+    }
+    a = hash_len_16(a, c);
+    b = hash_len_16(d, b);
+    let low = a ^ b;
+    let high = hash_len_16(b, a);
+    (high as u128) << 64 | low as u128
+}
+
+/// One 64-byte block of the long-input loop, `mul` being `K1` except for the
+/// final block of FarmHash.
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+pub(crate) const fn block(
+    input: &[u8],
+    s: usize,
+    mut x: u64,
+    mut y: u64,
+    mut z: u64,
+    v: (u64, u64),
+    w: (u64, u64),
+    mul: u64,
+) -> (u64, u64, u64, (u64, u64), (u64, u64)) {
+    x = x
+        .wrapping_add(y)
+        .wrapping_add(v.0)
+        .wrapping_add(fetch64(input, s + 8))
+        .rotate_right(37)
+        .wrapping_mul(mul);
+    y = y
+        .wrapping_add(v.1)
+        .wrapping_add(fetch64(input, s + 48))
+        .rotate_right(42)
+        .wrapping_mul(mul);
+    x ^= w.1;
+    y = y.wrapping_add(v.0).wrapping_add(fetch64(input, s + 40));
+    z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(mul);
+    let v = weak_hash_len_32_with_seeds(input, s, v.1.wrapping_mul(mul), x.wrapping_add(w.0));
+    let w = weak_hash_len_32_with_seeds(input, s + 32, z.wrapping_add(w.1), y.wrapping_add(fetch64(input, s + 16)));
+    // `std::swap(z, x)`
+    (z, y, x, v, w)
+}
+
+pub(crate) const fn hash_len_0_to_16(input: &[u8]) -> u64 {
+    let len = input.len();
+    if len >= 8 {
+        let mul = K2.wrapping_add(len as u64 * 2);
+        let a = fetch64(input, 0).wrapping_add(K2);
+        let b = fetch64(input, len - 8);
+        let c = b.rotate_right(37).wrapping_mul(mul).wrapping_add(a);
+        let d = a.rotate_right(25).wrapping_add(b).wrapping_mul(mul);
+        hash_len_16_mul(c, d, mul)
+    } else if len >= 4 {
+        let mul = K2.wrapping_add(len as u64 * 2);
+        let a = fetch32(input, 0) as u64;
+        hash_len_16_mul((len as u64).wrapping_add(a << 3), fetch32(input, len - 4) as u64, mul)
+    } else if len > 0 {
+        let a = input[0] as u32;
+        let b = input[len >> 1] as u32;
+        let c = input[len - 1] as u32;
+        let y = a.wrapping_add(b << 8);
+        let z = (len as u32).wrapping_add(c << 2);
+        shift_mix((y as u64).wrapping_mul(K2) ^ (z as u64).wrapping_mul(K0)).wrapping_mul(K2)
+    } else {
+        K2
+    }
+}
+
+pub(crate) const fn hash_len_17_to_32(input: &[u8]) -> u64 {
+    let len = input.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(input, 0).wrapping_mul(K1);
+    let b = fetch64(input, 8);
+    let c = fetch64(input, len - 8).wrapping_mul(mul);
+    let d = fetch64(input, len - 16).wrapping_mul(K2);
+    hash_len_16_mul(
+        a.wrapping_add(b).rotate_right(43).wrapping_add(c.rotate_right(30)).wrapping_add(d),
+        a.wrapping_add(b.wrapping_add(K2).rotate_right(18)).wrapping_add(c),
+        mul,
+    )
+}
+
+const fn hash_len_33_to_64(input: &[u8]) -> u64 {
+    let len = input.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let mut a = fetch64(input, 0).wrapping_mul(K2);
+    let mut b = fetch64(input, 8);
+    let c = fetch64(input, len - 24);
+    let d = fetch64(input, len - 32);
+    let e = fetch64(input, 16).wrapping_mul(K2);
+    let f = fetch64(input, 24).wrapping_mul(9);
+    let g = fetch64(input, len - 8);
+    let h = fetch64(input, len - 16).wrapping_mul(mul);
+    let u = a
+        .wrapping_add(g)
+        .rotate_right(43)
+        .wrapping_add(b.rotate_right(30).wrapping_add(c).wrapping_mul(9));
+    let v = (a.wrapping_add(g) ^ d).wrapping_add(f).wrapping_add(1);
+    let w = u.wrapping_add(v).wrapping_mul(mul).swap_bytes().wrapping_add(h);
+    let x = e.wrapping_add(f).rotate_right(42).wrapping_add(c);
+    let y = v.wrapping_add(w).wrapping_mul(mul).swap_bytes().wrapping_add(g).wrapping_mul(mul);
+    let z = e.wrapping_add(f).wrapping_add(c);
+    a = x.wrapping_add(z).wrapping_mul(mul).wrapping_add(y).swap_bytes().wrapping_add(b);
+    b = shift_mix(z.wrapping_add(a).wrapping_mul(mul).wrapping_add(d).wrapping_add(h)).wrapping_mul(mul);
+    b.wrapping_add(x)
+}
+
+/// `Hash128to64`, Murmur-inspired mixing of two words.
+#[inline(always)]
+pub(crate) const fn hash_len_16(u: u64, v: u64) -> u64 {
+    hash_len_16_mul(u, v, 0x9ddfea08eb382d69)
+}
+
+#[inline(always)]
+pub(crate) const fn hash_len_16_mul(u: u64, v: u64, mul: u64) -> u64 {
+    let a = shift_mix((u ^ v).wrapping_mul(mul));
+    let b = shift_mix((v ^ a).wrapping_mul(mul));
+    b.wrapping_mul(mul)
+}
+
+#[inline(always)]
+pub(crate) const fn shift_mix(value: u64) -> u64 {
+    value ^ (value >> 47)
+}
+
+/// `WeakHashLen32WithSeeds` of the 32 bytes at `offset`.
+#[inline(always)]
+pub(crate) const fn weak_hash_len_32_with_seeds(input: &[u8], offset: usize, mut a: u64, mut b: u64) -> (u64, u64) {
+    let w = fetch64(input, offset);
+    let x = fetch64(input, offset + 8);
+    let y = fetch64(input, offset + 16);
+    let z = fetch64(input, offset + 24);
+    a = a.wrapping_add(w);
+    b = b.wrapping_add(a).wrapping_add(z).rotate_right(21);
+    let c = a;
+    a = a.wrapping_add(x).wrapping_add(y);
+    b = b.wrapping_add(a.rotate_right(44));
+    (a.wrapping_add(z), b.wrapping_add(c))
+}
+
+#[inline(always)]
+pub(crate) const fn fetch64(bytes: &[u8], offset: usize) -> u64 {
+    match bytes.split_at_checked(offset) {
+        Some((_, tail)) => match tail.first_chunk::<8>() {
+            Some(chunk) => u64::from_le_bytes(*chunk),
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+#[inline(always)]
+pub(crate) const fn fetch32(bytes: &[u8], offset: usize) -> u32 {
+    match bytes.split_at_checked(offset) {
+        Some((_, tail)) => match tail.first_chunk::<4>() {
+            Some(chunk) => u32::from_le_bytes(*chunk),
+            None => 0,
+        },
+        None => 0,
+    }
+}
@@ -0,0 +1,237 @@
+/// CRC of `WIDTH` bits (1 to 64) in the Rocksoft model: polynomial `poly`,
+/// initial register `init`, input and output reflection and `xor_out` applied
+/// to the final register.
+///
+/// The lookup table is generated by [`Crc::new`], so a `const` instance has it
+/// built at compile time. Only the low `WIDTH` bits of the parameters are used.
+/// Common algorithms are available as presets named after the
+/// [CRC catalogue](https://reveng.sourceforge.io/crc-catalogue/), e.g.
+/// [`CRC_32_ISO_HDLC`].
+///
+/// # Examples
+/// ```
+/// use cthash::{CRC_32_ISO_HDLC, Crc};
+///
+/// const C: [u8; 4] = CRC_32_ISO_HDLC.checksum(b"123456789");
+/// assert_eq!(C, [0xcb, 0xf4, 0x39, 0x26]);
+/// assert_eq!(CRC_32_ISO_HDLC.value(b"123456789"), 0xcbf43926);
+///
+/// // CRC-16/GENIBUS, not among the presets
+/// const GENIBUS: Crc<16> = Crc::new(0x1021, 0xffff, false, false, 0xffff);
+/// const G: [u8; 2] = GENIBUS.checksum(b"123456789");
+/// assert_eq!(G, [0xd6, 0x4e]);
+/// ```
+///
+/// The width must be between 1 and 64 bits:
+///
+/// ```compile_fail
+/// const C: cthash::Crc<65> = cthash::Crc::new(0x07, 0, false, false, 0);
+/// ```
+pub struct Crc<const WIDTH: u8> {
+    poly: u64,
+    init: u64,
+    reflect_in: bool,
+    reflect_out: bool,
+    xor_out: u64,
+    table: [u64; 256],
+}
+
+impl<const WIDTH: u8> Crc<WIDTH> {
+    const MASK: u64 = u64::MAX >> (64 - WIDTH as u32);
+
+    /// Create an instance and generate its lookup table.
+    pub const fn new(poly: u64, init: u64, reflect_in: bool, reflect_out: bool, xor_out: u64) -> Self {
+        const { assert!(WIDTH > 0 && WIDTH <= 64, "CRC width must be between 1 and 64 bits") }
+
+        let poly = poly & Self::MASK;
+        let mut table = [0u64; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc;
+            let mut bit = 0;
+            if reflect_in {
+                // register holds the reflected CRC in its low bits
+                let poly = reflect::<WIDTH>(poly);
+                crc = i as u64;
+                while bit < 8 {
+                    crc = if crc & 1 == 1 { crc >> 1 ^ poly } else { crc >> 1 };
+                    bit += 1;
+                }
+            } else {
+                // register holds the CRC in its high bits
+                let poly = poly << (64 - WIDTH as u32);
+                crc = (i as u64) << 56;
+                while bit < 8 {
+                    crc = if crc >> 63 == 1 { crc << 1 ^ poly } else { crc << 1 };
+                    bit += 1;
+                }
+            }
+            table[i] = crc;
+            i += 1;
+        }
+
+        Self {
+            poly,
+            init: init & Self::MASK,
+            reflect_in,
+            reflect_out,
+            xor_out: xor_out & Self::MASK,
+            table,
+        }
+    }
+
+    /// Compute the CRC as `N` big-endian bytes, `N` being `WIDTH` rounded up to whole bytes.
+    pub const fn checksum<const N: usize>(&self, input: &[u8]) -> [u8; N] {
+        const {
+            assert!(
+                N == (WIDTH as usize).div_ceil(8),
+                "checksum size must be the CRC width in whole bytes"
+            )
+        }
+
+        let bytes = self.value(input).to_be_bytes();
+        let mut out = [0u8; N];
+        out.copy_from_slice(bytes.split_at(8 - N).1);
+        out
+    }
+
+    /// Compute the CRC as an integer.
+    pub const fn value(&self, input: &[u8]) -> u64 {
+        let mut crc;
+        let mut i = 0;
+        if self.reflect_in {
+            crc = reflect::<WIDTH>(self.init);
+            while i < input.len() {
+                crc = self.table[(crc as u8 ^ input[i]) as usize] ^ crc >> 8;
+                i += 1;
+            }
+            if !self.reflect_out {
+                crc = reflect::<WIDTH>(crc);
+            }
+        } else {
+            crc = self.init << (64 - WIDTH as u32);
+            while i < input.len() {
+                crc = self.table[((crc >> 56) as u8 ^ input[i]) as usize] ^ crc << 8;
+                i += 1;
+            }
+            crc >>= 64 - WIDTH as u32;
+            if self.reflect_out {
+                crc = reflect::<WIDTH>(crc);
+            }
+        }
+        (crc ^ self.xor_out) & Self::MASK
+    }
+
+    /// CRC of the ASCII string `"123456789"`, listed for every algorithm in the catalogue.
+    pub const fn check(&self) -> u64 {
+        self.value(b"123456789")
+    }
+
+    /// Width in bits.
+    pub const fn width(&self) -> u8 {
+        WIDTH
+    }
+
+    /// Generator polynomial without its top bit.
+    pub const fn poly(&self) -> u64 {
+        self.poly
+    }
+
+    /// Initial register value.
+    pub const fn init(&self) -> u64 {
+        self.init
+    }
+
+    /// Whether input bytes are processed least significant bit first.
+    pub const fn reflect_in(&self) -> bool {
+        self.reflect_in
+    }
+
+    /// Whether the final register is reflected before `xor_out`.
+    pub const fn reflect_out(&self) -> bool {
+        self.reflect_out
+    }
+
+    /// Value XORed to the final register.
+    pub const fn xor_out(&self) -> u64 {
+        self.xor_out
+    }
+}
+
+/// Reverse the low `WIDTH` bits.
+#[inline(always)]
+const fn reflect<const WIDTH: u8>(value: u64) -> u64 {
+    value.reverse_bits() >> (64 - WIDTH as u32)
+}
+
+/// CRC-3/GSM.
+pub const CRC_3_GSM: Crc<3> = Crc::new(0x3, 0x0, false, false, 0x7);
+
+/// CRC-5/USB, USB token packets.
+pub const CRC_5_USB: Crc<5> = Crc::new(0x05, 0x1f, true, true, 0x1f);
+
+/// CRC-8/SMBUS, also known as plain CRC-8.
+pub const CRC_8_SMBUS: Crc<8> = Crc::new(0x07, 0x00, false, false, 0x00);
+
+/// CRC-8/MAXIM-DOW, 1-Wire devices.
+pub const CRC_8_MAXIM_DOW: Crc<8> = Crc::new(0x31, 0x00, true, true, 0x00);
+
+/// CRC-8/AUTOSAR.
+pub const CRC_8_AUTOSAR: Crc<8> = Crc::new(0x2f, 0xff, false, false, 0xff);
+
+/// CRC-12/UMTS, with reflected output only.
+pub const CRC_12_UMTS: Crc<12> = Crc::new(0x80f, 0x000, false, true, 0x000);
+
+/// CRC-16/ARC, also known as CRC-16 or CRC-16/LHA.
+pub const CRC_16_ARC: Crc<16> = Crc::new(0x8005, 0x0000, true, true, 0x0000);
+
+/// CRC-16/IBM-3740, commonly called CRC-16/CCITT-FALSE.
+pub const CRC_16_IBM_3740: Crc<16> = Crc::new(0x1021, 0xffff, false, false, 0x0000);
+
+/// CRC-16/IBM-SDLC, also known as CRC-16/X-25, HDLC frames.
+pub const CRC_16_IBM_SDLC: Crc<16> = Crc::new(0x1021, 0xffff, true, true, 0xffff);
+
+/// CRC-16/KERMIT, the original CCITT CRC, also known as CRC-16/CCITT.
+pub const CRC_16_KERMIT: Crc<16> = Crc::new(0x1021, 0x0000, true, true, 0x0000);
+
+/// CRC-16/MODBUS.
+pub const CRC_16_MODBUS: Crc<16> = Crc::new(0x8005, 0xffff, true, true, 0x0000);
+
+/// CRC-16/USB.
+pub const CRC_16_USB: Crc<16> = Crc::new(0x8005, 0xffff, true, true, 0xffff);
+
+/// CRC-16/XMODEM, also known as CRC-16/ACORN or CRC-16/LTE.
+pub const CRC_16_XMODEM: Crc<16> = Crc::new(0x1021, 0x0000, false, false, 0x0000);
+
+/// CRC-24/OPENPGP, ASCII armor checksum.
+pub const CRC_24_OPENPGP: Crc<24> = Crc::new(0x864cfb, 0xb704ce, false, false, 0x000000);
+
+/// CRC-32/ISO-HDLC, the CRC-32 of Ethernet, zlib, gzip and PNG.
+pub const CRC_32_ISO_HDLC: Crc<32> = Crc::new(0x04c11db7, 0xffffffff, true, true, 0xffffffff);
+
+/// CRC-32/ISCSI, also known as CRC-32C (Castagnoli), used by iSCSI, SCTP and ext4.
+pub const CRC_32_ISCSI: Crc<32> = Crc::new(0x1edc6f41, 0xffffffff, true, true, 0xffffffff);
+
+/// CRC-32/AUTOSAR.
+pub const CRC_32_AUTOSAR: Crc<32> = Crc::new(0xf4acfb13, 0xffffffff, true, true, 0xffffffff);
+
+/// CRC-32/BZIP2, also known as CRC-32/AAL5.
+pub const CRC_32_BZIP2: Crc<32> = Crc::new(0x04c11db7, 0xffffffff, false, false, 0xffffffff);
+
+/// CRC-32/CKSUM, the POSIX `cksum` CRC without the length suffix.
+pub const CRC_32_CKSUM: Crc<32> = Crc::new(0x04c11db7, 0x00000000, false, false, 0xffffffff);
+
+/// CRC-32/MPEG-2.
+pub const CRC_32_MPEG_2: Crc<32> = Crc::new(0x04c11db7, 0xffffffff, false, false, 0x00000000);
+
+/// CRC-64/ECMA-182.
+pub const CRC_64_ECMA_182: Crc<64> = Crc::new(0x42f0e1eba9ea3693, 0x0000000000000000, false, false, 0x0000000000000000);
+
+/// CRC-64/GO-ISO.
+pub const CRC_64_GO_ISO: Crc<64> = Crc::new(0x000000000000001b, 0xffffffffffffffff, true, true, 0xffffffffffffffff);
+
+/// CRC-64/NVME.
+pub const CRC_64_NVME: Crc<64> = Crc::new(0xad93d23594c93659, 0xffffffffffffffff, true, true, 0xffffffffffffffff);
+
+/// CRC-64/XZ, also known as CRC-64/GO-ECMA.
+pub const CRC_64_XZ: Crc<64> = Crc::new(0x42f0e1eba9ea3693, 0xffffffffffffffff, true, true, 0xffffffffffffffff);
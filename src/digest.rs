@@ -0,0 +1,241 @@
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+/// Digest of `N` bytes, formatted and parsed as hex.
+///
+/// The hash functions return bare arrays, wrap them to print, parse or
+/// compare digests in `const` contexts.
+///
+/// # Examples
+/// ```
+/// use cthash::{Digest, sha2_256};
+///
+/// const H: Digest<32> = Digest::new(sha2_256(b""));
+/// const EXPECTED: Digest<32> = Digest::from_hex("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+/// const { assert!(H.eq(&EXPECTED)) };
+///
+/// assert_eq!(H.to_string(), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+/// assert_eq!(format!("{H:#X}"), "0xE3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855");
+/// assert_eq!("0xE3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855".parse(), Ok(H));
+/// ```
+///
+/// Malformed hex fails compilation when parsed in a constant:
+///
+/// ```compile_fail
+/// const D: cthash::Digest<2> = cthash::Digest::from_hex("abcz");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Digest<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> Digest<N> {
+    /// Wrap the output of a hash function.
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Bytes of the digest.
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Unwrap the bytes of the digest.
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Parse `2 * N` hex digits in either case, optionally prefixed by `0x`,
+    /// in a `const` context.
+    ///
+    /// Only meant for constants, where malformed input fails compilation.
+    /// Use [`Digest::try_from_hex`] or [`FromStr`] to parse hex at runtime.
+    pub const fn from_hex(hex: &str) -> Self {
+        match Self::try_from_hex(hex) {
+            Ok(digest) => digest,
+            Err(ParseDigestError::InvalidLength) => panic!("hex digest has the wrong length"),
+            Err(ParseDigestError::InvalidCharacter) => panic!("hex digest contains a non-hex character"),
+        }
+    }
+
+    /// Parse `2 * N` hex digits in either case, optionally prefixed by `0x`.
+    pub const fn try_from_hex(hex: &str) -> Result<Self, ParseDigestError> {
+        let mut hex = hex.as_bytes();
+        if let Some((b"0x", digits)) = hex.split_first_chunk::<2>() {
+            hex = digits;
+        }
+        if hex.len() != 2 * N {
+            return Err(ParseDigestError::InvalidLength);
+        }
+
+        let (pairs, _) = hex.as_chunks::<2>();
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+        while i < N && i < pairs.len() {
+            match (nibble(pairs[i][0]), nibble(pairs[i][1])) {
+                (Some(high), Some(low)) => bytes[i] = high << 4 | low,
+                _ => return Err(ParseDigestError::InvalidCharacter),
+            }
+            i += 1;
+        }
+        Ok(Self(bytes))
+    }
+
+    /// Compare two digests, usable in `const` contexts unlike `==`.
+    #[allow(clippy::should_implement_trait)]
+    pub const fn eq(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != other.0[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Digest<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> From<Digest<N>> for [u8; N] {
+    fn from(digest: Digest<N>) -> Self {
+        digest.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Digest<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for Digest<N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Lowercase hex, prefixed by `0x` with `{:#x}` and padded like a string.
+impl<const N: usize> fmt::LowerHex for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, &self.0, false)
+    }
+}
+
+/// Uppercase hex, prefixed by `0x` with `{:#X}` and padded like a string.
+impl<const N: usize> fmt::UpperHex for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, &self.0, true)
+    }
+}
+
+/// Lowercase hex without prefix, like `sha256sum` prints digests.
+impl<const N: usize> fmt::Display for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Digest({self:x})")
+    }
+}
+
+impl<const N: usize> FromStr for Digest<N> {
+    type Err = ParseDigestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_hex(s)
+    }
+}
+
+/// Error parsing a [`Digest`] from hex.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDigestError {
+    /// Not exactly two hex digits per digest byte.
+    InvalidLength,
+    /// A character other than `0-9`, `a-f` or `A-F`.
+    InvalidCharacter,
+}
+
+impl fmt::Display for ParseDigestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidLength => "hex digest has the wrong length",
+            Self::InvalidCharacter => "hex digest contains a non-hex character",
+        })
+    }
+}
+
+impl core::error::Error for ParseDigestError {}
+
+/// Write `bytes` as hex, honoring width, fill and alignment like [`fmt::Formatter::pad`].
+pub(crate) fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8], upper: bool) -> fmt::Result {
+    let prefix = if f.alternate() { "0x" } else { "" };
+    let padding = f.width().unwrap_or(0).saturating_sub(prefix.len() + 2 * bytes.len());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(prefix)?;
+    for byte in bytes {
+        if upper {
+            write!(f, "{byte:02X}")?;
+        } else {
+            write!(f, "{byte:02x}")?;
+        }
+    }
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+#[inline(always)]
+const fn nibble(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Serialized as a lowercase hex string.
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Digest<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialized from a hex string in either case, optionally prefixed by `0x`.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Digest<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HexVisitor<const N: usize>;
+
+        impl<const N: usize> serde::de::Visitor<'_> for HexVisitor<N> {
+            type Value = Digest<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a hex string of {N} bytes")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                Digest::try_from_hex(s).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(HexVisitor)
+    }
+}
@@ -0,0 +1,423 @@
+use crate::digest::write_hex;
+use crate::{
+    ascon_hash256, blake2b, keccak_224, keccak_256, keccak_384, keccak_512, md2, md4, md5, sha1, sha2_224, sha2_256, sha2_384, sha2_512,
+    sha3_224, sha3_256, sha3_384, sha3_512, tiger, tiger2,
+};
+use core::fmt;
+use core::str::FromStr;
+
+/// Hash function chosen at runtime, e.g. from a configuration file.
+///
+/// Displayed with its canonical lowercase name and parsed from it or from
+/// common aliases, ignoring case, `-` and `_`: `"SHA-256"`, `"sha2_256"` and
+/// `"sha256"` are all [`HashKind::Sha256`].
+///
+/// # Examples
+/// ```
+/// use cthash::{HashKind, hash};
+///
+/// let kind: HashKind = "SHA3-512".parse().unwrap();
+/// assert_eq!(kind, HashKind::Sha3_512);
+/// assert_eq!(kind.to_string(), "sha3-512");
+///
+/// let digest = hash(kind, b"data");
+/// assert_eq!(digest.as_bytes(), cthash::sha3_512(b"data"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum HashKind {
+    /// MD2, see [`md2`].
+    Md2,
+    /// MD4, see [`md4`].
+    Md4,
+    /// MD5, see [`md5`].
+    Md5,
+    /// SHA-1, see [`sha1`].
+    Sha1,
+    /// SHA-224, see [`sha2_224`].
+    Sha224,
+    /// SHA-256, see [`sha2_256`].
+    Sha256,
+    /// SHA-384, see [`sha2_384`].
+    Sha384,
+    /// SHA-512, see [`sha2_512`].
+    Sha512,
+    /// SHA3-224, see [`sha3_224`].
+    Sha3_224,
+    /// SHA3-256, see [`sha3_256`].
+    Sha3_256,
+    /// SHA3-384, see [`sha3_384`].
+    Sha3_384,
+    /// SHA3-512, see [`sha3_512`].
+    Sha3_512,
+    /// Keccak-224, see [`keccak_224`].
+    Keccak224,
+    /// Keccak-256, see [`keccak_256`].
+    Keccak256,
+    /// Keccak-384, see [`keccak_384`].
+    Keccak384,
+    /// Keccak-512, see [`keccak_512`].
+    Keccak512,
+    /// Tiger, see [`tiger`].
+    Tiger,
+    /// Tiger2, see [`tiger2`].
+    Tiger2,
+    /// Ascon-Hash256, see [`ascon_hash256`].
+    AsconHash256,
+    /// BLAKE2b-512, see [`blake2b`].
+    Blake2b512,
+}
+
+impl HashKind {
+    /// All hash functions, in declaration order.
+    pub const ALL: [Self; 20] = [
+        Self::Md2,
+        Self::Md4,
+        Self::Md5,
+        Self::Sha1,
+        Self::Sha224,
+        Self::Sha256,
+        Self::Sha384,
+        Self::Sha512,
+        Self::Sha3_224,
+        Self::Sha3_256,
+        Self::Sha3_384,
+        Self::Sha3_512,
+        Self::Keccak224,
+        Self::Keccak256,
+        Self::Keccak384,
+        Self::Keccak512,
+        Self::Tiger,
+        Self::Tiger2,
+        Self::AsconHash256,
+        Self::Blake2b512,
+    ];
+
+    /// Canonical lowercase name, e.g. `"sha256"` or `"sha3-512"`.
+    pub const fn name(self) -> &'static str {
+        self.names()[0]
+    }
+
+    /// Length of the digest in bytes.
+    pub const fn output_len(self) -> usize {
+        match self {
+            Self::Md2 | Self::Md4 | Self::Md5 => 16,
+            Self::Sha1 => 20,
+            Self::Tiger | Self::Tiger2 => 24,
+            Self::Sha224 | Self::Sha3_224 | Self::Keccak224 => 28,
+            Self::Sha256 | Self::Sha3_256 | Self::Keccak256 | Self::AsconHash256 => 32,
+            Self::Sha384 | Self::Sha3_384 | Self::Keccak384 => 48,
+            Self::Sha512 | Self::Sha3_512 | Self::Keccak512 | Self::Blake2b512 => 64,
+        }
+    }
+
+    /// Canonical name followed by the aliases, all lowercase.
+    const fn names(self) -> &'static [&'static str] {
+        match self {
+            Self::Md2 => &["md2"],
+            Self::Md4 => &["md4"],
+            Self::Md5 => &["md5"],
+            Self::Sha1 => &["sha1", "sha-1"],
+            Self::Sha224 => &["sha224", "sha-224", "sha2-224"],
+            Self::Sha256 => &["sha256", "sha-256", "sha2-256"],
+            Self::Sha384 => &["sha384", "sha-384", "sha2-384"],
+            Self::Sha512 => &["sha512", "sha-512", "sha2-512"],
+            Self::Sha3_224 => &["sha3-224"],
+            Self::Sha3_256 => &["sha3-256"],
+            Self::Sha3_384 => &["sha3-384"],
+            Self::Sha3_512 => &["sha3-512"],
+            Self::Keccak224 => &["keccak-224"],
+            Self::Keccak256 => &["keccak-256"],
+            Self::Keccak384 => &["keccak-384"],
+            Self::Keccak512 => &["keccak-512"],
+            Self::Tiger => &["tiger", "tiger192"],
+            Self::Tiger2 => &["tiger2"],
+            Self::AsconHash256 => &["ascon-hash256", "ascon-hash"],
+            Self::Blake2b512 => &["blake2b", "blake2b-512", "blake2b512"],
+        }
+    }
+}
+
+impl fmt::Display for HashKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for HashKind {
+    type Err = ParseHashKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.names().iter().any(|name| same_name(s, name)))
+            .ok_or(ParseHashKindError)
+    }
+}
+
+/// Whether the names are equal ignoring ASCII case, `-` and `_`.
+fn same_name(a: &str, b: &str) -> bool {
+    fn significant(s: &str) -> impl Iterator<Item = u8> + '_ {
+        s.bytes().filter(|c| !matches!(c, b'-' | b'_')).map(|c| c.to_ascii_lowercase())
+    }
+    significant(a).eq(significant(b))
+}
+
+/// Error parsing a [`HashKind`] from an unknown name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseHashKindError;
+
+impl fmt::Display for ParseHashKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown hash function")
+    }
+}
+
+impl core::error::Error for ParseHashKindError {}
+
+/// Digest of a [`HashKind`] chosen at runtime, stored inline.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynDigest {
+    kind: HashKind,
+    bytes: [u8; 64],
+}
+
+impl DynDigest {
+    /// Hash function that computed the digest.
+    pub const fn kind(&self) -> HashKind {
+        self.kind
+    }
+
+    /// Bytes of the digest, [`HashKind::output_len`] of them.
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.kind.output_len()).0
+    }
+}
+
+impl AsRef<[u8]> for DynDigest {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Lowercase hex, prefixed by `0x` with `{:#x}` and padded like a string.
+impl fmt::LowerHex for DynDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.as_bytes(), false)
+    }
+}
+
+/// Uppercase hex, prefixed by `0x` with `{:#X}` and padded like a string.
+impl fmt::UpperHex for DynDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.as_bytes(), true)
+    }
+}
+
+/// Lowercase hex without prefix.
+impl fmt::Display for DynDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::Debug for DynDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DynDigest({}, {self:x})", self.kind)
+    }
+}
+
+/// Compute the digest of `data` with a hash function chosen at runtime.
+/// # Examples
+/// ```
+/// use cthash::{HashKind, hash};
+/// const H: cthash::DynDigest = hash(HashKind::Md5, b"data");
+/// assert_eq!(H.as_bytes(), cthash::md5(b"data"));
+/// ```
+#[inline]
+pub const fn hash(kind: HashKind, data: &[u8]) -> DynDigest {
+    let bytes = match kind {
+        HashKind::Md2 => inline(md2(data)),
+        HashKind::Md4 => inline(md4(data)),
+        HashKind::Md5 => inline(md5(data)),
+        HashKind::Sha1 => inline(sha1(data)),
+        HashKind::Sha224 => inline(sha2_224(data)),
+        HashKind::Sha256 => inline(sha2_256(data)),
+        HashKind::Sha384 => inline(sha2_384(data)),
+        HashKind::Sha512 => inline(sha2_512(data)),
+        HashKind::Sha3_224 => inline(sha3_224(data)),
+        HashKind::Sha3_256 => inline(sha3_256(data)),
+        HashKind::Sha3_384 => inline(sha3_384(data)),
+        HashKind::Sha3_512 => inline(sha3_512(data)),
+        HashKind::Keccak224 => inline(keccak_224(data)),
+        HashKind::Keccak256 => inline(keccak_256(data)),
+        HashKind::Keccak384 => inline(keccak_384(data)),
+        HashKind::Keccak512 => inline(keccak_512(data)),
+        HashKind::Tiger => inline(tiger(data)),
+        HashKind::Tiger2 => inline(tiger2(data)),
+        HashKind::AsconHash256 => inline(ascon_hash256(data)),
+        HashKind::Blake2b512 => inline(blake2b::<64>(data)),
+    };
+    DynDigest { kind, bytes }
+}
+
+/// Incremental hasher of a [`HashKind`] chosen at runtime.
+///
+/// With the `std` or `embedded-io` feature, it is also a writer, e.g. to
+/// `std::io::copy` a file into.
+///
+/// # Examples
+/// ```
+/// use cthash::{DynHasher, HashKind, hash};
+///
+/// let mut hasher = DynHasher::new(HashKind::Sha3_256);
+/// hasher.update(b"da");
+/// hasher.update(b"ta");
+/// assert_eq!(hasher.finalize(), hash(HashKind::Sha3_256, b"data"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DynHasher(State);
+
+macro_rules! dyn_hasher {
+    ($($kind:ident: $hasher:ident),* $(,)?) => {
+        #[derive(Clone, Debug)]
+        enum State {
+            $($kind(crate::$hasher),)*
+        }
+
+        impl DynHasher {
+            /// Start an empty computation.
+            #[inline]
+            pub const fn new(kind: HashKind) -> Self {
+                Self(match kind {
+                    $(HashKind::$kind => State::$kind(crate::$hasher::new()),)*
+                })
+            }
+
+            /// Hash function being computed.
+            pub const fn kind(&self) -> HashKind {
+                match self.0 {
+                    $(State::$kind(_) => HashKind::$kind,)*
+                }
+            }
+
+            /// Absorb more input.
+            #[inline]
+            pub const fn update(&mut self, data: &[u8]) {
+                match &mut self.0 {
+                    $(State::$kind(hasher) => hasher.update(data),)*
+                }
+            }
+
+            /// Digest of all the input absorbed so far.
+            #[inline]
+            pub const fn finalize(self) -> DynDigest {
+                let kind = self.kind();
+                let bytes = match self.0 {
+                    $(State::$kind(hasher) => inline(hasher.finalize()),)*
+                };
+                DynDigest { kind, bytes }
+            }
+        }
+    };
+}
+
+dyn_hasher!(
+    Md2: Md2Hasher,
+    Md4: Md4Hasher,
+    Md5: Md5Hasher,
+    Sha1: Sha1Hasher,
+    Sha224: Sha224Hasher,
+    Sha256: Sha256Hasher,
+    Sha384: Sha384Hasher,
+    Sha512: Sha512Hasher,
+    Sha3_224: Sha3_224Hasher,
+    Sha3_256: Sha3_256Hasher,
+    Sha3_384: Sha3_384Hasher,
+    Sha3_512: Sha3_512Hasher,
+    Keccak224: Keccak224Hasher,
+    Keccak256: Keccak256Hasher,
+    Keccak384: Keccak384Hasher,
+    Keccak512: Keccak512Hasher,
+    Tiger: TigerHasher,
+    Tiger2: Tiger2Hasher,
+    AsconHash256: AsconHash256Hasher,
+    Blake2b512: Blake2b512Hasher,
+);
+
+#[cfg(feature = "std")]
+impl std::io::Write for DynHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::ErrorType for DynHasher {
+    type Error = core::convert::Infallible;
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Write for DynHasher {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Compute the digest of everything read from `reader` until its end,
+/// through a fixed 4 KiB buffer.
+/// # Examples
+/// ```
+/// use cthash::{HashKind, hash, hash_reader};
+/// let digest = hash_reader(HashKind::Sha256, &b"data"[..]).unwrap();
+/// assert_eq!(digest, hash(HashKind::Sha256, b"data"));
+/// ```
+#[cfg(feature = "std")]
+pub fn hash_reader(kind: HashKind, mut reader: impl std::io::Read) -> std::io::Result<DynDigest> {
+    let mut hasher = DynHasher::new(kind);
+    let mut buf = [0u8; 4096];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(hasher.finalize()),
+            Ok(n) => hasher.update(buf.get(..n).unwrap_or(&buf)),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Compute the digest of everything read from an `embedded-io` `reader`
+/// until its end, through a fixed 256-byte buffer.
+#[cfg(feature = "embedded-io")]
+pub fn hash_embedded_reader<R: embedded_io::Read>(kind: HashKind, mut reader: R) -> Result<DynDigest, R::Error> {
+    let mut hasher = DynHasher::new(kind);
+    let mut buf = [0u8; 256];
+    loop {
+        match reader.read(&mut buf)? {
+            0 => return Ok(hasher.finalize()),
+            n => hasher.update(buf.get(..n).unwrap_or(&buf)),
+        }
+    }
+}
+
+/// Digest padded with zeroes to the size of the largest one.
+#[inline(always)]
+const fn inline<const N: usize>(digest: [u8; N]) -> [u8; 64] {
+    let mut bytes = [0; 64];
+    let mut i = 0;
+    while i < N && i < 64 {
+        bytes[i] = digest[i];
+        i += 1;
+    }
+    bytes
+}
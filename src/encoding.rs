@@ -0,0 +1,298 @@
+//! Text encodings of digests, usable in `const` contexts.
+//!
+//! Every encoder takes a `&[u8; N]` and returns an [`Encoded`] buffer sized
+//! from `N`, giving the text through [`Encoded::as_str`] and `Display`.
+//!
+//! # Examples
+//! ```
+//! use cthash::encoding::{Base32, Hex, base32, hex};
+//! use cthash::sha2_256;
+//!
+//! const H: Hex<32> = hex(&sha2_256(b""));
+//! const NAME: &str = H.as_str();
+//! assert_eq!(NAME, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+//!
+//! const B: Base32<5> = base32(b"fooba");
+//! assert_eq!(B.as_str(), "MZXW6YTB");
+//! ```
+
+use core::fmt;
+
+/// Text of an encoded `N`-byte input, with room for up to `W` characters per
+/// input byte.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Encoded<const N: usize, const W: usize> {
+    buf: [[u8; W]; N],
+    len: usize,
+}
+
+/// Hex of `N` bytes.
+pub type Hex<const N: usize> = Encoded<N, 2>;
+
+/// Base64 of `N` bytes.
+pub type Base64<const N: usize> = Encoded<N, 4>;
+
+/// Base32 of `N` bytes.
+pub type Base32<const N: usize> = Encoded<N, 8>;
+
+/// Base58 of `N` bytes.
+pub type Base58<const N: usize> = Encoded<N, 2>;
+
+impl<const N: usize, const W: usize> Encoded<N, W> {
+    const fn new() -> Self {
+        Self { buf: [[0; W]; N], len: 0 }
+    }
+
+    /// Append an ASCII character, ignored past the capacity.
+    #[inline(always)]
+    const fn push(&mut self, c: u8) {
+        let len = self.len;
+        if len < W * N {
+            self.set(len, c);
+            self.len = len + 1;
+        }
+    }
+
+    /// Byte at `index`, 0 past the capacity.
+    #[inline(always)]
+    const fn get(&self, index: usize) -> u8 {
+        if W != 0 && index < W * N {
+            self.buf[index / W][index % W]
+        } else {
+            0
+        }
+    }
+
+    /// Overwrite the byte at `index`, ignored past the capacity.
+    #[inline(always)]
+    const fn set(&mut self, index: usize, c: u8) {
+        if W != 0 && index < W * N {
+            self.buf[index / W][index % W] = c;
+        }
+    }
+
+    /// Encoded text.
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.as_bytes()) {
+            Ok(s) => s,
+            Err(_) => "",
+        }
+    }
+
+    /// Encoded text as ASCII bytes.
+    pub const fn as_bytes(&self) -> &[u8] {
+        let bytes = self.buf.as_flattened();
+        bytes.split_at(if self.len < bytes.len() { self.len } else { bytes.len() }).0
+    }
+
+    /// Length of the text in bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the text is empty, only for empty inputs.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize, const W: usize> AsRef<str> for Encoded<N, W> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, const W: usize> PartialEq<str> for Encoded<N, W> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize, const W: usize> PartialEq<&str> for Encoded<N, W> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize, const W: usize> fmt::Display for Encoded<N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize, const W: usize> fmt::Debug for Encoded<N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE32_HEX: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const BASE58: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encode as lowercase hex.
+/// # Examples
+/// ```
+/// use cthash::encoding::{Hex, hex};
+/// const H: Hex<2> = hex(&[0xbe, 0xef]);
+/// assert_eq!(H.as_str(), "beef");
+/// ```
+pub const fn hex<const N: usize>(input: &[u8; N]) -> Hex<N> {
+    encode_bits(input, HEX_LOWER, false)
+}
+
+/// Encode as uppercase hex.
+pub const fn hex_upper<const N: usize>(input: &[u8; N]) -> Hex<N> {
+    encode_bits(input, HEX_UPPER, false)
+}
+
+/// Encode as standard base64 with `=` padding (RFC 4648, section 4).
+/// # Examples
+/// ```
+/// use cthash::encoding::{Base64, base64};
+/// const B: Base64<2> = base64(b"fo");
+/// assert_eq!(B.as_str(), "Zm8=");
+/// ```
+pub const fn base64<const N: usize>(input: &[u8; N]) -> Base64<N> {
+    encode_bits(input, BASE64, true)
+}
+
+/// Encode as standard base64 without padding.
+pub const fn base64_nopad<const N: usize>(input: &[u8; N]) -> Base64<N> {
+    encode_bits(input, BASE64, false)
+}
+
+/// Encode as URL and filename safe base64 with `=` padding (RFC 4648, section 5).
+pub const fn base64url<const N: usize>(input: &[u8; N]) -> Base64<N> {
+    encode_bits(input, BASE64_URL, true)
+}
+
+/// Encode as URL and filename safe base64 without padding, as in JWTs.
+pub const fn base64url_nopad<const N: usize>(input: &[u8; N]) -> Base64<N> {
+    encode_bits(input, BASE64_URL, false)
+}
+
+/// Encode as base32 with `=` padding (RFC 4648, section 6).
+pub const fn base32<const N: usize>(input: &[u8; N]) -> Base32<N> {
+    encode_bits(input, BASE32, true)
+}
+
+/// Encode as base32 without padding.
+pub const fn base32_nopad<const N: usize>(input: &[u8; N]) -> Base32<N> {
+    encode_bits(input, BASE32, false)
+}
+
+/// Encode as base32 with the extended hex alphabet and `=` padding (RFC 4648, section 7).
+pub const fn base32hex<const N: usize>(input: &[u8; N]) -> Base32<N> {
+    encode_bits(input, BASE32_HEX, true)
+}
+
+/// Encode as base32 with the extended hex alphabet without padding.
+pub const fn base32hex_nopad<const N: usize>(input: &[u8; N]) -> Base32<N> {
+    encode_bits(input, BASE32_HEX, false)
+}
+
+/// Encode as base58 with the Bitcoin alphabet, leading zero bytes becoming `1`s.
+/// # Examples
+/// ```
+/// use cthash::encoding::{Base58, base58};
+/// const B: Base58<3> = base58(b"\0bb");
+/// assert_eq!(B.as_str(), "18VF");
+/// ```
+pub const fn base58<const N: usize>(input: &[u8; N]) -> Base58<N> {
+    // base-58 digits of the input as a big-endian number, least significant first
+    let mut digits = Base58::<N>::new();
+    let mut zeros = 0;
+    while zeros < N && input[zeros] == 0 {
+        zeros += 1;
+    }
+    let mut i = zeros;
+    while i < N {
+        // at most 256 after each digit, so `value` stays below 2^17
+        let mut carry = input[i] as u16;
+        let mut j = 0;
+        while j < digits.len {
+            let value = ((digits.get(j) as u32) << 8) + carry as u32;
+            digits.set(j, (value % 58) as u8);
+            carry = (value / 58) as u16;
+            j += 1;
+        }
+        while carry != 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+        i += 1;
+    }
+
+    let mut out = Base58::<N>::new();
+    let mut i = 0;
+    while i < zeros {
+        out.push(BASE58[0]);
+        i += 1;
+    }
+    let mut j = digits.len;
+    while j > 0 {
+        j -= 1;
+        out.push(BASE58[digits.get(j) as usize % 58]);
+    }
+    out
+}
+
+/// Message of `assert_digest!` when the digest differs, with room for the
+/// prefix as long as the digest is at least 3 bytes.
+#[doc(hidden)]
+pub const fn __digest_mismatch<const N: usize>(found: &[u8; N]) -> Encoded<N, 16> {
+    let mut out = Encoded::new();
+    let prefix = b"digest mismatch, the input hashes to ";
+    let mut i = 0;
+    while i < prefix.len() {
+        out.push(prefix[i]);
+        i += 1;
+    }
+    let hex = hex(found);
+    let mut i = 0;
+    while i < hex.len {
+        out.push(hex.get(i));
+        i += 1;
+    }
+    out
+}
+
+/// Split the input into groups of as many bits as the alphabet has digits,
+/// most significant first, padding the output to a whole number of bytes with
+/// `=` if requested.
+const fn encode_bits<const N: usize, const W: usize, const A: usize>(input: &[u8; N], alphabet: &[u8; A], pad: bool) -> Encoded<N, W> {
+    let bits = const { A.ilog2() as usize };
+    let mut out = Encoded::new();
+    let mut bit = 0;
+    while bit < 8 * N {
+        // the two bytes holding the group, zero past the end
+        let byte = bit / 8;
+        let high = if byte < N { input[byte] } else { 0 };
+        let low = if byte + 1 < N { input[byte + 1] } else { 0 };
+        let window = (high as usize) << 8 | low as usize;
+        let digit = window >> (16 - bits - bit % 8) & ((1 << bits) - 1);
+        out.push(alphabet[digit % A]);
+        bit += bits;
+    }
+
+    if pad {
+        while out.len < W * N && !out.len.is_multiple_of(chars_per_group(bits)) {
+            out.push(b'=');
+        }
+    }
+    out
+}
+
+/// Characters per group of whole bytes: 4 for base64, 8 for base32.
+const fn chars_per_group(bits: usize) -> usize {
+    let mut chars = 1;
+    while !(chars * bits).is_multiple_of(8) {
+        chars += 1;
+    }
+    chars
+}
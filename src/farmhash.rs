@@ -0,0 +1,102 @@
+use crate::cityhash::{
+    K0, K1, K2, block, fetch64, hash_len_0_to_16, hash_len_16_mul, hash_len_17_to_32, shift_mix, weak_hash_len_32_with_seeds,
+};
+
+/// Compute FarmHash `Fingerprint64`, the function behind BigQuery's
+/// `FARM_FINGERPRINT`, which returns it as `i64`.
+///
+/// Unlike the other FarmHash functions, fingerprints are stable across
+/// platforms and library versions.
+/// # Examples
+/// ```
+/// use cthash::farmhash_fingerprint64;
+/// // `SELECT FARM_FINGERPRINT('')`
+/// const FP: i64 = farmhash_fingerprint64(b"") as i64;
+/// assert_eq!(FP, -7286425919675154353);
+/// ```
+#[inline]
+pub const fn farmhash_fingerprint64(input: &[u8]) -> u64 {
+    const SEED: u64 = 81;
+
+    let len = input.len();
+    if len <= 16 {
+        return hash_len_0_to_16(input);
+    } else if len <= 32 {
+        return hash_len_17_to_32(input);
+    } else if len <= 64 {
+        return hash_len_33_to_64(input);
+    }
+
+    let mut x = SEED;
+    let mut y = SEED.wrapping_mul(K1).wrapping_add(113);
+    let mut z = shift_mix(y.wrapping_mul(K2).wrapping_add(113)).wrapping_mul(K2);
+    let mut v = (0, 0);
+    let mut w = (0, 0);
+    x = x.wrapping_mul(K2).wrapping_add(fetch64(input, 0));
+
+    // all 64-byte blocks but the last one, which is the final 64 bytes of input
+    let (blocks, last) = match (input.split_at_checked((len - 1) / 64 * 64), input.last_chunk::<64>()) {
+        (Some((head, _)), Some(last)) => (head.as_chunks::<64>().0, last),
+        _ => (&[] as &[[u8; 64]], &[0; 64]),
+    };
+    let mut i = 0;
+    while i < blocks.len() {
+        (x, y, z, v, w) = block(&blocks[i], 0, x, y, z, v, w, K1);
+        i += 1;
+    }
+
+    let mul = K1.wrapping_add((z & 0xff) << 1);
+    w.0 = w.0.wrapping_add((len as u64 - 1) & 63);
+    v.0 = v.0.wrapping_add(w.0);
+    w.0 = w.0.wrapping_add(v.0);
+    x = x
+        .wrapping_add(y)
+        .wrapping_add(v.0)
+        .wrapping_add(fetch64(last, 8))
+        .rotate_right(37)
+        .wrapping_mul(mul);
+    y = y
+        .wrapping_add(v.1)
+        .wrapping_add(fetch64(last, 48))
+        .rotate_right(42)
+        .wrapping_mul(mul);
+    x ^= w.1.wrapping_mul(9);
+    y = y.wrapping_add(v.0.wrapping_mul(9)).wrapping_add(fetch64(last, 40));
+    z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(mul);
+    v = weak_hash_len_32_with_seeds(last, 0, v.1.wrapping_mul(mul), x.wrapping_add(w.0));
+    w = weak_hash_len_32_with_seeds(last, 32, z.wrapping_add(w.1), y.wrapping_add(fetch64(last, 16)));
+    (z, x) = (x, z);
+
+    hash_len_16_mul(
+        hash_len_16_mul(v.0, w.0, mul)
+            .wrapping_add(shift_mix(y).wrapping_mul(K0))
+            .wrapping_add(z),
+        hash_len_16_mul(v.1, w.1, mul).wrapping_add(x),
+        mul,
+    )
+}
+
+const fn hash_len_33_to_64(input: &[u8]) -> u64 {
+    let len = input.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(input, 0).wrapping_mul(K2);
+    let b = fetch64(input, 8);
+    // the final 32 bytes, `len` being at least 33
+    let end = match input.last_chunk::<32>() {
+        Some(end) => end,
+        None => &[0; 32],
+    };
+    let c = fetch64(end, 24).wrapping_mul(mul);
+    let d = fetch64(end, 16).wrapping_mul(K2);
+    let y = a.wrapping_add(b).rotate_right(43).wrapping_add(c.rotate_right(30)).wrapping_add(d);
+    let z = hash_len_16_mul(y, a.wrapping_add(b.wrapping_add(K2).rotate_right(18)).wrapping_add(c), mul);
+    let e = fetch64(input, 16).wrapping_mul(mul);
+    let f = fetch64(input, 24);
+    let g = y.wrapping_add(fetch64(end, 0)).wrapping_mul(mul);
+    let h = z.wrapping_add(fetch64(end, 8)).wrapping_mul(mul);
+    hash_len_16_mul(
+        e.wrapping_add(f).rotate_right(43).wrapping_add(g.rotate_right(30)).wrapping_add(h),
+        e.wrapping_add(f.wrapping_add(a).rotate_right(18)).wrapping_add(g),
+        mul,
+    )
+}
@@ -0,0 +1,350 @@
+/// Prime field with a modulus below 2^256.
+///
+/// Elements are kept in Montgomery form as four little-endian 64-bit limbs,
+/// so every operation is a `const fn` without allocation or panics. The
+/// modulus must be an odd prime, which is not checked.
+///
+/// # Examples
+/// ```
+/// use cthash::BN254_FR;
+///
+/// const A: cthash::FieldElement = BN254_FR.from_u64(3);
+/// const B: cthash::FieldElement = BN254_FR.inv(&A);
+/// assert_eq!(BN254_FR.mul(&A, &B), BN254_FR.one());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrimeField {
+    modulus: [u64; 4],
+    // R^2 mod p, where R = 2^256
+    r2: [u64; 4],
+    // -p^-1 mod 2^64
+    inv: u64,
+    bits: u32,
+}
+
+/// Element of a [`PrimeField`] in Montgomery form.
+///
+/// An element is only meaningful together with the field that created it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct FieldElement(pub(crate) [u64; 4]);
+
+/// Scalar field of the BN254 (alt_bn128) curve.
+pub const BN254_FR: PrimeField = PrimeField::new(&[
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9,
+    0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+]);
+
+/// Scalar field of the BLS12-381 curve.
+pub const BLS12_381_FR: PrimeField = PrimeField::new(&[
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05, 0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe,
+    0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+]);
+
+impl PrimeField {
+    /// Create a field from its big-endian modulus.
+    pub const fn new(modulus: &[u8; 32]) -> Self {
+        let modulus = limbs_from_be_bytes(modulus);
+
+        // Newton iteration for p^-1 mod 2^64, each step doubles correct bits
+        let mut inv = 1u64;
+        let mut i = 0;
+        while i < 6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+            i += 1;
+        }
+
+        // R^2 mod p by doubling 1 for 512 times
+        let mut r2 = [1, 0, 0, 0];
+        i = 0;
+        while i < 512 {
+            r2 = double_mod(&r2, &modulus);
+            i += 1;
+        }
+
+        let bits = 256 - leading_zeros(&modulus);
+
+        Self {
+            modulus,
+            r2,
+            inv: inv.wrapping_neg(),
+            bits,
+        }
+    }
+
+    /// Number of significant bits of the modulus.
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Big-endian modulus.
+    pub const fn modulus(&self) -> [u8; 32] {
+        limbs_to_be_bytes(&self.modulus)
+    }
+
+    /// Additive identity.
+    pub const fn zero(&self) -> FieldElement {
+        FieldElement([0; 4])
+    }
+
+    /// Multiplicative identity.
+    pub const fn one(&self) -> FieldElement {
+        self.from_u64(1)
+    }
+
+    /// Convert an integer into a field element.
+    pub const fn from_u64(&self, value: u64) -> FieldElement {
+        self.reduce(&[value, 0, 0, 0])
+    }
+
+    /// Convert a big-endian integer into a field element, reducing it modulo p.
+    pub const fn from_be_bytes(&self, bytes: &[u8; 32]) -> FieldElement {
+        self.reduce(&limbs_from_be_bytes(bytes))
+    }
+
+    /// Convert a little-endian integer into a field element, reducing it modulo p.
+    pub const fn from_le_bytes(&self, bytes: &[u8; 32]) -> FieldElement {
+        let (chunks, _) = bytes.as_chunks();
+        self.reduce(&[
+            u64::from_le_bytes(chunks[0]),
+            u64::from_le_bytes(chunks[1]),
+            u64::from_le_bytes(chunks[2]),
+            u64::from_le_bytes(chunks[3]),
+        ])
+    }
+
+    /// Big-endian canonical representation of a field element.
+    pub const fn to_be_bytes(&self, a: &FieldElement) -> [u8; 32] {
+        let limbs = mont_mul(&a.0, &[1, 0, 0, 0], &self.modulus, self.inv);
+        limbs_to_be_bytes(&limbs)
+    }
+
+    /// Little-endian canonical representation of a field element.
+    pub const fn to_le_bytes(&self, a: &FieldElement) -> [u8; 32] {
+        let limbs = mont_mul(&a.0, &[1, 0, 0, 0], &self.modulus, self.inv);
+        let mut out = [0u8; 32];
+        let (chunks, _) = out.as_chunks_mut();
+        let mut i = 0;
+        while i < 4 {
+            chunks[i] = limbs[i].to_le_bytes();
+            i += 1;
+        }
+        out
+    }
+
+    /// Compute `a + b`.
+    pub const fn add(&self, a: &FieldElement, b: &FieldElement) -> FieldElement {
+        let (sum, carry) = add_limbs(&a.0, &b.0);
+        if carry || !lt(&sum, &self.modulus) {
+            FieldElement(sub_limbs(&sum, &self.modulus).0)
+        } else {
+            FieldElement(sum)
+        }
+    }
+
+    /// Compute `a - b`.
+    pub const fn sub(&self, a: &FieldElement, b: &FieldElement) -> FieldElement {
+        let (diff, borrow) = sub_limbs(&a.0, &b.0);
+        if borrow {
+            FieldElement(add_limbs(&diff, &self.modulus).0)
+        } else {
+            FieldElement(diff)
+        }
+    }
+
+    /// Compute `-a`.
+    pub const fn neg(&self, a: &FieldElement) -> FieldElement {
+        self.sub(&self.zero(), a)
+    }
+
+    /// Compute `a * b`.
+    pub const fn mul(&self, a: &FieldElement, b: &FieldElement) -> FieldElement {
+        FieldElement(mont_mul(&a.0, &b.0, &self.modulus, self.inv))
+    }
+
+    /// Compute `a * a`.
+    pub const fn square(&self, a: &FieldElement) -> FieldElement {
+        self.mul(a, a)
+    }
+
+    /// Compute `a` raised to a little-endian 256-bit exponent.
+    pub const fn pow(&self, a: &FieldElement, exp: &[u64; 4]) -> FieldElement {
+        let mut acc = self.one();
+        // squaring is skipped until the most significant set bit
+        let mut started = false;
+        let mut limb = 4;
+        while limb > 0 {
+            limb -= 1;
+            let mut bit = 64;
+            while bit > 0 {
+                bit -= 1;
+                if started {
+                    acc = self.square(&acc);
+                }
+                if (exp[limb] >> bit) & 1 == 1 {
+                    acc = self.mul(&acc, a);
+                    started = true;
+                }
+            }
+        }
+        acc
+    }
+
+    /// Compute `a` raised to a small exponent, such as an S-box power.
+    pub const fn pow_u64(&self, a: &FieldElement, exp: u64) -> FieldElement {
+        self.pow(a, &[exp, 0, 0, 0])
+    }
+
+    /// Compute `a^-1`, or zero for zero.
+    pub const fn inv(&self, a: &FieldElement) -> FieldElement {
+        let (p_minus_2, _) = sub_limbs(&self.modulus, &[2, 0, 0, 0]);
+        self.pow(a, &p_minus_2)
+    }
+
+    /// Check if the element is zero.
+    pub const fn is_zero(&self, a: &FieldElement) -> bool {
+        eq(&a.0, &[0; 4])
+    }
+
+    /// Check if two elements are equal.
+    pub const fn eq(&self, a: &FieldElement, b: &FieldElement) -> bool {
+        eq(&a.0, &b.0)
+    }
+
+    /// Reduce an integer modulo p and convert it into Montgomery form.
+    pub(crate) const fn reduce(&self, limbs: &[u64; 4]) -> FieldElement {
+        // any a < R works here: a * R^2 < R * p keeps the product below 2p before the final subtraction
+        FieldElement(mont_mul(limbs, &self.r2, &self.modulus, self.inv))
+    }
+
+    /// Check if an integer is a canonical field element, i.e. less than p.
+    pub(crate) const fn is_canonical(&self, limbs: &[u64; 4]) -> bool {
+        lt(limbs, &self.modulus)
+    }
+}
+
+#[inline(always)]
+const fn limbs_from_be_bytes(bytes: &[u8; 32]) -> [u64; 4] {
+    let (chunks, _) = bytes.as_chunks();
+    [u64::from_be_bytes(chunks[3]), u64::from_be_bytes(chunks[2]), u64::from_be_bytes(chunks[1]), u64::from_be_bytes(chunks[0])]
+}
+
+#[inline(always)]
+const fn limbs_to_be_bytes(limbs: &[u64; 4]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let (chunks, _) = out.as_chunks_mut();
+    let mut i = 0;
+    while i < 4 {
+        chunks[i] = limbs[3 - i].to_be_bytes();
+        i += 1;
+    }
+    out
+}
+
+#[inline(always)]
+const fn leading_zeros(a: &[u64; 4]) -> u32 {
+    let mut i = 4;
+    let mut zeros = 0;
+    while i > 0 {
+        i -= 1;
+        zeros += a[i].leading_zeros();
+        if a[i] != 0 {
+            break;
+        }
+    }
+    zeros
+}
+
+#[inline(always)]
+const fn eq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    a[0] == b[0] && a[1] == b[1] && a[2] == b[2] && a[3] == b[3]
+}
+
+#[inline(always)]
+const fn lt(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+#[inline(always)]
+const fn add_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+    let mut i = 0;
+    while i < 4 {
+        let (s0, c0) = a[i].overflowing_add(b[i]);
+        let (s1, c1) = s0.overflowing_add(carry as u64);
+        out[i] = s1;
+        carry = c0 | c1;
+        i += 1;
+    }
+    (out, carry)
+}
+
+#[inline(always)]
+const fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut out = [0u64; 4];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < 4 {
+        let (d0, b0) = a[i].overflowing_sub(b[i]);
+        let (d1, b1) = d0.overflowing_sub(borrow as u64);
+        out[i] = d1;
+        borrow = b0 | b1;
+        i += 1;
+    }
+    (out, borrow)
+}
+
+/// Compute `2 * a mod p` for `a < p`.
+#[inline(always)]
+const fn double_mod(a: &[u64; 4], p: &[u64; 4]) -> [u64; 4] {
+    let (sum, carry) = add_limbs(a, a);
+    if carry || !lt(&sum, p) { sub_limbs(&sum, p).0 } else { sum }
+}
+
+/// Compute `a + b * c + carry` as a low and high word.
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let r = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (r as u64, (r >> 64) as u64)
+}
+
+/// Montgomery multiplication `a * b * R^-1 mod p` (CIOS).
+const fn mont_mul(a: &[u64; 4], b: &[u64; 4], p: &[u64; 4], inv: u64) -> [u64; 4] {
+    let mut t = [0u64; 6];
+
+    let mut i = 0;
+    while i < 4 {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 4 {
+            (t[j], carry) = mac(t[j], a[i], b[j], carry);
+            j += 1;
+        }
+        let (sum, overflow) = t[4].overflowing_add(carry);
+        t[4] = sum;
+        t[5] = overflow as u64;
+
+        let m = t[0].wrapping_mul(inv);
+        (_, carry) = mac(t[0], m, p[0], 0);
+        j = 1;
+        while j < 4 {
+            (t[j - 1], carry) = mac(t[j], m, p[j], carry);
+            j += 1;
+        }
+        let (sum, overflow) = t[4].overflowing_add(carry);
+        t[3] = sum;
+        t[4] = t[5] + overflow as u64;
+
+        i += 1;
+    }
+
+    let out = [t[0], t[1], t[2], t[3]];
+    if t[4] != 0 || !lt(&out, p) { sub_limbs(&out, p).0 } else { out }
+}
@@ -0,0 +1,181 @@
+/// Compute 32-bit FNV-1 hash, big-endian like its usual hex notation.
+/// # Examples
+/// ```
+/// use cthash::fnv1_32;
+/// const H: [u8; 4] = fnv1_32(b"data");
+/// ```
+pub const fn fnv1_32(input: &[u8]) -> [u8; 4] {
+    fnv1_32_value(input).to_be_bytes()
+}
+
+/// Compute 32-bit FNV-1 hash as an integer.
+/// # Examples
+/// ```
+/// use cthash::fnv1_32_value;
+/// const H: u32 = fnv1_32_value(b"data");
+/// ```
+pub const fn fnv1_32_value(input: &[u8]) -> u32 {
+    fnv32::<false>(input)
+}
+
+/// Compute 64-bit FNV-1 hash, big-endian like its usual hex notation.
+/// # Examples
+/// ```
+/// use cthash::fnv1_64;
+/// const H: [u8; 8] = fnv1_64(b"data");
+/// ```
+pub const fn fnv1_64(input: &[u8]) -> [u8; 8] {
+    fnv1_64_value(input).to_be_bytes()
+}
+
+/// Compute 64-bit FNV-1 hash as an integer.
+/// # Examples
+/// ```
+/// use cthash::fnv1_64_value;
+/// const H: u64 = fnv1_64_value(b"data");
+/// ```
+pub const fn fnv1_64_value(input: &[u8]) -> u64 {
+    fnv64::<false>(input)
+}
+
+/// Compute 128-bit FNV-1 hash, big-endian like its usual hex notation.
+/// # Examples
+/// ```
+/// use cthash::fnv1_128;
+/// const H: [u8; 16] = fnv1_128(b"data");
+/// ```
+pub const fn fnv1_128(input: &[u8]) -> [u8; 16] {
+    fnv1_128_value(input).to_be_bytes()
+}
+
+/// Compute 128-bit FNV-1 hash as an integer.
+/// # Examples
+/// ```
+/// use cthash::fnv1_128_value;
+/// const H: u128 = fnv1_128_value(b"data");
+/// ```
+pub const fn fnv1_128_value(input: &[u8]) -> u128 {
+    fnv128::<false>(input)
+}
+
+/// Compute 32-bit FNV-1a hash, big-endian like its usual hex notation.
+/// # Examples
+/// ```
+/// use cthash::fnv1a_32;
+/// const H: [u8; 4] = fnv1a_32(b"data");
+/// ```
+pub const fn fnv1a_32(input: &[u8]) -> [u8; 4] {
+    fnv1a_32_value(input).to_be_bytes()
+}
+
+/// Compute 32-bit FNV-1a hash as an integer.
+/// # Examples
+/// ```
+/// use cthash::fnv1a_32_value;
+///
+/// const fn kind(tag: &[u8]) -> u8 {
+///     match fnv1a_32_value(tag) {
+///         h if h == fnv1a_32_value(b"png") => 1,
+///         h if h == fnv1a_32_value(b"jpeg") => 2,
+///         _ => 0,
+///     }
+/// }
+/// assert_eq!(kind(b"jpeg"), 2);
+/// ```
+pub const fn fnv1a_32_value(input: &[u8]) -> u32 {
+    fnv32::<true>(input)
+}
+
+/// Compute 64-bit FNV-1a hash, big-endian like its usual hex notation.
+/// # Examples
+/// ```
+/// use cthash::fnv1a_64;
+/// const H: [u8; 8] = fnv1a_64(b"data");
+/// ```
+pub const fn fnv1a_64(input: &[u8]) -> [u8; 8] {
+    fnv1a_64_value(input).to_be_bytes()
+}
+
+/// Compute 64-bit FNV-1a hash as an integer.
+/// # Examples
+/// ```
+/// use cthash::fnv1a_64_value;
+/// const H: u64 = fnv1a_64_value(b"data");
+/// ```
+pub const fn fnv1a_64_value(input: &[u8]) -> u64 {
+    fnv64::<true>(input)
+}
+
+/// Compute 128-bit FNV-1a hash, big-endian like its usual hex notation.
+/// # Examples
+/// ```
+/// use cthash::fnv1a_128;
+/// const H: [u8; 16] = fnv1a_128(b"data");
+/// ```
+pub const fn fnv1a_128(input: &[u8]) -> [u8; 16] {
+    fnv1a_128_value(input).to_be_bytes()
+}
+
+/// Compute 128-bit FNV-1a hash as an integer.
+/// # Examples
+/// ```
+/// use cthash::fnv1a_128_value;
+/// const H: u128 = fnv1a_128_value(b"data");
+/// ```
+pub const fn fnv1a_128_value(input: &[u8]) -> u128 {
+    fnv128::<true>(input)
+}
+
+#[inline(always)]
+const fn fnv32<const ALTERNATE: bool>(input: &[u8]) -> u32 {
+    const OFFSET_BASIS: u32 = 0x811c9dc5;
+    const PRIME: u32 = 0x01000193;
+
+    let mut h = OFFSET_BASIS;
+    let mut i = 0;
+    while i < input.len() {
+        if ALTERNATE {
+            h = (h ^ input[i] as u32).wrapping_mul(PRIME);
+        } else {
+            h = h.wrapping_mul(PRIME) ^ input[i] as u32;
+        }
+        i += 1;
+    }
+    h
+}
+
+#[inline(always)]
+const fn fnv64<const ALTERNATE: bool>(input: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x00000100000001b3;
+
+    let mut h = OFFSET_BASIS;
+    let mut i = 0;
+    while i < input.len() {
+        if ALTERNATE {
+            h = (h ^ input[i] as u64).wrapping_mul(PRIME);
+        } else {
+            h = h.wrapping_mul(PRIME) ^ input[i] as u64;
+        }
+        i += 1;
+    }
+    h
+}
+
+#[inline(always)]
+const fn fnv128<const ALTERNATE: bool>(input: &[u8]) -> u128 {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    let mut h = OFFSET_BASIS;
+    let mut i = 0;
+    while i < input.len() {
+        if ALTERNATE {
+            h = (h ^ input[i] as u128).wrapping_mul(PRIME);
+        } else {
+            h = h.wrapping_mul(PRIME) ^ input[i] as u128;
+        }
+        i += 1;
+    }
+    h
+}
@@ -0,0 +1,223 @@
+/// Compute 64-bit HighwayHash of `data` with a 256-bit key.
+///
+/// The key is given as four words, like the `uint64_t key[4]` of the
+/// reference implementation.
+/// # Examples
+/// ```
+/// use cthash::highwayhash64;
+/// const KEY: [u64; 4] = [0x0706050403020100, 0x0f0e0d0c0b0a0908, 0x1716151413121110, 0x1f1e1d1c1b1a1918];
+/// assert_eq!(highwayhash64(&KEY, b""), 0x907a56de22c26e53);
+/// ```
+pub const fn highwayhash64(key: &[u64; 4], data: &[u8]) -> u64 {
+    let mut state = State::new(key);
+    state.update(data);
+    state.finalize64()
+}
+
+/// Compute 128-bit HighwayHash of `data` with a 256-bit key, as the two
+/// words of the reference implementation.
+/// # Examples
+/// ```
+/// use cthash::highwayhash128;
+/// const H: [u64; 2] = highwayhash128(&[1, 2, 3, 4], b"data");
+/// ```
+pub const fn highwayhash128(key: &[u64; 4], data: &[u8]) -> [u64; 2] {
+    let mut state = State::new(key);
+    state.update(data);
+    state.finalize128()
+}
+
+/// Compute 256-bit HighwayHash of `data` with a 256-bit key, as the four
+/// words of the reference implementation.
+/// # Examples
+/// ```
+/// use cthash::highwayhash256;
+/// const H: [u64; 4] = highwayhash256(&[1, 2, 3, 4], b"data");
+/// ```
+pub const fn highwayhash256(key: &[u64; 4], data: &[u8]) -> [u64; 4] {
+    let mut state = State::new(key);
+    state.update(data);
+    state.finalize256()
+}
+
+struct State {
+    v0: [u64; 4],
+    v1: [u64; 4],
+    mul0: [u64; 4],
+    mul1: [u64; 4],
+}
+
+impl State {
+    const fn new(key: &[u64; 4]) -> Self {
+        let mul0 = [0xdbe6d5d5fe4cce2f, 0xa4093822299f31d0, 0x13198a2e03707344, 0x243f6a8885a308d3];
+        let mul1 = [0x3bd39e10cb0ef593, 0xc0acf169b5f18a8c, 0xbe5466cf34e90c6c, 0x452821e638d01377];
+        let mut v0 = [0; 4];
+        let mut v1 = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            v0[i] = mul0[i] ^ key[i];
+            v1[i] = mul1[i] ^ key[i].rotate_left(32);
+            i += 1;
+        }
+        Self { v0, v1, mul0, mul1 }
+    }
+
+    /// Process all 32-byte packets, then the zero-padded remainder if any.
+    const fn update(&mut self, data: &[u8]) {
+        let (packets, remainder) = data.as_chunks::<32>();
+        let mut i = 0;
+        while i < packets.len() {
+            self.update_packet(&packets[i]);
+            i += 1;
+        }
+        if !remainder.is_empty() {
+            self.update_remainder(remainder);
+        }
+    }
+
+    const fn update_remainder(&mut self, remainder: &[u8]) {
+        let size = remainder.len();
+        let (whole, tail) = remainder.split_at(size & !3);
+
+        let mut i = 0;
+        while i < 4 {
+            self.v0[i] = self.v0[i].wrapping_add(((size as u64) << 32).wrapping_add(size as u64));
+            // rotate both 32-bit halves by the remainder size
+            let low = (self.v1[i] as u32).rotate_left(size as u32);
+            let high = ((self.v1[i] >> 32) as u32).rotate_left(size as u32);
+            self.v1[i] = (high as u64) << 32 | low as u64;
+            i += 1;
+        }
+
+        let mut packet = [0u8; 32];
+        if let Some((head, _)) = packet.split_at_mut_checked(whole.len()) {
+            head.copy_from_slice(whole);
+        }
+        if size & 16 != 0 {
+            // the last 4 bytes of input, overlapping the whole words
+            if let (Some(last), Some((_, dst))) = (remainder.last_chunk::<4>(), packet.split_last_chunk_mut::<4>()) {
+                *dst = *last;
+            }
+        } else {
+            // the first, middle and last of the up to three bytes left
+            match tail {
+                [a] => [packet[16], packet[17], packet[18]] = [*a, *a, *a],
+                [a, b] => [packet[16], packet[17], packet[18]] = [*a, *b, *b],
+                [a, b, c] => [packet[16], packet[17], packet[18]] = [*a, *b, *c],
+                _ => {}
+            }
+        }
+        self.update_packet(&packet);
+    }
+
+    const fn update_packet(&mut self, packet: &[u8; 32]) {
+        let (words, _) = packet.as_chunks::<8>();
+        self.update_lanes(&[
+            u64::from_le_bytes(words[0]),
+            u64::from_le_bytes(words[1]),
+            u64::from_le_bytes(words[2]),
+            u64::from_le_bytes(words[3]),
+        ]);
+    }
+
+    const fn update_lanes(&mut self, lanes: &[u64; 4]) {
+        let mut i = 0;
+        while i < 4 {
+            self.v1[i] = self.v1[i].wrapping_add(self.mul0[i].wrapping_add(lanes[i]));
+            self.mul0[i] ^= (self.v1[i] & 0xffffffff).wrapping_mul(self.v0[i] >> 32);
+            self.v0[i] = self.v0[i].wrapping_add(self.mul1[i]);
+            self.mul1[i] ^= (self.v0[i] & 0xffffffff).wrapping_mul(self.v1[i] >> 32);
+            i += 1;
+        }
+        (self.v0[1], self.v0[0]) = zipper_merge_and_add(self.v1[1], self.v1[0], self.v0[1], self.v0[0]);
+        (self.v0[3], self.v0[2]) = zipper_merge_and_add(self.v1[3], self.v1[2], self.v0[3], self.v0[2]);
+        (self.v1[1], self.v1[0]) = zipper_merge_and_add(self.v0[1], self.v0[0], self.v1[1], self.v1[0]);
+        (self.v1[3], self.v1[2]) = zipper_merge_and_add(self.v0[3], self.v0[2], self.v1[3], self.v1[2]);
+    }
+
+    const fn finalize64(mut self) -> u64 {
+        let mut i = 0;
+        while i < 4 {
+            self.permute_and_update();
+            i += 1;
+        }
+        self.v0[0]
+            .wrapping_add(self.v1[0])
+            .wrapping_add(self.mul0[0])
+            .wrapping_add(self.mul1[0])
+    }
+
+    const fn finalize128(mut self) -> [u64; 2] {
+        let mut i = 0;
+        while i < 6 {
+            self.permute_and_update();
+            i += 1;
+        }
+        let Self { v0, v1, mul0, mul1 } = self;
+        [
+            v0[0].wrapping_add(mul0[0]).wrapping_add(v1[2]).wrapping_add(mul1[2]),
+            v0[1].wrapping_add(mul0[1]).wrapping_add(v1[3]).wrapping_add(mul1[3]),
+        ]
+    }
+
+    const fn finalize256(mut self) -> [u64; 4] {
+        let mut i = 0;
+        while i < 10 {
+            self.permute_and_update();
+            i += 1;
+        }
+        let Self { v0, v1, mul0, mul1 } = self;
+        let (h1, h0) = modular_reduction(
+            v1[1].wrapping_add(mul1[1]),
+            v1[0].wrapping_add(mul1[0]),
+            v0[1].wrapping_add(mul0[1]),
+            v0[0].wrapping_add(mul0[0]),
+        );
+        let (h3, h2) = modular_reduction(
+            v1[3].wrapping_add(mul1[3]),
+            v1[2].wrapping_add(mul1[2]),
+            v0[3].wrapping_add(mul0[3]),
+            v0[2].wrapping_add(mul0[2]),
+        );
+        [h0, h1, h2, h3]
+    }
+
+    /// Absorb `v0` with its lanes swapped in pairs and halves exchanged.
+    const fn permute_and_update(&mut self) {
+        let v0 = self.v0;
+        self.update_lanes(&[v0[2].rotate_left(32), v0[3].rotate_left(32), v0[0].rotate_left(32), v0[1].rotate_left(32)]);
+    }
+}
+
+/// Add the bytes of `v1:v0` shuffled to lie across multiplier inputs to `add1:add0`.
+#[inline(always)]
+const fn zipper_merge_and_add(v1: u64, v0: u64, add1: u64, add0: u64) -> (u64, u64) {
+    let add0 = add0.wrapping_add(
+        (((v0 & 0xff000000) | (v1 & 0xff00000000)) >> 24)
+            | (((v0 & 0xff0000000000) | (v1 & 0xff000000000000)) >> 16)
+            | (v0 & 0xff0000)
+            | ((v0 & 0xff00) << 32)
+            | ((v1 & 0xff00000000000000) >> 8)
+            | (v0 << 56),
+    );
+    let add1 = add1.wrapping_add(
+        (((v1 & 0xff000000) | (v0 & 0xff00000000)) >> 24)
+            | (v1 & 0xff0000)
+            | ((v1 & 0xff0000000000) >> 16)
+            | ((v1 & 0xff00) << 24)
+            | ((v0 & 0xff000000000000) >> 8)
+            | ((v1 & 0xff) << 48)
+            | (v0 & 0xff00000000000000),
+    );
+    (add1, add0)
+}
+
+/// `ModularReduction` of the 256-bit `a3:a2:a1:a0` (top two bits cleared) to a
+/// 128-bit word pair, returning the high and low word.
+#[inline(always)]
+const fn modular_reduction(a3_unmasked: u64, a2: u64, a1: u64, a0: u64) -> (u64, u64) {
+    let a3 = a3_unmasked & 0x3fffffffffffffff;
+    let m1 = a1 ^ ((a3 << 1) | (a2 >> 63)) ^ ((a3 << 2) | (a2 >> 62));
+    let m0 = a0 ^ (a2 << 1) ^ (a2 << 2);
+    (m1, m0)
+}
@@ -0,0 +1,124 @@
+/// Compute Bob Jenkins' one-at-a-time hash, big-endian.
+/// # Examples
+/// ```
+/// use cthash::jenkins_oaat;
+/// const H: [u8; 4] = jenkins_oaat(b"data");
+/// ```
+pub const fn jenkins_oaat(input: &[u8]) -> [u8; 4] {
+    jenkins_oaat_value(input).to_be_bytes()
+}
+
+/// Compute Bob Jenkins' one-at-a-time hash as an integer.
+/// # Examples
+/// ```
+/// use cthash::jenkins_oaat_value;
+/// assert_eq!(jenkins_oaat_value(b"a"), 0xca2e9442);
+/// ```
+pub const fn jenkins_oaat_value(input: &[u8]) -> u32 {
+    let mut h = 0u32;
+    let mut i = 0;
+    while i < input.len() {
+        h = h.wrapping_add(input[i] as u32);
+        h = h.wrapping_add(h << 10);
+        h ^= h >> 6;
+        i += 1;
+    }
+    h = h.wrapping_add(h << 3);
+    h ^= h >> 11;
+    h.wrapping_add(h << 15)
+}
+
+/// Compute `hashlittle2` of lookup3 with a 64-bit seed, big-endian.
+///
+/// See [`lookup3_hashlittle2_value`] for how the seed and the result map to
+/// the two 32-bit values of the C function.
+/// # Examples
+/// ```
+/// use cthash::lookup3_hashlittle2;
+/// const H: [u8; 8] = lookup3_hashlittle2(b"data", 0);
+/// ```
+pub const fn lookup3_hashlittle2(input: &[u8], seed: u64) -> [u8; 8] {
+    lookup3_hashlittle2_value(input, seed).to_be_bytes()
+}
+
+/// Compute `hashlittle2` of lookup3 with a 64-bit seed as an integer.
+///
+/// The low 32 bits of `seed` are the primary initial value `*pc` and the
+/// high bits the secondary `*pb`. The result packs the primary hash (the one
+/// `hashlittle` returns when `*pb` is 0) into the low bits, i.e.
+/// `*pc + ((uint64_t)*pb << 32)` as suggested in `lookup3.c`.
+/// # Examples
+/// ```
+/// use cthash::lookup3_hashlittle2_value;
+/// // `hashlittle("Four score and seven years ago", 30, 0)` of the lookup3.c self-test
+/// assert_eq!(lookup3_hashlittle2_value(b"Four score and seven years ago", 0) as u32, 0x17770551);
+/// ```
+#[inline]
+pub const fn lookup3_hashlittle2_value(input: &[u8], seed: u64) -> u64 {
+    let init = 0xdeadbeefu32.wrapping_add(input.len() as u32).wrapping_add(seed as u32);
+    let mut a = init;
+    let mut b = init;
+    let mut c = init.wrapping_add((seed >> 32) as u32);
+
+    if !input.is_empty() {
+        // all but the last 1 to 12 bytes go through `mix`
+        let mut rest = input;
+        while let Some((block, tail)) = rest.split_first_chunk::<12>()
+            && !tail.is_empty()
+        {
+            let (x, y, z) = load(block);
+            (a, b, c) = mix(a.wrapping_add(x), b.wrapping_add(y), c.wrapping_add(z));
+            rest = tail;
+        }
+
+        let mut last = [0u8; 12];
+        if let Some((head, _)) = last.split_at_mut_checked(rest.len()) {
+            head.copy_from_slice(rest);
+        }
+        let (x, y, z) = load(&last);
+        (b, c) = final_mix(a.wrapping_add(x), b.wrapping_add(y), c.wrapping_add(z));
+    }
+
+    (b as u64) << 32 | c as u64
+}
+
+/// Block of 12 bytes as three little-endian words.
+#[inline(always)]
+const fn load(block: &[u8; 12]) -> (u32, u32, u32) {
+    let [a0, a1, a2, a3, b0, b1, b2, b3, c0, c1, c2, c3] = *block;
+    (
+        u32::from_le_bytes([a0, a1, a2, a3]),
+        u32::from_le_bytes([b0, b1, b2, b3]),
+        u32::from_le_bytes([c0, c1, c2, c3]),
+    )
+}
+
+#[inline(always)]
+const fn mix(mut a: u32, mut b: u32, mut c: u32) -> (u32, u32, u32) {
+    a = a.wrapping_sub(c) ^ c.rotate_left(4);
+    c = c.wrapping_add(b);
+    b = b.wrapping_sub(a) ^ a.rotate_left(6);
+    a = a.wrapping_add(c);
+    c = c.wrapping_sub(b) ^ b.rotate_left(8);
+    b = b.wrapping_add(a);
+    a = a.wrapping_sub(c) ^ c.rotate_left(16);
+    c = c.wrapping_add(b);
+    b = b.wrapping_sub(a) ^ a.rotate_left(19);
+    a = a.wrapping_add(c);
+    c = c.wrapping_sub(b) ^ b.rotate_left(4);
+    b = b.wrapping_add(a);
+    (a, b, c)
+}
+
+/// `final` of lookup3.c, only `b` and `c` are used afterwards.
+#[inline(always)]
+const fn final_mix(mut a: u32, mut b: u32, mut c: u32) -> (u32, u32) {
+    c = (c ^ b).wrapping_sub(b.rotate_left(14));
+    a = (a ^ c).wrapping_sub(c.rotate_left(11));
+    b = (b ^ a).wrapping_sub(a.rotate_left(25));
+    c = (c ^ b).wrapping_sub(b.rotate_left(16));
+    a = (a ^ c).wrapping_sub(c.rotate_left(4));
+    b = (b ^ a).wrapping_sub(a.rotate_left(14));
+    c = (c ^ b).wrapping_sub(b.rotate_left(24));
+    (b, c)
+}
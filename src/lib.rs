@@ -32,26 +32,267 @@
 //!
 //! ```
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod block_api;
 
+mod algorithm;
+mod ascon;
+mod blake2;
+mod cityhash;
+mod crc;
+mod digest;
+mod dynhash;
+mod farmhash;
+mod field;
+mod fnv;
+mod highwayhash;
+mod jenkins;
+mod macros;
+mod md2;
 mod md4;
 mod md5;
+mod md6;
+mod murmur;
+mod poseidon;
+mod rapidhash;
 mod sha1;
 mod sha2;
 mod sha3;
+mod siphash;
+mod skein;
+mod threefish;
+mod tiger;
+mod wyhash;
+mod xxhash;
 
+pub use algorithm::AsconHash256;
+pub use algorithm::AsconHash256Hasher;
+pub use algorithm::Blake2b512;
+pub use algorithm::Blake2b512Hasher;
+pub use algorithm::HashAlgorithm;
+pub use algorithm::Keccak224;
+pub use algorithm::Keccak224Hasher;
+pub use algorithm::Keccak256;
+pub use algorithm::Keccak256Hasher;
+pub use algorithm::Keccak384;
+pub use algorithm::Keccak384Hasher;
+pub use algorithm::Keccak512;
+pub use algorithm::Keccak512Hasher;
+pub use algorithm::Md2;
+pub use algorithm::Md2Hasher;
+pub use algorithm::Md4;
+pub use algorithm::Md4Hasher;
+pub use algorithm::Md5;
+pub use algorithm::Md5Hasher;
+pub use algorithm::Sha1;
+pub use algorithm::Sha1Hasher;
+pub use algorithm::Sha3_224;
+pub use algorithm::Sha3_224Hasher;
+pub use algorithm::Sha3_256;
+pub use algorithm::Sha3_256Hasher;
+pub use algorithm::Sha3_384;
+pub use algorithm::Sha3_384Hasher;
+pub use algorithm::Sha3_512;
+pub use algorithm::Sha3_512Hasher;
+pub use algorithm::Sha224;
+pub use algorithm::Sha224Hasher;
+pub use algorithm::Sha256;
+pub use algorithm::Sha256Hasher;
+pub use algorithm::Sha384;
+pub use algorithm::Sha384Hasher;
+pub use algorithm::Sha512;
+pub use algorithm::Sha512Hasher;
+pub use algorithm::Tiger;
+pub use algorithm::Tiger2;
+pub use algorithm::Tiger2Hasher;
+pub use algorithm::TigerHasher;
+pub use ascon::ascon_cxof128;
+pub use ascon::ascon_hash256;
+pub use ascon::ascon_xof128;
+pub use blake2::blake2b;
+pub use cityhash::cityhash64;
+pub use cityhash::cityhash128;
+pub use crc::CRC_3_GSM;
+pub use crc::CRC_5_USB;
+pub use crc::CRC_8_AUTOSAR;
+pub use crc::CRC_8_MAXIM_DOW;
+pub use crc::CRC_8_SMBUS;
+pub use crc::CRC_12_UMTS;
+pub use crc::CRC_16_ARC;
+pub use crc::CRC_16_IBM_3740;
+pub use crc::CRC_16_IBM_SDLC;
+pub use crc::CRC_16_KERMIT;
+pub use crc::CRC_16_MODBUS;
+pub use crc::CRC_16_USB;
+pub use crc::CRC_16_XMODEM;
+pub use crc::CRC_24_OPENPGP;
+pub use crc::CRC_32_AUTOSAR;
+pub use crc::CRC_32_BZIP2;
+pub use crc::CRC_32_CKSUM;
+pub use crc::CRC_32_ISCSI;
+pub use crc::CRC_32_ISO_HDLC;
+pub use crc::CRC_32_MPEG_2;
+pub use crc::CRC_64_ECMA_182;
+pub use crc::CRC_64_GO_ISO;
+pub use crc::CRC_64_NVME;
+pub use crc::CRC_64_XZ;
+pub use crc::Crc;
+pub use digest::Digest;
+pub use digest::ParseDigestError;
+pub use dynhash::DynDigest;
+pub use dynhash::DynHasher;
+pub use dynhash::HashKind;
+pub use dynhash::ParseHashKindError;
+pub use dynhash::hash;
+#[cfg(feature = "embedded-io")]
+pub use dynhash::hash_embedded_reader;
+#[cfg(feature = "std")]
+pub use dynhash::hash_reader;
+pub use farmhash::farmhash_fingerprint64;
+pub use field::BLS12_381_FR;
+pub use field::BN254_FR;
+pub use field::FieldElement;
+pub use field::PrimeField;
+pub use fnv::fnv1_32;
+pub use fnv::fnv1_32_value;
+pub use fnv::fnv1_64;
+pub use fnv::fnv1_64_value;
+pub use fnv::fnv1_128;
+pub use fnv::fnv1_128_value;
+pub use fnv::fnv1a_32;
+pub use fnv::fnv1a_32_value;
+pub use fnv::fnv1a_64;
+pub use fnv::fnv1a_64_value;
+pub use fnv::fnv1a_128;
+pub use fnv::fnv1a_128_value;
+pub use highwayhash::highwayhash64;
+pub use highwayhash::highwayhash128;
+pub use highwayhash::highwayhash256;
+pub use jenkins::jenkins_oaat;
+pub use jenkins::jenkins_oaat_value;
+pub use jenkins::lookup3_hashlittle2;
+pub use jenkins::lookup3_hashlittle2_value;
+pub use md2::md2;
 pub use md4::md4;
+pub use md4::md4_fixed;
+pub use md4::md4_trunc;
 pub use md5::md5;
+pub use md5::md5_fixed;
+pub use md5::md5_trunc;
+pub use md6::Md6;
+pub use md6::md6;
+pub use murmur::cassandra_token;
+pub use murmur::kafka_partition;
+pub use murmur::murmur2_32;
+pub use murmur::murmur3_32;
+pub use murmur::murmur3_x64_128;
+pub use poseidon::POSEIDON_BLS12_381_X5_3;
+pub use poseidon::POSEIDON_BLS12_381_X5_5;
+pub use poseidon::POSEIDON_BN254_X5_2;
+pub use poseidon::POSEIDON_BN254_X5_3;
+pub use poseidon::POSEIDON_BN254_X5_5;
+pub use poseidon::POSEIDON2_BLS12_381_X5_3;
+pub use poseidon::POSEIDON2_BN254_X5_3;
+pub use poseidon::Poseidon;
+pub use poseidon::Poseidon2;
+pub use rapidhash::rapidhash;
 pub use sha1::sha1;
+pub use sha1::sha1_bits;
+pub use sha1::sha1_fixed;
+pub use sha1::sha1_trunc;
 pub use sha2::sha2_224;
+pub use sha2::sha2_224_bits;
+pub use sha2::sha2_224_fixed;
+pub use sha2::sha2_224_trunc;
 pub use sha2::sha2_256;
+pub use sha2::sha2_256_bits;
+pub use sha2::sha2_256_fixed;
+pub use sha2::sha2_256_trunc;
+pub use sha2::sha2_256d;
 pub use sha2::sha2_384;
+pub use sha2::sha2_384_bits;
+pub use sha2::sha2_384_fixed;
+pub use sha2::sha2_384_trunc;
 pub use sha2::sha2_512;
+pub use sha2::sha2_512_bits;
+pub use sha2::sha2_512_fixed;
+pub use sha2::sha2_512_trunc;
+pub use sha3::KeccakSponge;
 pub use sha3::keccak_224;
+pub use sha3::keccak_224_fixed;
+pub use sha3::keccak_224_trunc;
 pub use sha3::keccak_256;
+pub use sha3::keccak_256_fixed;
+pub use sha3::keccak_256_trunc;
 pub use sha3::keccak_384;
+pub use sha3::keccak_384_fixed;
+pub use sha3::keccak_384_trunc;
 pub use sha3::keccak_512;
+pub use sha3::keccak_512_fixed;
+pub use sha3::keccak_512_trunc;
 pub use sha3::sha3_224;
+pub use sha3::sha3_224_bits;
+pub use sha3::sha3_224_fixed;
+pub use sha3::sha3_224_trunc;
 pub use sha3::sha3_256;
+pub use sha3::sha3_256_bits;
+pub use sha3::sha3_256_fixed;
+pub use sha3::sha3_256_trunc;
 pub use sha3::sha3_384;
+pub use sha3::sha3_384_bits;
+pub use sha3::sha3_384_fixed;
+pub use sha3::sha3_384_trunc;
 pub use sha3::sha3_512;
+pub use sha3::sha3_512_bits;
+pub use sha3::sha3_512_fixed;
+pub use sha3::sha3_512_trunc;
+pub use siphash::SipBuildHasher;
+pub use siphash::SipBuildHasher13;
+pub use siphash::SipBuildHasher24;
+pub use siphash::SipHasher;
+pub use siphash::SipHasher13;
+pub use siphash::SipHasher24;
+pub use siphash::siphash13;
+pub use siphash::siphash13_128;
+pub use siphash::siphash24;
+pub use siphash::siphash24_128;
+pub use skein::Skein;
+pub use skein::Skein256;
+pub use skein::Skein512;
+pub use skein::Skein1024;
+pub use skein::skein256;
+pub use skein::skein512;
+pub use skein::skein1024;
+pub use tiger::tiger;
+pub use tiger::tiger_fixed;
+pub use tiger::tiger_tree;
+pub use tiger::tiger_trunc;
+pub use tiger::tiger2;
+pub use tiger::tiger2_fixed;
+pub use tiger::tiger2_trunc;
+pub use wyhash::wyhash;
+pub use xxhash::xxh3_64;
+pub use xxhash::xxh3_64_with_secret;
+pub use xxhash::xxh3_128;
+pub use xxhash::xxh3_128_with_secret;
+pub use xxhash::xxh32;
+pub use xxhash::xxh64;
+
+#[cfg(feature = "std")]
+pub mod build;
+pub mod encoding;
+
+/// Low-level primitives used inside the hash functions.
+///
+/// These are not hash functions on their own, misusing them (e.g. reusing a
+/// tweak with the same key) can break the security of whatever is built on top.
+pub mod hazmat {
+    pub use crate::threefish::threefish256_decrypt;
+    pub use crate::threefish::threefish256_encrypt;
+    pub use crate::threefish::threefish512_decrypt;
+    pub use crate::threefish::threefish512_encrypt;
+    pub use crate::threefish::threefish1024_decrypt;
+    pub use crate::threefish::threefish1024_encrypt;
+}
@@ -0,0 +1,36 @@
+/// Assert at compile time that a hash function gives the expected hex digest.
+///
+/// The first argument is a hash function returning `[u8; N]`, e.g.
+/// `sha2_256` or `md6::<32>`, the second its input and the third the
+/// expected digest as a hex literal, accepted by [`Digest::from_hex`].
+/// Meant for artifacts vendored with `include_bytes!`, so that the build
+/// fails when one of them changes.
+///
+/// [`Digest::from_hex`]: crate::Digest::from_hex
+///
+/// # Examples
+/// ```
+/// use cthash::{assert_digest, sha2_256};
+///
+/// assert_digest!(sha2_256, b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+/// ```
+///
+/// A mismatch fails compilation with the actual digest in the error message:
+///
+/// ```compile_fail
+/// use cthash::{assert_digest, sha2_256};
+///
+/// // error: evaluation panicked: digest mismatch, the input hashes to e3b0c442…
+/// assert_digest!(sha2_256, b"", "0000000000000000000000000000000000000000000000000000000000000000");
+/// ```
+#[macro_export]
+macro_rules! assert_digest {
+    ($hash:path, $data:expr, $expected:expr $(,)?) => {
+        const _: () = {
+            let found = $hash($data);
+            if !$crate::Digest::new(found).eq(&$crate::Digest::from_hex($expected)) {
+                ::core::panic!("{}", $crate::encoding::__digest_mismatch(&found).as_str());
+            }
+        };
+    };
+}
@@ -0,0 +1,123 @@
+use crate::block_api::BlockBuffer;
+
+/// Compute MD2 digest.
+/// # Examples
+/// ```
+/// use cthash::md2;
+/// const H: [u8; 16] = md2(b"data");
+/// ```
+#[inline]
+pub const fn md2(input: &[u8]) -> [u8; 16] {
+    let mut state = [0u8; 48];
+    let mut checksum = [0u8; 16];
+
+    md2_pad_and_run(input, &mut state, &mut checksum);
+
+    let mut out = [0u8; 16];
+    out.copy_from_slice(state.split_at(16).0);
+    out
+}
+
+/// Incremental state of MD2.
+#[derive(Clone, Debug)]
+pub(crate) struct Md2Core {
+    state: [u8; 48],
+    checksum: [u8; 16],
+    buffer: BlockBuffer<16>,
+}
+
+impl Md2Core {
+    pub(crate) const fn new() -> Self {
+        Self {
+            state: [0; 48],
+            checksum: [0; 16],
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let (block, data) = self.buffer.fill(data);
+        if let Some(block) = block {
+            compress(&mut self.state, &mut self.checksum, block);
+        }
+
+        let (blocks, rem) = data.as_chunks();
+        let mut i = 0;
+        while i < blocks.len() {
+            compress(&mut self.state, &mut self.checksum, &blocks[i]);
+            i += 1;
+        }
+        self.buffer.keep(rem);
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        const { assert!(OUT <= 16, "truncated length must not exceed digest size") }
+
+        let mut state = self.state;
+        let mut checksum = self.checksum;
+        md2_pad_and_run(self.buffer.pending(), &mut state, &mut checksum);
+
+        let mut out = [0u8; OUT];
+        out.copy_from_slice(state.split_at(OUT).0);
+        out
+    }
+}
+
+#[inline(always)]
+const fn md2_pad_and_run(input: &[u8], state: &mut [u8; 48], checksum: &mut [u8; 16]) {
+    let (blocks, rem) = input.as_chunks::<16>();
+
+    let mut i = 0;
+    while i < blocks.len() {
+        compress(state, checksum, &blocks[i]);
+        i += 1;
+    }
+
+    // pad with `n` bytes of value `n`, at least one
+    let pad = (16 - rem.len()) as u8;
+    let mut last = [pad; 16];
+    last.split_at_mut(rem.len()).0.copy_from_slice(rem);
+    compress(state, checksum, &last);
+
+    let checksum = *checksum;
+    compress(state, &mut [0u8; 16], &checksum);
+}
+
+/// Process one block, updating both the state and the running checksum.
+const fn compress(state: &mut [u8; 48], checksum: &mut [u8; 16], block: &[u8; 16]) {
+    let mut l = checksum[15];
+    let mut j = 0;
+    while j < 16 {
+        state[16 + j] = block[j];
+        state[32 + j] = block[j] ^ state[j];
+        checksum[j] ^= S[(block[j] ^ l) as usize];
+        l = checksum[j];
+        j += 1;
+    }
+
+    let mut t = 0u8;
+    let mut round = 0;
+    while round < 18 {
+        let mut k = 0;
+        while k < 48 {
+            state[k] ^= S[t as usize];
+            t = state[k];
+            k += 1;
+        }
+        t = t.wrapping_add(round as u8);
+        round += 1;
+    }
+}
+
+/// Permutation of 0..=255 constructed from the digits of pi (RFC 1319).
+const S: [u8; 256] = [
+    41, 46, 67, 201, 162, 216, 124, 1, 61, 54, 84, 161, 236, 240, 6, 19, 98, 167, 5, 243, 192, 199, 115, 140, 152, 147, 43, 217, 188, 76,
+    130, 202, 30, 155, 87, 60, 253, 212, 224, 22, 103, 66, 111, 24, 138, 23, 229, 18, 190, 78, 196, 214, 218, 158, 222, 73, 160, 251, 245,
+    142, 187, 47, 238, 122, 169, 104, 121, 145, 21, 178, 7, 63, 148, 194, 16, 137, 11, 34, 95, 33, 128, 127, 93, 154, 90, 144, 50, 39, 53,
+    62, 204, 231, 191, 247, 151, 3, 255, 25, 48, 179, 72, 165, 181, 209, 215, 94, 146, 42, 172, 86, 170, 198, 79, 184, 56, 210, 150, 164,
+    125, 182, 118, 252, 107, 226, 156, 116, 4, 241, 69, 157, 112, 89, 100, 113, 135, 32, 134, 91, 207, 101, 230, 45, 168, 2, 27, 96, 37,
+    173, 174, 176, 185, 246, 28, 70, 97, 105, 52, 64, 126, 15, 85, 71, 163, 35, 221, 81, 175, 58, 195, 92, 249, 206, 186, 197, 234, 38, 44,
+    83, 13, 110, 133, 40, 132, 9, 211, 223, 205, 244, 65, 129, 77, 82, 106, 220, 55, 200, 108, 193, 171, 250, 36, 225, 123, 8, 12, 189,
+    177, 74, 120, 136, 149, 139, 227, 99, 232, 109, 233, 203, 213, 254, 59, 0, 29, 57, 242, 239, 183, 14, 102, 88, 208, 228, 166, 119, 114,
+    248, 235, 117, 75, 10, 49, 68, 80, 180, 143, 237, 31, 26, 219, 153, 141, 51, 159, 17, 131, 20,
+];
@@ -1,4 +1,4 @@
-use crate::block_api::eager_split_pad;
+use crate::block_api::{BlockBuffer, eager_split_pad, fixed_split_pad};
 
 /// Compute MD4 digest.
 /// # Examples
@@ -7,24 +7,112 @@ use crate::block_api::eager_split_pad;
 /// const H: [u8; 16] = md4(b"data");
 /// ```
 pub const fn md4(input: &[u8]) -> [u8; 16] {
-    let mut state = [0x67452301u32, 0xefcdab89, 0x98badcfe, 0x10325476];
+    let mut state = IV;
 
-    md4_pad_and_run(input, &mut state);
+    md4_pad_and_run(input, (input.len() as u64).wrapping_mul(8), &mut state);
 
-    let mut out = [0u8; 16];
+    output(&state)
+}
+
+/// Compute MD4 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 16 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::md4_trunc;
+/// const H: [u8; 8] = md4_trunc(b"data");
+/// assert_eq!(H, cthash::md4(b"data")[..8]);
+/// ```
+pub const fn md4_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 16, "truncated length must not exceed digest size") }
+
+    let mut state = IV;
+
+    md4_pad_and_run(input, (input.len() as u64).wrapping_mul(8), &mut state);
+
+    output(&state)
+}
+
+/// Compute MD4 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing: the digest of another hash function is fed
+/// into the compression function with its padding laid out at compile time.
+/// # Examples
+/// ```
+/// use cthash::{md4, md4_fixed};
+/// const H: [u8; 16] = md4_fixed(&md4(b"data"));
+/// ```
+pub const fn md4_fixed<const N: usize>(input: &[u8; N]) -> [u8; 16] {
+    let mut state = IV;
+
+    md4_fixed_pad_and_run(input, &mut state);
+
+    output(&state)
+}
+
+const IV: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+/// Incremental state of MD4.
+#[derive(Clone, Debug)]
+pub(crate) struct Md4Core {
+    state: [u32; 4],
+    buffer: BlockBuffer<64>,
+}
+
+impl Md4Core {
+    pub(crate) const MD4: Self = Self::new(IV);
+
+    const fn new(iv: [u32; 4]) -> Self {
+        Self {
+            state: iv,
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let (block, data) = self.buffer.fill(data);
+        if let Some(block) = block {
+            compress(&mut self.state, block);
+        }
+
+        let (blocks, rem) = data.as_chunks();
+        let mut i = 0;
+        while i < blocks.len() {
+            compress(&mut self.state, &blocks[i]);
+            i += 1;
+        }
+        self.buffer.keep(rem);
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        let mut state = self.state;
+        md4_pad_and_run(self.buffer.pending(), (self.buffer.len() as u64).wrapping_mul(8), &mut state);
+        output(&state)
+    }
+}
+
+/// Serialize the first `OUT` bytes of the state.
+#[inline(always)]
+const fn output<const OUT: usize>(state: &[u32; 4]) -> [u8; OUT] {
+    let mut out = [0u8; OUT];
+    let (out_chunks, out_rem) = out.as_chunks_mut();
     let mut i = 0;
-    while i < 4 {
-        out.as_chunks_mut().0[i] = state[i].to_le_bytes();
+    while i < out_chunks.len() {
+        out_chunks[i] = state[i].to_le_bytes();
         i += 1;
     }
+    if !out_rem.is_empty() {
+        let last_word = state[i].to_le_bytes();
+        out_rem.copy_from_slice(last_word.split_at(out_rem.len()).0);
+    }
     out
 }
 
 #[inline(always)]
-const fn md4_pad_and_run(input: &[u8], state: &mut [u32; 4]) {
+const fn md4_pad_and_run(input: &[u8], bit_len: u64, state: &mut [u32; 4]) {
     let mut b0 = [0; _];
     let mut b1 = [0; _];
-    let bit_len = ((input.len() as u64) * 8).to_le_bytes();
+    let bit_len = bit_len.to_le_bytes();
     let (blocks, pad_two_blocks) = eager_split_pad(input, &bit_len, 0x80, &mut b0, &mut b1);
 
     let mut i = 0;
@@ -41,6 +129,27 @@ const fn md4_pad_and_run(input: &[u8], state: &mut [u32; 4]) {
     }
 }
 
+#[inline(always)]
+const fn md4_fixed_pad_and_run<const N: usize>(input: &[u8; N], state: &mut [u32; 4]) {
+    let mut b0 = [0; _];
+    let mut b1 = [0; _];
+    let bit_len = const { ((N as u64) * 8).to_le_bytes() };
+    let (blocks, pad_two_blocks) = fixed_split_pad(input, &bit_len, 0x80, &mut b0, &mut b1);
+
+    let mut i = 0;
+    while i < blocks.len() {
+        compress(state, &blocks[i]);
+        i += 1;
+    }
+
+    if pad_two_blocks {
+        compress(state, &b0);
+        compress(state, &b1);
+    } else {
+        compress(state, &b0);
+    }
+}
+
 const fn compress(h: &mut [u32; 4], block: &[u8; 64]) {
     const K1: u32 = 0x5a827999;
     const K2: u32 = 0x6ed9eba1;
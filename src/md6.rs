@@ -0,0 +1,308 @@
+/// Compute MD6 digest of `N` bytes with the default parameters: tree mode
+/// (`L = 64`), no key and `40 + d / 4` rounds for `d = 8 * N` bits.
+///
+/// `N` must be between 1 and 64, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::md6;
+/// const H: [u8; 32] = md6(b"data");
+/// ```
+pub const fn md6<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N > 0 && N <= 64, "digest size must be between 1 and 64 bytes") }
+
+    let params = Params::new::<N>(&[], 40 + 2 * N as u64, 64);
+    params.hash(input)
+}
+
+/// MD6 with `ROUNDS` rounds and mode parameter `L`.
+///
+/// `L` is the number of tree levels before MD6 falls back to sequential
+/// (Merkle-Damgård like) processing: 64 is the default tree mode, 0 makes it
+/// fully sequential.
+///
+/// # Examples
+/// ```
+/// use cthash::{Md6, md6};
+///
+/// // same as the default parameters for a 256-bit digest
+/// const H: [u8; 32] = Md6::<104, 64>::hash(b"data");
+/// assert_eq!(H, md6::<32>(b"data"));
+///
+/// // keyed and sequential
+/// const MAC: [u8; 32] = Md6::<104, 0>::hash_keyed(b"data", b"secret");
+/// ```
+///
+/// Both parameters are limited by the sizes of their fields in the control word:
+///
+/// ```compile_fail
+/// use cthash::Md6;
+/// const H: [u8; 32] = Md6::<104, 65>::hash(b"data");
+/// ```
+pub struct Md6<const ROUNDS: usize, const L: u8>;
+
+impl<const ROUNDS: usize, const L: u8> Md6<ROUNDS, L> {
+    /// Compute MD6 digest of `N` bytes.
+    pub const fn hash<const N: usize>(input: &[u8]) -> [u8; N] {
+        Self::hash_keyed(input, &[])
+    }
+
+    /// Compute MD6 digest of `N` bytes with a key of up to 64 bytes.
+    ///
+    /// The reference implementation uses at least 80 rounds for keyed hashing.
+    pub const fn hash_keyed<const N: usize, const K: usize>(input: &[u8], key: &[u8; K]) -> [u8; N] {
+        const {
+            assert!(N > 0 && N <= 64, "digest size must be between 1 and 64 bytes");
+            assert!(K <= 64, "key must not exceed 64 bytes");
+            assert!(ROUNDS < 4096, "number of rounds must fit in 12 bits");
+            assert!(L <= 64, "mode parameter must not exceed 64");
+        }
+
+        let params = Params::new::<N>(key, ROUNDS as u64, L);
+        params.hash(input)
+    }
+}
+
+/// Parameters of a single hash computation, shared by all compression calls.
+struct Params {
+    key: [u64; 8],
+    key_len: u64,
+    rounds: u64,
+    mode: u8,
+    digest_bits: u64,
+}
+
+impl Params {
+    #[inline(always)]
+    const fn new<const N: usize>(key: &[u8], rounds: u64, mode: u8) -> Self {
+        let mut key_bytes = [0u8; 64];
+        let (key, _) = key.split_at(if key.len() < 64 { key.len() } else { 64 });
+        key_bytes.split_at_mut(key.len()).0.copy_from_slice(key);
+
+        Self {
+            key: words_from_be_bytes(&key_bytes),
+            key_len: key.len() as u64,
+            rounds,
+            mode,
+            digest_bits: 8 * N as u64,
+        }
+    }
+
+    /// Find the root: the first level with a single node, or the sequential
+    /// level `L + 1` if the tree is not done by then.
+    const fn hash<const N: usize>(&self, input: &[u8]) -> [u8; N] {
+        let mut level = 1;
+        let chaining = loop {
+            if level > self.mode as u64 {
+                break self.seq(input, level);
+            }
+            if level_count(input.len(), level) == 1 {
+                break self.par_node(input, level, 0);
+            }
+            level += 1;
+        };
+
+        // digest is the tail of the final chaining value
+        let mut bytes = [0u8; 128];
+        let (chunks, _) = bytes.as_chunks_mut::<8>();
+        let mut i = 0;
+        while i < 16 {
+            chunks[i] = chaining[i].to_be_bytes();
+            i += 1;
+        }
+        let mut out = [0u8; N];
+        out.copy_from_slice(bytes.split_at(128 - N).1);
+        out
+    }
+
+    /// Node `index` of tree level `level`, computed recursively from its children.
+    #[inline]
+    const fn par_node(&self, input: &[u8], level: u64, index: u64) -> [u64; 16] {
+        let mut data = [0u64; 64];
+        let pad_bits;
+
+        if level <= 1 {
+            let (blocks, rem) = input.as_chunks::<512>();
+            let chunk: &[u8] = if (index as usize) < blocks.len() {
+                &blocks[index as usize]
+            } else {
+                rem
+            };
+            data = load_block(chunk);
+            pad_bits = 512usize.saturating_sub(chunk.len()) as u64 * 8;
+        } else {
+            let children = level_count(input.len(), level - 1);
+            let mut c = 0;
+            while c < 4 && (index << 2 | c as u64) < children {
+                let child = self.par_node(input, level - 1, index << 2 | c as u64);
+                copy_chaining(&mut data, c, &child);
+                c += 1;
+            }
+            pad_bits = (4 - c as u64) * 1024;
+        }
+
+        let last = level_count(input.len(), level) == 1;
+        self.compress(&data, level, index, last, pad_bits)
+    }
+
+    /// Sequential mode at level `L + 1`: each block carries the previous
+    /// chaining value followed by 384 bytes of level `L` output.
+    #[inline]
+    const fn seq(&self, input: &[u8], level: u64) -> [u64; 16] {
+        let mut chaining = [0u64; 16];
+
+        if level <= 1 {
+            // L = 0, raw message bytes
+            let (blocks, rem) = input.as_chunks::<384>();
+            let count = blocks.len() + (!rem.is_empty() || blocks.is_empty()) as usize;
+            let mut i = 0;
+            while i < count {
+                let chunk: &[u8] = if i < blocks.len() { &blocks[i] } else { rem };
+                let mut data = [0u64; 64];
+                let (head, tail) = data.split_at_mut(16);
+                head.copy_from_slice(&chaining);
+                let block = load_block(chunk);
+                tail.copy_from_slice(block.split_at(48).0);
+                let pad_bits = 384usize.saturating_sub(chunk.len()) as u64 * 8;
+                chaining = self.compress(&data, level, i as u64, i + 1 == count, pad_bits);
+                i += 1;
+            }
+        } else {
+            let nodes = level_count(input.len(), level - 1);
+            let count = nodes.div_ceil(3);
+            let mut node = 0;
+            let mut i = 0;
+            while i < count {
+                let mut data = [0u64; 64];
+                copy_chaining(&mut data, 0, &chaining);
+                let mut c = 0;
+                while c < 3 && node < nodes {
+                    let value = self.par_node(input, level - 1, node);
+                    copy_chaining(&mut data, c + 1, &value);
+                    node += 1;
+                    c += 1;
+                }
+                let pad_bits = (3 - c as u64) * 1024;
+                chaining = self.compress(&data, level, i, i + 1 == count, pad_bits);
+                i += 1;
+            }
+        }
+
+        chaining
+    }
+
+    /// Compression function on `Q || K || U || V || B`.
+    #[inline]
+    const fn compress(&self, data: &[u64; 64], level: u64, index: u64, last: bool, pad_bits: u64) -> [u64; 16] {
+        // unique node ID and control word
+        let u = level << 56 | index & ((1 << 56) - 1);
+        let v = self.rounds << 48 | (self.mode as u64) << 40 | (last as u64) << 36 | pad_bits << 20 | self.key_len << 12 | self.digest_bits;
+
+        // 89 most recent words of the feedback register, slot `step % 89` holds the oldest one
+        let mut a = [0u64; 89];
+        let (q, rest) = a.split_at_mut(15);
+        q.copy_from_slice(&Q);
+        let (k, rest) = rest.split_at_mut(8);
+        k.copy_from_slice(&self.key);
+        rest[0] = u;
+        rest[1] = v;
+        rest.split_at_mut(2).1.copy_from_slice(data);
+
+        let mut s = S0;
+        let mut step = 0;
+        let mut round = 0;
+        while round < self.rounds {
+            let mut i = 0;
+            while i < 16 {
+                let pos = step % 89;
+                let mut x =
+                    s ^ a[pos] ^ a[(pos + 72) % 89] ^ (a[(pos + 71) % 89] & a[(pos + 68) % 89]) ^ (a[(pos + 58) % 89] & a[(pos + 22) % 89]);
+                x ^= x >> RS[i];
+                a[pos] = x ^ x << LS[i];
+                step = pos + 1;
+                i += 1;
+            }
+            s = s.rotate_left(1) ^ (s & S_MASK);
+            round += 1;
+        }
+
+        let pos = step % 89;
+        let mut out = [0u64; 16];
+        let mut j = 0;
+        while j < 16 {
+            out[j] = a[(pos + 73 + j) % 89];
+            j += 1;
+        }
+        out
+    }
+}
+
+/// Number of nodes of tree level `level` for a message of `len` bytes.
+#[inline(always)]
+const fn level_count(len: usize, level: u64) -> u64 {
+    let mut count = (len as u64).div_ceil(512);
+    if count == 0 {
+        count = 1;
+    }
+    let mut l = 1;
+    while l < level {
+        count = count.div_ceil(4);
+        l += 1;
+    }
+    count
+}
+
+/// Zero-padded block of up to 512 bytes as big-endian words.
+#[inline(always)]
+const fn load_block(chunk: &[u8]) -> [u64; 64] {
+    let mut bytes = [0u8; 512];
+    let (chunk, _) = chunk.split_at(if chunk.len() < 512 { chunk.len() } else { 512 });
+    bytes.split_at_mut(chunk.len()).0.copy_from_slice(chunk);
+    words_from_be_bytes(&bytes)
+}
+
+#[inline(always)]
+const fn words_from_be_bytes<const W: usize, const B: usize>(bytes: &[u8; B]) -> [u64; W] {
+    let (chunks, _) = bytes.as_chunks::<8>();
+    let mut out = [0u64; W];
+    let mut i = 0;
+    while i < W && i < chunks.len() {
+        out[i] = u64::from_be_bytes(chunks[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Store a chaining value as the `slot`-th quarter of a block.
+#[inline(always)]
+const fn copy_chaining(data: &mut [u64; 64], slot: usize, chaining: &[u64; 16]) {
+    let mut k = 0;
+    while k < 16 {
+        data[16 * (slot % 4) + k] = chaining[k];
+        k += 1;
+    }
+}
+
+/// Fractional part of `sqrt(6)`.
+const Q: [u64; 15] = [
+    0x7311c2812425cfa0,
+    0x6432286434aac8e7,
+    0xb60450e9ef68b7c1,
+    0xe8fb23908d9f06f1,
+    0xdd2e76cba691e5bf,
+    0x0cd0d63b2c30bc41,
+    0x1f8ccf6823058f8a,
+    0x54e5ed5b88e3775d,
+    0x4ad12aae0a6d6031,
+    0x3e7f16bb88222e0d,
+    0x8af8671d3fb50c2c,
+    0x995ad1178bd25c31,
+    0xc878c1dd04c4b633,
+    0x3b72066c7a1552ac,
+    0x0d6f3522631effcb,
+];
+
+const S0: u64 = 0x0123456789abcdef;
+const S_MASK: u64 = 0x7311c2812425cfa0;
+
+const RS: [u32; 16] = [10, 5, 13, 10, 11, 12, 2, 7, 14, 15, 7, 13, 11, 7, 6, 12];
+const LS: [u32; 16] = [11, 24, 9, 16, 15, 9, 27, 15, 6, 2, 29, 8, 15, 5, 31, 9];
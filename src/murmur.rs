@@ -0,0 +1,180 @@
+use core::num::NonZeroU32;
+
+/// Compute 32-bit MurmurHash2 with `seed`.
+/// # Examples
+/// ```
+/// use cthash::murmur2_32;
+/// const H: u32 = murmur2_32(b"data", 0);
+/// ```
+pub const fn murmur2_32(input: &[u8], seed: u32) -> u32 {
+    const M: u32 = 0x5bd1e995;
+
+    let mut h = seed ^ input.len() as u32;
+
+    let (blocks, tail) = input.as_chunks::<4>();
+    let mut i = 0;
+    while i < blocks.len() {
+        let mut k = u32::from_le_bytes(blocks[i]).wrapping_mul(M);
+        k ^= k >> 24;
+        h = h.wrapping_mul(M) ^ k.wrapping_mul(M);
+        i += 1;
+    }
+
+    if !tail.is_empty() {
+        h ^= read_tail(tail, false) as u32;
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^ h >> 15
+}
+
+/// Kafka partition of a record key, as chosen by the default partitioner of
+/// the Java client: positive part of `murmur2_32` with seed `0x9747b28c`
+/// modulo the number of partitions.
+/// # Examples
+/// ```
+/// use core::num::NonZeroU32;
+/// use cthash::kafka_partition;
+///
+/// const PARTITIONS: NonZeroU32 = NonZeroU32::new(12).unwrap();
+/// const P: u32 = kafka_partition(b"user-42", PARTITIONS);
+/// assert!(P < 12);
+/// ```
+pub const fn kafka_partition(key: &[u8], partitions: NonZeroU32) -> u32 {
+    (murmur2_32(key, 0x9747b28c) & 0x7fffffff) % partitions.get()
+}
+
+/// Compute 32-bit MurmurHash3 (x86) with `seed`.
+/// # Examples
+/// ```
+/// use cthash::murmur3_32;
+/// const H: u32 = murmur3_32(b"data", 0);
+/// ```
+pub const fn murmur3_32(input: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e2d51;
+    const C2: u32 = 0x1b873593;
+
+    let mut h = seed;
+
+    let (blocks, tail) = input.as_chunks::<4>();
+    let mut i = 0;
+    while i < blocks.len() {
+        let k = u32::from_le_bytes(blocks[i]).wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h = (h ^ k).rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
+        i += 1;
+    }
+
+    if !tail.is_empty() {
+        h ^= (read_tail(tail, false) as u32).wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+    }
+
+    h ^= input.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^ h >> 16
+}
+
+/// Compute 128-bit MurmurHash3 (x64) with `seed`.
+///
+/// The first 64-bit half `h1` of the reference implementation is in the low
+/// bits, so `to_le_bytes()` gives its 16 output bytes.
+/// # Examples
+/// ```
+/// use cthash::murmur3_x64_128;
+/// const H: u128 = murmur3_x64_128(b"data", 0);
+/// ```
+#[inline]
+pub const fn murmur3_x64_128(input: &[u8], seed: u32) -> u128 {
+    let (h1, h2) = murmur3_x64_128_halves(input, seed as u64, false);
+    (h2 as u128) << 64 | h1 as u128
+}
+
+/// Token of a partition key under Cassandra's `Murmur3Partitioner`.
+///
+/// Cassandra's MurmurHash3 reads the trailing `len % 16` bytes as signed Java
+/// bytes, so keys with a byte of `0x80` or more there hash differently from
+/// [`murmur3_x64_128`] with seed 0. The token is its first half `h1`, with
+/// `i64::MIN` mapped to `i64::MAX` as it is reserved for the minimum token.
+/// # Examples
+/// ```
+/// use cthash::cassandra_token;
+/// const TOKEN: i64 = cassandra_token(b"123");
+/// assert_eq!(TOKEN, -7468325962851647638);
+/// ```
+#[inline]
+pub const fn cassandra_token(key: &[u8]) -> i64 {
+    let (h1, _) = murmur3_x64_128_halves(key, 0, true);
+    match h1 as i64 {
+        i64::MIN => i64::MAX,
+        token => token,
+    }
+}
+
+/// Halves `h1` and `h2` of MurmurHash3 (x64), with tail bytes sign-extended
+/// if `signed_tail` is set.
+#[inline(always)]
+const fn murmur3_x64_128_halves(input: &[u8], seed: u64, signed_tail: bool) -> (u64, u64) {
+    const C1: u64 = 0x87c37b91114253d5;
+    const C2: u64 = 0x4cf5ad432745937f;
+
+    let mut h1 = seed;
+    let mut h2 = seed;
+
+    let (blocks, tail) = input.as_chunks::<16>();
+    let mut i = 0;
+    while i < blocks.len() {
+        let (k1, k2) = blocks[i].split_at(8);
+        let k1 = read_tail(k1, false).wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+        h1 = (h1 ^ k1).rotate_left(27).wrapping_add(h2).wrapping_mul(5).wrapping_add(0x52dce729);
+        let k2 = read_tail(k2, false).wrapping_mul(C2).rotate_left(33).wrapping_mul(C1);
+        h2 = (h2 ^ k2).rotate_left(31).wrapping_add(h1).wrapping_mul(5).wrapping_add(0x38495ab5);
+        i += 1;
+    }
+
+    if let Some((k1, k2)) = tail.split_at_checked(8) {
+        if !k2.is_empty() {
+            h2 ^= read_tail(k2, signed_tail).wrapping_mul(C2).rotate_left(33).wrapping_mul(C1);
+        }
+        h1 ^= read_tail(k1, signed_tail).wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+    } else if !tail.is_empty() {
+        h1 ^= read_tail(tail, signed_tail).wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+    }
+
+    h1 ^= input.len() as u64;
+    h2 ^= input.len() as u64;
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+    h1 = fmix64(h1);
+    h2 = fmix64(h2);
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+
+    (h1, h2)
+}
+
+#[inline(always)]
+const fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51afd7ed558ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ceb9fe1a85ec53);
+    k ^ k >> 33
+}
+
+/// Up to 8 bytes as a little-endian integer, each byte sign-extended
+/// before being combined if `signed` is set.
+#[inline(always)]
+const fn read_tail(bytes: &[u8], signed: bool) -> u64 {
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() && i < 8 {
+        let byte = if signed { bytes[i] as i8 as u64 } else { bytes[i] as u64 };
+        value ^= byte << (8 * i);
+        i += 1;
+    }
+    value
+}
@@ -0,0 +1,644 @@
+use crate::field::{BLS12_381_FR, BN254_FR, FieldElement, PrimeField};
+
+/// Poseidon permutation with state width `T`, `RF` full and `RP` partial rounds.
+///
+/// Round constants and the MDS matrix are generated at compile time with the
+/// Grain LFSR of the reference implementation (`generate_parameters_grain.sage`).
+/// The secure-MDS checks and resampling of the script are not implemented, so
+/// only the presets like [`POSEIDON_BN254_X5_3`] are provided, whose
+/// parameters match circomlib and the reference test vectors.
+///
+/// # Examples
+/// ```
+/// use cthash::{BN254_FR, FieldElement, POSEIDON_BN254_X5_3};
+///
+/// // circomlib `poseidon([1, 2])`
+/// const H: FieldElement = POSEIDON_BN254_X5_3.hash(&[BN254_FR.from_u64(1), BN254_FR.from_u64(2)]);
+/// const H_BYTES: [u8; 32] = BN254_FR.to_be_bytes(&H);
+/// assert_eq!(H_BYTES[..4], [0x11, 0x5c, 0xc0, 0xf5]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Poseidon<const T: usize, const RF: usize, const RP: usize> {
+    field: PrimeField,
+    alpha: u64,
+    full_round_constants: [[FieldElement; T]; RF],
+    partial_round_constants: [[FieldElement; T]; RP],
+    mds: [[FieldElement; T]; T],
+}
+
+impl<const T: usize, const RF: usize, const RP: usize> Poseidon<T, RF, RP> {
+    /// Generate an instance over `field` with S-box `x^alpha`.
+    ///
+    /// Neither the MDS matrix nor `alpha` are checked, so this is only used
+    /// for the vetted presets.
+    pub(crate) const fn new(field: PrimeField, alpha: u64) -> Self {
+        const {
+            assert!(T >= 2, "state must have at least two elements");
+            assert!(RF.is_multiple_of(2), "number of full rounds must be even");
+        }
+
+        let mut grain = Grain::new(field.bits(), T, RF, RP);
+
+        let mut full_round_constants = [[FieldElement([0; 4]); T]; RF];
+        let mut partial_round_constants = [[FieldElement([0; 4]); T]; RP];
+        let mut r = 0;
+        while r < RF + RP {
+            let mut i = 0;
+            while i < T {
+                let c = grain.next_element(&field);
+                if r < RF / 2 {
+                    full_round_constants[r][i] = c;
+                } else if r < RF / 2 + RP {
+                    partial_round_constants[r - RF / 2][i] = c;
+                } else {
+                    full_round_constants[r - RP][i] = c;
+                }
+                i += 1;
+            }
+            r += 1;
+        }
+
+        let mds = cauchy_matrix(&mut grain, &field);
+
+        Self {
+            field,
+            alpha,
+            full_round_constants,
+            partial_round_constants,
+            mds,
+        }
+    }
+
+    /// Field of the instance.
+    pub const fn field(&self) -> &PrimeField {
+        &self.field
+    }
+
+    /// Apply the permutation to `state`.
+    pub const fn permute(&self, state: &mut [FieldElement; T]) {
+        let mut r = 0;
+        while r < RF / 2 {
+            self.full_round(state, &self.full_round_constants[r]);
+            r += 1;
+        }
+        r = 0;
+        while r < RP {
+            add_round_constants(&self.field, state, &self.partial_round_constants[r]);
+            state[0] = self.field.pow_u64(&state[0], self.alpha);
+            *state = self.mix(state);
+            r += 1;
+        }
+        r = RF / 2;
+        while r < RF {
+            self.full_round(state, &self.full_round_constants[r]);
+            r += 1;
+        }
+    }
+
+    /// Hash exactly `T - 1` elements as circomlib does: permute
+    /// `[0, inputs..]` and return the first element.
+    pub const fn hash<const N: usize>(&self, inputs: &[FieldElement; N]) -> FieldElement {
+        const { assert!(N + 1 == T, "number of inputs must be one less than state width") }
+
+        let mut state = [FieldElement([0; 4]); T];
+        let mut i = 0;
+        while i < N {
+            state[i + 1] = inputs[i];
+            i += 1;
+        }
+        self.permute(&mut state);
+        state[0]
+    }
+
+    /// Hash `N` elements into `OUT` elements with the fixed-length sponge of
+    /// the Poseidon paper: rate `T - 1`, capacity element initialized with
+    /// `N * 2^64 + (OUT - 1)` and zero padding.
+    pub const fn sponge<const N: usize, const OUT: usize>(&self, inputs: &[FieldElement; N]) -> [FieldElement; OUT] {
+        let mut state = [FieldElement([0; 4]); T];
+        state[0] = sponge_domain(&self.field, N, OUT);
+
+        let mut i = 0;
+        while i < N {
+            let k = 1 + i % (T - 1);
+            state[k] = self.field.add(&state[k], &inputs[i]);
+            i += 1;
+            if k == T - 1 || i == N {
+                self.permute(&mut state);
+            }
+        }
+        if N == 0 {
+            self.permute(&mut state);
+        }
+
+        let mut out = [FieldElement([0; 4]); OUT];
+        let mut i = 0;
+        while i < OUT {
+            let k = 1 + i % (T - 1);
+            out[i] = state[k];
+            i += 1;
+            if k == T - 1 && i < OUT {
+                self.permute(&mut state);
+            }
+        }
+        out
+    }
+
+    #[inline(always)]
+    const fn full_round(&self, state: &mut [FieldElement; T], constants: &[FieldElement; T]) {
+        add_round_constants(&self.field, state, constants);
+        let mut i = 0;
+        while i < T {
+            state[i] = self.field.pow_u64(&state[i], self.alpha);
+            i += 1;
+        }
+        *state = self.mix(state);
+    }
+
+    #[inline(always)]
+    const fn mix(&self, state: &[FieldElement; T]) -> [FieldElement; T] {
+        let mut out = [FieldElement([0; 4]); T];
+        let mut i = 0;
+        while i < T {
+            let mut j = 0;
+            while j < T {
+                out[i] = self.field.add(&out[i], &self.field.mul(&self.mds[i][j], &state[j]));
+                j += 1;
+            }
+            i += 1;
+        }
+        out
+    }
+}
+
+/// Poseidon2 permutation with state width `T`, `RF` external and `RP` internal rounds.
+///
+/// Round constants are generated at compile time with the Grain LFSR as in
+/// the reference implementation by HorizenLabs. The external matrix is the
+/// one of the Poseidon2 paper, the internal matrix is `1 + diag(μ)`. Like
+/// [`Poseidon`], only the presets like [`POSEIDON2_BN254_X5_3`] are provided.
+///
+/// # Examples
+/// ```
+/// use cthash::{BN254_FR, FieldElement, POSEIDON2_BN254_X5_3};
+///
+/// const STATE: [FieldElement; 3] = {
+///     let mut state = [BN254_FR.from_u64(0), BN254_FR.from_u64(1), BN254_FR.from_u64(2)];
+///     POSEIDON2_BN254_X5_3.permute(&mut state);
+///     state
+/// };
+/// assert_eq!(BN254_FR.to_be_bytes(&STATE[0])[..4], [0x0b, 0xb6, 0x1d, 0x24]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Poseidon2<const T: usize, const RF: usize, const RP: usize> {
+    field: PrimeField,
+    alpha: u64,
+    external_round_constants: [[FieldElement; T]; RF],
+    internal_round_constants: [FieldElement; RP],
+    internal_diag_m1: [FieldElement; T],
+}
+
+impl<const T: usize, const RF: usize, const RP: usize> Poseidon2<T, RF, RP> {
+    /// Generate an instance over `field` with S-box `x^alpha` for `T` of 2 or 3,
+    /// where the internal matrix is fixed by the paper.
+    pub(crate) const fn new(field: PrimeField, alpha: u64) -> Self {
+        const { assert!(T == 2 || T == 3, "internal matrix must be given for state width above 3") }
+
+        let mut internal_diag_m1 = [field.one(); T];
+        internal_diag_m1[T - 1] = field.from_u64(2);
+
+        Self::with_internal_diag(field, alpha, internal_diag_m1)
+    }
+
+    /// Generate an instance over `field` with S-box `x^alpha` and the
+    /// internal matrix `1 + diag(internal_diag_m1)`.
+    ///
+    /// `T` must be 2, 3 or a multiple of 4 up to 24. Neither the internal
+    /// matrix nor `alpha` are checked, so this is only used for the vetted presets.
+    pub(crate) const fn with_internal_diag(field: PrimeField, alpha: u64, internal_diag_m1: [FieldElement; T]) -> Self {
+        const {
+            assert!(
+                T == 2 || T == 3 || (T.is_multiple_of(4) && T <= 24),
+                "state width must be 2, 3 or a multiple of 4 up to 24"
+            );
+            assert!(RF.is_multiple_of(2), "number of external rounds must be even");
+        }
+
+        let mut grain = Grain::new(field.bits(), T, RF, RP);
+
+        let mut external_round_constants = [[FieldElement([0; 4]); T]; RF];
+        let mut internal_round_constants = [FieldElement([0; 4]); RP];
+
+        let mut r = 0;
+        while r < RF / 2 {
+            let mut i = 0;
+            while i < T {
+                external_round_constants[r][i] = grain.next_element(&field);
+                i += 1;
+            }
+            r += 1;
+        }
+        r = 0;
+        while r < RP {
+            internal_round_constants[r] = grain.next_element(&field);
+            r += 1;
+        }
+        r = RF / 2;
+        while r < RF {
+            let mut i = 0;
+            while i < T {
+                external_round_constants[r][i] = grain.next_element(&field);
+                i += 1;
+            }
+            r += 1;
+        }
+
+        Self {
+            field,
+            alpha,
+            external_round_constants,
+            internal_round_constants,
+            internal_diag_m1,
+        }
+    }
+
+    /// Field of the instance.
+    pub const fn field(&self) -> &PrimeField {
+        &self.field
+    }
+
+    /// Apply the permutation to `state`.
+    pub const fn permute(&self, state: &mut [FieldElement; T]) {
+        *state = self.external_mix(state);
+
+        let mut r = 0;
+        while r < RF / 2 {
+            self.external_round(state, &self.external_round_constants[r]);
+            r += 1;
+        }
+        r = 0;
+        while r < RP {
+            let x = self.field.add(&state[0], &self.internal_round_constants[r]);
+            state[0] = self.field.pow_u64(&x, self.alpha);
+            *state = self.internal_mix(state);
+            r += 1;
+        }
+        r = RF / 2;
+        while r < RF {
+            self.external_round(state, &self.external_round_constants[r]);
+            r += 1;
+        }
+    }
+
+    /// Hash exactly `T - 1` elements: permute `[0, inputs..]` and return the first element.
+    pub const fn hash<const N: usize>(&self, inputs: &[FieldElement; N]) -> FieldElement {
+        const { assert!(N + 1 == T, "number of inputs must be one less than state width") }
+
+        let mut state = [FieldElement([0; 4]); T];
+        let mut i = 0;
+        while i < N {
+            state[i + 1] = inputs[i];
+            i += 1;
+        }
+        self.permute(&mut state);
+        state[0]
+    }
+
+    /// Hash `N` elements into `OUT` elements with the same fixed-length
+    /// sponge as [`Poseidon::sponge`].
+    pub const fn sponge<const N: usize, const OUT: usize>(&self, inputs: &[FieldElement; N]) -> [FieldElement; OUT] {
+        let mut state = [FieldElement([0; 4]); T];
+        state[0] = sponge_domain(&self.field, N, OUT);
+
+        let mut i = 0;
+        while i < N {
+            let k = 1 + i % (T - 1);
+            state[k] = self.field.add(&state[k], &inputs[i]);
+            i += 1;
+            if k == T - 1 || i == N {
+                self.permute(&mut state);
+            }
+        }
+        if N == 0 {
+            self.permute(&mut state);
+        }
+
+        let mut out = [FieldElement([0; 4]); OUT];
+        let mut i = 0;
+        while i < OUT {
+            let k = 1 + i % (T - 1);
+            out[i] = state[k];
+            i += 1;
+            if k == T - 1 && i < OUT {
+                self.permute(&mut state);
+            }
+        }
+        out
+    }
+
+    #[inline(always)]
+    const fn external_round(&self, state: &mut [FieldElement; T], constants: &[FieldElement; T]) {
+        add_round_constants(&self.field, state, constants);
+        let mut i = 0;
+        while i < T {
+            state[i] = self.field.pow_u64(&state[i], self.alpha);
+            i += 1;
+        }
+        *state = self.external_mix(state);
+    }
+
+    /// Multiply by `circ(2, 1)`, `circ(2, 1, 1)` or the `M4`-based block matrix.
+    #[inline(always)]
+    const fn external_mix(&self, state: &[FieldElement; T]) -> [FieldElement; T] {
+        let f = &self.field;
+        let mut out = *state;
+
+        if T < 4 {
+            let sum = sum(f, state);
+            let mut i = 0;
+            while i < T {
+                out[i] = f.add(&state[i], &sum);
+                i += 1;
+            }
+            return out;
+        }
+
+        // Apply M4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]] to every chunk
+        let mut c = 0;
+        while c + 4 <= T {
+            let [x0, x1, x2, x3] = [state[c], state[c + 1], state[c + 2], state[c + 3]];
+            let t0 = f.add(&x0, &x1);
+            let t1 = f.add(&x2, &x3);
+            let t2 = f.add(&f.add(&x1, &x1), &t1);
+            let t3 = f.add(&f.add(&x3, &x3), &t0);
+            let t4 = f.add(&f.add(&f.add(&t1, &t1), &f.add(&t1, &t1)), &t3);
+            let t5 = f.add(&f.add(&f.add(&t0, &t0), &f.add(&t0, &t0)), &t2);
+            let t6 = f.add(&t3, &t5);
+            let t7 = f.add(&t2, &t4);
+            out[c] = t6;
+            out[c + 1] = t5;
+            out[c + 2] = t7;
+            out[c + 3] = t4;
+            c += 4;
+        }
+
+        // Add the sum of chunks to every chunk, that is `2 * M4` on the diagonal,
+        // the reference uses plain `M4` for a single chunk
+        if T == 4 {
+            return out;
+        }
+        let mut sums = [FieldElement([0; 4]); 4];
+        c = 0;
+        while c < T {
+            sums[c % 4] = f.add(&sums[c % 4], &out[c]);
+            c += 1;
+        }
+        c = 0;
+        while c < T {
+            out[c] = f.add(&out[c], &sums[c % 4]);
+            c += 1;
+        }
+        out
+    }
+
+    /// Multiply by `1 + diag(internal_diag_m1)`.
+    #[inline(always)]
+    const fn internal_mix(&self, state: &[FieldElement; T]) -> [FieldElement; T] {
+        let f = &self.field;
+        let sum = sum(f, state);
+        let mut out = [FieldElement([0; 4]); T];
+        let mut i = 0;
+        while i < T {
+            out[i] = f.add(&f.mul(&self.internal_diag_m1[i], &state[i]), &sum);
+            i += 1;
+        }
+        out
+    }
+}
+
+/// Poseidon over the BN254 scalar field with `x^5` S-box and state width 2,
+/// as `poseidon` with one input in circomlib.
+pub const POSEIDON_BN254_X5_2: Poseidon<2, 8, 56> = Poseidon::new(BN254_FR, 5);
+
+/// Poseidon over the BN254 scalar field with `x^5` S-box and state width 3,
+/// as `poseidon` with two inputs in circomlib.
+pub const POSEIDON_BN254_X5_3: Poseidon<3, 8, 57> = Poseidon::new(BN254_FR, 5);
+
+/// Poseidon over the BN254 scalar field with `x^5` S-box and state width 5,
+/// as `poseidon` with four inputs in circomlib.
+pub const POSEIDON_BN254_X5_5: Poseidon<5, 8, 60> = Poseidon::new(BN254_FR, 5);
+
+/// Poseidon over the BLS12-381 scalar field with `x^5` S-box and state width 3.
+pub const POSEIDON_BLS12_381_X5_3: Poseidon<3, 8, 57> = Poseidon::new(BLS12_381_FR, 5);
+
+/// Poseidon over the BLS12-381 scalar field with `x^5` S-box and state width 5.
+pub const POSEIDON_BLS12_381_X5_5: Poseidon<5, 8, 60> = Poseidon::new(BLS12_381_FR, 5);
+
+/// Poseidon2 over the BN254 scalar field with `x^5` S-box and state width 3.
+pub const POSEIDON2_BN254_X5_3: Poseidon2<3, 8, 56> = Poseidon2::new(BN254_FR, 5);
+
+/// Poseidon2 over the BLS12-381 scalar field with `x^5` S-box and state width 3.
+pub const POSEIDON2_BLS12_381_X5_3: Poseidon2<3, 8, 56> = Poseidon2::new(BLS12_381_FR, 5);
+
+#[inline(always)]
+const fn add_round_constants<const T: usize>(f: &PrimeField, state: &mut [FieldElement; T], constants: &[FieldElement; T]) {
+    let mut i = 0;
+    while i < T {
+        state[i] = f.add(&state[i], &constants[i]);
+        i += 1;
+    }
+}
+
+#[inline(always)]
+const fn sum<const T: usize>(f: &PrimeField, state: &[FieldElement; T]) -> FieldElement {
+    let mut acc = FieldElement([0; 4]);
+    let mut i = 0;
+    while i < T {
+        acc = f.add(&acc, &state[i]);
+        i += 1;
+    }
+    acc
+}
+
+/// Capacity element `inputs * 2^64 + (outputs - 1)` of the fixed-length sponge.
+#[inline(always)]
+const fn sponge_domain(f: &PrimeField, inputs: usize, outputs: usize) -> FieldElement {
+    f.reduce(&[(outputs as u64).wrapping_sub(1), inputs as u64, 0, 0])
+}
+
+/// Cauchy matrix `1 / (x_i + y_j)` from `2 * T` distinct Grain samples.
+const fn cauchy_matrix<const T: usize>(grain: &mut Grain, f: &PrimeField) -> [[FieldElement; T]; T] {
+    loop {
+        let mut xy = [[FieldElement([0; 4]); T]; 2];
+        let mut distinct = true;
+        loop {
+            let mut i = 0;
+            while i < 2 * T {
+                xy[i / T][i % T] = f.reduce(&grain.next_bits(f.bits()));
+                i += 1;
+            }
+            let mut i = 0;
+            while i < 2 * T {
+                let mut j = i + 1;
+                while j < 2 * T {
+                    distinct &= !f.eq(&xy[i / T][i % T], &xy[j / T][j % T]);
+                    j += 1;
+                }
+                i += 1;
+            }
+            if distinct {
+                break;
+            }
+            distinct = true;
+        }
+
+        let [xs, ys] = xy;
+        let mut mds = [[FieldElement([0; 4]); T]; T];
+        let mut invertible = true;
+        // batch inversion: keep prefix products, invert once, then unwind
+        let mut prefix = [[FieldElement([0; 4]); T]; T];
+        let mut acc = f.one();
+        let mut k = 0;
+        while k < T * T {
+            let s = f.add(&xs[k / T], &ys[k % T]);
+            invertible &= !f.is_zero(&s);
+            prefix[k / T][k % T] = acc;
+            acc = f.mul(&acc, &s);
+            mds[k / T][k % T] = s;
+            k += 1;
+        }
+        if !invertible {
+            continue;
+        }
+        let mut inv = f.inv(&acc);
+        while k > 0 {
+            k -= 1;
+            let s = mds[k / T][k % T];
+            mds[k / T][k % T] = f.mul(&inv, &prefix[k / T][k % T]);
+            inv = f.mul(&inv, &s);
+        }
+        return mds;
+    }
+}
+
+/// Grain LFSR in self-shrinking mode as used for Poseidon parameter generation.
+struct Grain {
+    // 80 bits, oldest bit is the least significant one
+    state: u128,
+    // bits which survived the shrinking step, oldest one is the most significant
+    kept: u128,
+    kept_len: u32,
+}
+
+/// Self-shrinking of four bit pairs (least significant pair first), the count
+/// of kept bits is in the high nibble and the kept bits in the low nibble.
+const SHRINK: [u8; 256] = {
+    let mut table = [0u8; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let (mut value, mut count) = (0u8, 0u8);
+        let mut pair = 0;
+        while pair < 4 {
+            let bits = (byte >> (2 * pair)) & 0b11;
+            if bits & 1 == 1 {
+                value = value << 1 | (bits >> 1) as u8;
+                count += 1;
+            }
+            pair += 1;
+        }
+        table[byte] = count << 4 | value;
+        byte += 1;
+    }
+    table
+};
+
+impl Grain {
+    const fn new(field_bits: u32, t: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        // field GF(p) = 1, S-box x^alpha = 0, then sizes
+        let fields: [(u128, u32); 6] =
+            [(1, 2), (0, 4), (field_bits as u128, 12), (t as u128, 12), (full_rounds as u128, 10), (partial_rounds as u128, 10)];
+
+        let mut state = 0u128;
+        let mut pos = 0;
+        let mut i = 0;
+        while i < fields.len() {
+            let (value, width) = fields[i];
+            let mut b = width;
+            while b > 0 {
+                b -= 1;
+                state |= ((value >> b) & 1) << pos;
+                pos += 1;
+            }
+            i += 1;
+        }
+        // remaining 30 bits are ones
+        while pos < 80 {
+            state |= 1 << pos;
+            pos += 1;
+        }
+
+        // discard first 160 bits
+        let mut grain = Self {
+            state,
+            kept: 0,
+            kept_len: 0,
+        };
+        i = 0;
+        while i < 10 {
+            grain.clock16();
+            i += 1;
+        }
+        grain
+    }
+
+    /// Clock the register 16 times at once, the closest tap is 13 bits away
+    /// from the oldest one so none of the new bits feed into each other.
+    #[inline(always)]
+    const fn clock16(&mut self) -> u128 {
+        let s = self.state;
+        let bits = (s >> 62 ^ s >> 51 ^ s >> 38 ^ s >> 23 ^ s >> 13 ^ s) & 0xffff;
+        self.state = s >> 16 | bits << 64;
+        bits
+    }
+
+    /// Next `n <= 64` bits of the shrunk output, first bit is the most significant.
+    const fn take(&mut self, n: u32) -> u64 {
+        while self.kept_len < n {
+            let bits = self.clock16();
+            let mut half = 0;
+            while half < 2 {
+                let entry = SHRINK[((bits >> (8 * half)) & 0xff) as usize];
+                let count = (entry >> 4) as u32;
+                self.kept = self.kept << count | (entry & 0xf) as u128;
+                self.kept_len += count;
+                half += 1;
+            }
+        }
+        self.kept_len -= n;
+        let value = (self.kept >> self.kept_len) as u64;
+        self.kept &= (1 << self.kept_len) - 1;
+        if n == 64 { value } else { value & ((1 << n) - 1) }
+    }
+
+    /// Next `n` bits as a big-endian integer.
+    const fn next_bits(&mut self, n: u32) -> [u64; 4] {
+        let mut out = [0u64; 4];
+        let mut limb = n.div_ceil(64) as usize;
+        let mut width = n - 64 * (limb as u32 - 1);
+        while limb > 0 {
+            limb -= 1;
+            out[limb] = self.take(width);
+            width = 64;
+        }
+        out
+    }
+
+    /// Next field element by rejection sampling.
+    const fn next_element(&mut self, f: &PrimeField) -> FieldElement {
+        loop {
+            let candidate = self.next_bits(f.bits());
+            if f.is_canonical(&candidate) {
+                return f.reduce(&candidate);
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::wyhash::{SECRET as WY_SECRET, mix, mum, read_small, read_u32, read_u64};
+
+/// Default secret of rapidhash V3.
+const SECRET: [u64; 7] =
+    [WY_SECRET[0], WY_SECRET[1], WY_SECRET[2], WY_SECRET[3], 0xa0761d6478bd642f, 0xe7037ed1a0b428db, 0x90ed1765281c388c];
+
+/// Compute 64-bit rapidhash (V3) of `data` with the default secret, like
+/// `rapidhash_withSeed` of the reference implementation.
+/// # Examples
+/// ```
+/// use cthash::rapidhash;
+/// const H: u64 = rapidhash(0, b"data");
+/// ```
+#[inline]
+pub const fn rapidhash(seed: u64, data: &[u8]) -> u64 {
+    let len = data.len();
+    let mut seed = seed ^ mix(seed ^ SECRET[2], SECRET[1]);
+    let mut a = 0;
+    let mut b = 0;
+    let remainder;
+
+    if len <= 16 {
+        if len >= 4 {
+            seed ^= len as u64;
+            if len >= 8 {
+                a ^= read_u64(data, 0);
+                b ^= read_u64(data, len - 8);
+            } else {
+                a ^= read_u32(data, 0);
+                b ^= read_u32(data, len - 4);
+            }
+        } else {
+            let [first, middle, last] = read_small(data);
+            a ^= (first as u64) << 45 | last as u64;
+            b ^= middle as u64;
+        }
+        remainder = len;
+    } else {
+        let mut rest = data;
+        if len > 112 {
+            let mut see = [seed; 7];
+            while let Some((block, tail)) = rest.split_first_chunk::<112>()
+                && !tail.is_empty()
+            {
+                let mut i = 0;
+                while i < 7 {
+                    see[i] = mix(read_u64(block, 16 * i) ^ SECRET[i], read_u64(block, 16 * i + 8) ^ see[i]);
+                    i += 1;
+                }
+                rest = tail;
+            }
+            seed = see[0] ^ see[1] ^ see[6] ^ (see[2] ^ see[3]) ^ (see[4] ^ see[5]);
+        }
+
+        // up to six 16-byte steps over what is left
+        const STEP_SECRETS: [u64; 6] = [SECRET[2], SECRET[2], SECRET[1], SECRET[1], SECRET[2], SECRET[1]];
+        let mut steps = rest;
+        let mut i = 0;
+        while i < STEP_SECRETS.len()
+            && let Some((step, tail)) = steps.split_first_chunk::<16>()
+            && !tail.is_empty()
+        {
+            seed = mix(read_u64(step, 0) ^ STEP_SECRETS[i], read_u64(step, 8) ^ seed);
+            steps = tail;
+            i += 1;
+        }
+
+        remainder = rest.len();
+        a ^= read_u64(data, len - 16) ^ remainder as u64;
+        b ^= read_u64(data, len - 8);
+    }
+
+    let (a, b) = mum(a ^ SECRET[1], b ^ seed);
+    mix(a ^ 0xaaaaaaaaaaaaaaaa, b ^ SECRET[1] ^ remainder as u64)
+}
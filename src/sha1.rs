@@ -1,4 +1,6 @@
-use crate::block_api::eager_split_pad;
+use crate::block_api::{BlockBuffer, eager_split_pad, fixed_split_pad, msb_split_bits};
+
+const IV: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
 
 const K: [u32; 4] = [0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xCA62C1D6];
 
@@ -9,25 +11,151 @@ const K: [u32; 4] = [0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xCA62C1D6];
 /// const H: [u8; 20] = sha1(b"data");
 /// ```
 pub const fn sha1(input: &[u8]) -> [u8; 20] {
-    let mut state: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+    let mut state = IV;
+
+    sha1_pad_and_run(input, 0x80, (input.len() as u64).wrapping_mul(8), &mut state);
+
+    output(&state)
+}
+
+/// Compute SHA-1 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken most significant first, so the trailing bits of a message
+/// whose length is not a multiple of 8 occupy the high bits of its last byte.
+/// `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha1_bits;
+/// const H: [u8; 20] = sha1_bits(&[0b1011_0000], 4);
+/// ```
+pub const fn sha1_bits(input: &[u8], bit_len: usize) -> [u8; 20] {
+    let mut state = IV;
+
+    let (input, delim, bit_len) = msb_split_bits(input, bit_len);
+    sha1_pad_and_run(input, delim, bit_len, &mut state);
+
+    output(&state)
+}
+
+/// Compute SHA-1 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 20 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::sha1_trunc;
+/// const H: [u8; 8] = sha1_trunc(b"data");
+/// assert_eq!(H, cthash::sha1(b"data")[..8]);
+/// ```
+pub const fn sha1_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 20, "truncated length must not exceed digest size") }
+
+    let mut state = IV;
+
+    sha1_pad_and_run(input, 0x80, (input.len() as u64).wrapping_mul(8), &mut state);
+
+    output(&state)
+}
+
+/// Compute SHA-1 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing: the digest of another hash function is fed
+/// into the compression function with its padding laid out at compile time.
+/// # Examples
+/// ```
+/// use cthash::{sha1, sha1_fixed};
+/// const H: [u8; 20] = sha1_fixed(&sha1(b"data"));
+/// ```
+pub const fn sha1_fixed<const N: usize>(input: &[u8; N]) -> [u8; 20] {
+    let mut state = IV;
+
+    sha1_fixed_pad_and_run(input, &mut state);
+
+    output(&state)
+}
+
+/// Incremental state of SHA-1.
+#[derive(Clone, Debug)]
+pub(crate) struct Sha1Core {
+    state: [u32; 5],
+    buffer: BlockBuffer<64>,
+}
 
-    sha1_pad_and_run(input, &mut state);
+impl Sha1Core {
+    pub(crate) const SHA1: Self = Self::new(IV);
 
-    let mut out = [0u8; 20];
+    const fn new(iv: [u32; 5]) -> Self {
+        Self {
+            state: iv,
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let (block, data) = self.buffer.fill(data);
+        if let Some(block) = block {
+            compress(&mut self.state, block);
+        }
+
+        let (blocks, rem) = data.as_chunks();
+        let mut i = 0;
+        while i < blocks.len() {
+            compress(&mut self.state, &blocks[i]);
+            i += 1;
+        }
+        self.buffer.keep(rem);
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        let mut state = self.state;
+        sha1_pad_and_run(self.buffer.pending(), 0x80, (self.buffer.len() as u64).wrapping_mul(8), &mut state);
+        output(&state)
+    }
+}
+
+/// Serialize the first `OUT` bytes of the state.
+#[inline(always)]
+const fn output<const OUT: usize>(state: &[u32; 5]) -> [u8; OUT] {
+    let mut out = [0u8; OUT];
+    let (out_chunks, out_rem) = out.as_chunks_mut();
     let mut i = 0;
-    while i < 5 {
-        out.as_chunks_mut().0[i] = state[i].to_be_bytes();
+    while i < out_chunks.len() {
+        out_chunks[i] = state[i].to_be_bytes();
         i += 1;
     }
+    if !out_rem.is_empty() {
+        let last_word = state[i].to_be_bytes();
+        out_rem.copy_from_slice(last_word.split_at(out_rem.len()).0);
+    }
     out
 }
 
 #[inline(always)]
-const fn sha1_pad_and_run(input: &[u8], state: &mut [u32; 5]) {
+const fn sha1_pad_and_run(input: &[u8], delim: u8, bit_len: u64, state: &mut [u32; 5]) {
+    let mut b0 = [0; _];
+    let mut b1 = [0; _];
+    let bit_len = bit_len.to_be_bytes();
+    let (blocks, pad_two_blocks) = eager_split_pad(input, &bit_len, delim, &mut b0, &mut b1);
+
+    let mut i = 0;
+    while i < blocks.len() {
+        compress(state, &blocks[i]);
+        i += 1;
+    }
+
+    if pad_two_blocks {
+        compress(state, &b0);
+        compress(state, &b1);
+    } else {
+        compress(state, &b0);
+    }
+}
+
+#[inline(always)]
+const fn sha1_fixed_pad_and_run<const N: usize>(input: &[u8; N], state: &mut [u32; 5]) {
     let mut b0 = [0; _];
     let mut b1 = [0; _];
-    let bit_len = ((input.len() as u64) * 8).to_be_bytes();
-    let (blocks, pad_two_blocks) = eager_split_pad(input, &bit_len, 0x80, &mut b0, &mut b1);
+    let bit_len = const { ((N as u64) * 8).to_be_bytes() };
+    let (blocks, pad_two_blocks) = fixed_split_pad(input, &bit_len, 0x80, &mut b0, &mut b1);
 
     let mut i = 0;
     while i < blocks.len() {
@@ -1,4 +1,4 @@
-use crate::block_api::eager_split_pad;
+use crate::block_api::{BlockBuffer, eager_split_pad, fixed_split_pad, msb_split_bits};
 
 /// Compute SHA-224 digest.
 /// # Examples
@@ -7,17 +7,56 @@ use crate::block_api::eager_split_pad;
 /// const H: [u8; 28] = sha2_224(b"data");
 /// ```
 pub const fn sha2_224(input: &[u8]) -> [u8; 28] {
-    // SHA-224 IV
-    let mut state = [0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4];
-    sha256_pad_and_run(input, &mut state);
+    let mut state = SHA224_IV;
+    sha256_pad_and_run(input, 0x80, (input.len() as u64).wrapping_mul(8), &mut state);
+    sha256_output(&state)
+}
 
-    let mut out = [0u8; 28];
-    let mut i = 0;
-    while i < 7 {
-        out.as_chunks_mut().0[i] = state[i].to_be_bytes();
-        i += 1;
-    }
-    out
+/// Compute SHA-224 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken most significant first, `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha2_224_bits;
+/// const H: [u8; 28] = sha2_224_bits(&[0b1011_0000], 4);
+/// ```
+pub const fn sha2_224_bits(input: &[u8], bit_len: usize) -> [u8; 28] {
+    let mut state = SHA224_IV;
+    let (input, delim, bit_len) = msb_split_bits(input, bit_len);
+    sha256_pad_and_run(input, delim, bit_len, &mut state);
+    sha256_output(&state)
+}
+
+/// Compute SHA-224 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 28 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::sha2_224_trunc;
+/// const H: [u8; 8] = sha2_224_trunc(b"data");
+/// assert_eq!(H, cthash::sha2_224(b"data")[..8]);
+/// ```
+pub const fn sha2_224_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 28, "truncated length must not exceed digest size") }
+
+    let mut state = SHA224_IV;
+    sha256_pad_and_run(input, 0x80, (input.len() as u64).wrapping_mul(8), &mut state);
+    sha256_output(&state)
+}
+
+/// Compute SHA-224 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing: the digest of another hash function is fed
+/// into the compression function with its padding laid out at compile time.
+/// # Examples
+/// ```
+/// use cthash::{sha2_224, sha2_224_fixed};
+/// const H: [u8; 28] = sha2_224_fixed(&sha2_224(b"data"));
+/// ```
+pub const fn sha2_224_fixed<const N: usize>(input: &[u8; N]) -> [u8; 28] {
+    let mut state = SHA224_IV;
+    sha256_fixed_pad_and_run(input, &mut state);
+    sha256_output(&state)
 }
 
 /// Compute SHA-256 digest.
@@ -27,17 +66,73 @@ pub const fn sha2_224(input: &[u8]) -> [u8; 28] {
 /// const H: [u8; 32] = sha2_256(b"data");
 /// ```
 pub const fn sha2_256(input: &[u8]) -> [u8; 32] {
-    // SHA-256 IV
-    let mut state = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
-    sha256_pad_and_run(input, &mut state);
+    let mut state = SHA256_IV;
+    sha256_pad_and_run(input, 0x80, (input.len() as u64).wrapping_mul(8), &mut state);
+    sha256_output(&state)
+}
 
-    let mut out = [0u8; 32];
-    let mut i = 0;
-    while i < 8 {
-        out.as_chunks_mut().0[i] = state[i].to_be_bytes();
-        i += 1;
-    }
-    out
+/// Compute SHA-256 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken most significant first, `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha2_256_bits;
+/// const H: [u8; 32] = sha2_256_bits(&[0b1011_0000], 4);
+/// ```
+pub const fn sha2_256_bits(input: &[u8], bit_len: usize) -> [u8; 32] {
+    let mut state = SHA256_IV;
+    let (input, delim, bit_len) = msb_split_bits(input, bit_len);
+    sha256_pad_and_run(input, delim, bit_len, &mut state);
+    sha256_output(&state)
+}
+
+/// Compute SHA-256 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 32 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::sha2_256_trunc;
+/// const H: [u8; 8] = sha2_256_trunc(b"data");
+/// assert_eq!(H, cthash::sha2_256(b"data")[..8]);
+/// ```
+///
+/// ```compile_fail
+/// use cthash::sha2_256_trunc;
+/// // longer than the digest
+/// const H: [u8; 33] = sha2_256_trunc(b"data");
+/// ```
+pub const fn sha2_256_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 32, "truncated length must not exceed digest size") }
+
+    let mut state = SHA256_IV;
+    sha256_pad_and_run(input, 0x80, (input.len() as u64).wrapping_mul(8), &mut state);
+    sha256_output(&state)
+}
+
+/// Compute SHA-256 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing: the digest of another hash function is fed
+/// into the compression function with its padding laid out at compile time.
+/// # Examples
+/// ```
+/// use cthash::{sha2_256_fixed, sha2_512};
+/// const H: [u8; 32] = sha2_256_fixed(&sha2_512(b"data"));
+/// ```
+pub const fn sha2_256_fixed<const N: usize>(input: &[u8; N]) -> [u8; 32] {
+    let mut state = SHA256_IV;
+    sha256_fixed_pad_and_run(input, &mut state);
+    sha256_output(&state)
+}
+
+/// Compute double SHA-256 digest `SHA-256(SHA-256(input))`, as used in Bitcoin.
+/// # Examples
+/// ```
+/// use cthash::sha2_256d;
+/// const H: [u8; 32] = sha2_256d(b"data");
+/// assert_eq!(H, cthash::sha2_256(&cthash::sha2_256(b"data")));
+/// ```
+pub const fn sha2_256d(input: &[u8]) -> [u8; 32] {
+    sha2_256_fixed(&sha2_256(input))
 }
 
 /// Compute SHA-384 digest.
@@ -47,26 +142,56 @@ pub const fn sha2_256(input: &[u8]) -> [u8; 32] {
 /// const H: [u8; 48] = sha2_384(b"data");
 /// ```
 pub const fn sha2_384(input: &[u8]) -> [u8; 48] {
-    // SHA-384 IV
-    let mut state = [
-        0xcbbb9d5dc1059ed8,
-        0x629a292a367cd507,
-        0x9159015a3070dd17,
-        0x152fecd8f70e5939,
-        0x67332667ffc00b31,
-        0x8eb44a8768581511,
-        0xdb0c2e0d64f98fa7,
-        0x47b5481dbefa4fa4,
-    ];
-    sha512_pad_and_run(input, &mut state);
-
-    let mut out = [0u8; 48];
-    let mut i = 0;
-    while i < 6 {
-        out.as_chunks_mut().0[i] = state[i].to_be_bytes();
-        i += 1;
-    }
-    out
+    let mut state = SHA384_IV;
+    sha512_pad_and_run(input, 0x80, (input.len() as u128).wrapping_mul(8), &mut state);
+    sha512_output(&state)
+}
+
+/// Compute SHA-384 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken most significant first, `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha2_384_bits;
+/// const H: [u8; 48] = sha2_384_bits(&[0b1011_0000], 4);
+/// ```
+pub const fn sha2_384_bits(input: &[u8], bit_len: usize) -> [u8; 48] {
+    let mut state = SHA384_IV;
+    let (input, delim, bit_len) = msb_split_bits(input, bit_len);
+    sha512_pad_and_run(input, delim, bit_len as u128, &mut state);
+    sha512_output(&state)
+}
+
+/// Compute SHA-384 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 48 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::sha2_384_trunc;
+/// const H: [u8; 8] = sha2_384_trunc(b"data");
+/// assert_eq!(H, cthash::sha2_384(b"data")[..8]);
+/// ```
+pub const fn sha2_384_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 48, "truncated length must not exceed digest size") }
+
+    let mut state = SHA384_IV;
+    sha512_pad_and_run(input, 0x80, (input.len() as u128).wrapping_mul(8), &mut state);
+    sha512_output(&state)
+}
+
+/// Compute SHA-384 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing: the digest of another hash function is fed
+/// into the compression function with its padding laid out at compile time.
+/// # Examples
+/// ```
+/// use cthash::{sha2_384, sha2_384_fixed};
+/// const H: [u8; 48] = sha2_384_fixed(&sha2_384(b"data"));
+/// ```
+pub const fn sha2_384_fixed<const N: usize>(input: &[u8; N]) -> [u8; 48] {
+    let mut state = SHA384_IV;
+    sha512_fixed_pad_and_run(input, &mut state);
+    sha512_output(&state)
 }
 
 /// Compute SHA-512 digest.
@@ -76,34 +201,228 @@ pub const fn sha2_384(input: &[u8]) -> [u8; 48] {
 /// const H: [u8; 64] = sha2_512(b"data");
 /// ```
 pub const fn sha2_512(input: &[u8]) -> [u8; 64] {
-    // SHA-512 IV
-    let mut state = [
-        0x6a09e667f3bcc908,
-        0xbb67ae8584caa73b,
-        0x3c6ef372fe94f82b,
-        0xa54ff53a5f1d36f1,
-        0x510e527fade682d1,
-        0x9b05688c2b3e6c1f,
-        0x1f83d9abfb41bd6b,
-        0x5be0cd19137e2179,
-    ];
-    sha512_pad_and_run(input, &mut state);
-
-    let mut out = [0u8; 64];
+    let mut state = SHA512_IV;
+    sha512_pad_and_run(input, 0x80, (input.len() as u128).wrapping_mul(8), &mut state);
+    sha512_output(&state)
+}
+
+/// Compute SHA-512 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken most significant first, `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha2_512_bits;
+/// const H: [u8; 64] = sha2_512_bits(&[0b1011_0000], 4);
+/// ```
+pub const fn sha2_512_bits(input: &[u8], bit_len: usize) -> [u8; 64] {
+    let mut state = SHA512_IV;
+    let (input, delim, bit_len) = msb_split_bits(input, bit_len);
+    sha512_pad_and_run(input, delim, bit_len as u128, &mut state);
+    sha512_output(&state)
+}
+
+/// Compute SHA-512 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 64 bytes, which is checked at compile time.
+///
+/// Note that this is not SHA-512/256 or SHA-512/224, which use different
+/// initial values.
+/// # Examples
+/// ```
+/// use cthash::sha2_512_trunc;
+/// const H: [u8; 8] = sha2_512_trunc(b"data");
+/// assert_eq!(H, cthash::sha2_512(b"data")[..8]);
+/// ```
+pub const fn sha2_512_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 64, "truncated length must not exceed digest size") }
+
+    let mut state = SHA512_IV;
+    sha512_pad_and_run(input, 0x80, (input.len() as u128).wrapping_mul(8), &mut state);
+    sha512_output(&state)
+}
+
+/// Compute SHA-512 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing: the digest of another hash function is fed
+/// into the compression function with its padding laid out at compile time.
+/// # Examples
+/// ```
+/// use cthash::{sha2_256, sha2_512_fixed};
+/// const H: [u8; 64] = sha2_512_fixed(&sha2_256(b"data"));
+/// ```
+pub const fn sha2_512_fixed<const N: usize>(input: &[u8; N]) -> [u8; 64] {
+    let mut state = SHA512_IV;
+    sha512_fixed_pad_and_run(input, &mut state);
+    sha512_output(&state)
+}
+
+const SHA224_IV: [u32; 8] = [0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4];
+
+const SHA256_IV: [u32; 8] = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+const SHA384_IV: [u64; 8] = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+
+const SHA512_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// Incremental state of SHA-224 and SHA-256.
+#[derive(Clone, Debug)]
+pub(crate) struct Sha256Core {
+    state: [u32; 8],
+    buffer: BlockBuffer<64>,
+}
+
+impl Sha256Core {
+    pub(crate) const SHA224: Self = Self::new(SHA224_IV);
+    pub(crate) const SHA256: Self = Self::new(SHA256_IV);
+
+    const fn new(iv: [u32; 8]) -> Self {
+        Self {
+            state: iv,
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let (block, data) = self.buffer.fill(data);
+        if let Some(block) = block {
+            sha256_compress(&mut self.state, block);
+        }
+
+        let (blocks, rem) = data.as_chunks();
+        let mut i = 0;
+        while i < blocks.len() {
+            sha256_compress(&mut self.state, &blocks[i]);
+            i += 1;
+        }
+        self.buffer.keep(rem);
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        let mut state = self.state;
+        sha256_pad_and_run(self.buffer.pending(), 0x80, (self.buffer.len() as u64).wrapping_mul(8), &mut state);
+        sha256_output(&state)
+    }
+}
+
+/// Incremental state of SHA-384 and SHA-512.
+#[derive(Clone, Debug)]
+pub(crate) struct Sha512Core {
+    state: [u64; 8],
+    buffer: BlockBuffer<128>,
+}
+
+impl Sha512Core {
+    pub(crate) const SHA384: Self = Self::new(SHA384_IV);
+    pub(crate) const SHA512: Self = Self::new(SHA512_IV);
+
+    const fn new(iv: [u64; 8]) -> Self {
+        Self {
+            state: iv,
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let (block, data) = self.buffer.fill(data);
+        if let Some(block) = block {
+            sha512_compress(&mut self.state, block);
+        }
+
+        let (blocks, rem) = data.as_chunks();
+        let mut i = 0;
+        while i < blocks.len() {
+            sha512_compress(&mut self.state, &blocks[i]);
+            i += 1;
+        }
+        self.buffer.keep(rem);
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        let mut state = self.state;
+        sha512_pad_and_run(self.buffer.pending(), 0x80, self.buffer.len().wrapping_mul(8), &mut state);
+        sha512_output(&state)
+    }
+}
+
+/// Serialize the first `OUT` bytes of the state.
+#[inline(always)]
+const fn sha256_output<const OUT: usize>(state: &[u32; 8]) -> [u8; OUT] {
+    let mut out = [0u8; OUT];
+    let (out_chunks, out_rem) = out.as_chunks_mut();
     let mut i = 0;
-    while i < 8 {
-        out.as_chunks_mut().0[i] = state[i].to_be_bytes();
+    while i < out_chunks.len() {
+        out_chunks[i] = state[i].to_be_bytes();
         i += 1;
     }
+    if !out_rem.is_empty() {
+        let last_word = state[i].to_be_bytes();
+        out_rem.copy_from_slice(last_word.split_at(out_rem.len()).0);
+    }
     out
 }
 
+/// Serialize the first `OUT` bytes of the state.
 #[inline(always)]
-const fn sha256_pad_and_run(input: &[u8], state: &mut [u32; 8]) {
+const fn sha512_output<const OUT: usize>(state: &[u64; 8]) -> [u8; OUT] {
+    let mut out = [0u8; OUT];
+    let (out_chunks, out_rem) = out.as_chunks_mut();
+    let mut i = 0;
+    while i < out_chunks.len() {
+        out_chunks[i] = state[i].to_be_bytes();
+        i += 1;
+    }
+    if !out_rem.is_empty() {
+        let last_word = state[i].to_be_bytes();
+        out_rem.copy_from_slice(last_word.split_at(out_rem.len()).0);
+    }
+    out
+}
+
+#[inline(always)]
+const fn sha256_pad_and_run(input: &[u8], delim: u8, bit_len: u64, state: &mut [u32; 8]) {
+    let mut b0 = [0; _];
+    let mut b1 = [0; _];
+    let bit_len = bit_len.to_be_bytes();
+    let (blocks, pad_two_blocks) = eager_split_pad(input, &bit_len, delim, &mut b0, &mut b1);
+
+    let mut i = 0;
+    while i < blocks.len() {
+        sha256_compress(state, &blocks[i]);
+        i += 1;
+    }
+
+    if pad_two_blocks {
+        sha256_compress(state, &b0);
+        sha256_compress(state, &b1);
+    } else {
+        sha256_compress(state, &b0);
+    }
+}
+
+#[inline(always)]
+const fn sha256_fixed_pad_and_run<const N: usize>(input: &[u8; N], state: &mut [u32; 8]) {
     let mut b0 = [0; _];
     let mut b1 = [0; _];
-    let bit_len = ((input.len() as u64) * 8).to_be_bytes();
-    let (blocks, pad_two_blocks) = eager_split_pad(input, &bit_len, 0x80, &mut b0, &mut b1);
+    let bit_len = const { ((N as u64) * 8).to_be_bytes() };
+    let (blocks, pad_two_blocks) = fixed_split_pad(input, &bit_len, 0x80, &mut b0, &mut b1);
 
     let mut i = 0;
     while i < blocks.len() {
@@ -179,11 +498,32 @@ const fn sha256_compress(state: &mut [u32; 8], block: &[u8; 64]) {
 }
 
 #[inline(always)]
-const fn sha512_pad_and_run(input: &[u8], state: &mut [u64; 8]) {
+const fn sha512_pad_and_run(input: &[u8], delim: u8, bit_len: u128, state: &mut [u64; 8]) {
+    let mut b0 = [0; _];
+    let mut b1 = [0; _];
+    let bit_len = bit_len.to_be_bytes();
+    let (blocks, pad_two_blocks) = eager_split_pad(input, &bit_len, delim, &mut b0, &mut b1);
+
+    let mut i = 0;
+    while i < blocks.len() {
+        sha512_compress(state, &blocks[i]);
+        i += 1;
+    }
+
+    if pad_two_blocks {
+        sha512_compress(state, &b0);
+        sha512_compress(state, &b1);
+    } else {
+        sha512_compress(state, &b0);
+    }
+}
+
+#[inline(always)]
+const fn sha512_fixed_pad_and_run<const N: usize>(input: &[u8; N], state: &mut [u64; 8]) {
     let mut b0 = [0; _];
     let mut b1 = [0; _];
-    let bit_len = ((input.len() as u128) * 8).to_be_bytes();
-    let (blocks, pad_two_blocks) = eager_split_pad(input, &bit_len, 0x80, &mut b0, &mut b1);
+    let bit_len = const { ((N as u128) * 8).to_be_bytes() };
+    let (blocks, pad_two_blocks) = fixed_split_pad(input, &bit_len, 0x80, &mut b0, &mut b1);
 
     let mut i = 0;
     while i < blocks.len() {
@@ -1,3 +1,5 @@
+use crate::block_api::{BlockBuffer, split_bits};
+
 /// Compute SHA3-224 digest.
 /// # Examples
 /// ```
@@ -5,7 +7,46 @@
 /// const H: [u8; 28] = sha3_224(b"data");
 /// ```
 pub const fn sha3_224(input: &[u8]) -> [u8; 28] {
-    keccak::<28, 144>(input, 0x06)
+    KeccakSponge::<144, 0x06>::hash(input)
+}
+
+/// Compute SHA3-224 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 28 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::sha3_224_trunc;
+/// const H: [u8; 8] = sha3_224_trunc(b"data");
+/// assert_eq!(H, cthash::sha3_224(b"data")[..8]);
+/// ```
+pub const fn sha3_224_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 28, "truncated length must not exceed digest size") }
+
+    KeccakSponge::<144, 0x06>::hash(input)
+}
+
+/// Compute SHA3-224 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing, e.g. of another digest.
+/// # Examples
+/// ```
+/// use cthash::{sha3_224, sha3_224_fixed};
+/// const H: [u8; 28] = sha3_224_fixed(&sha3_224(b"data"));
+/// ```
+pub const fn sha3_224_fixed<const N: usize>(input: &[u8; N]) -> [u8; 28] {
+    KeccakSponge::<144, 0x06>::hash_fixed(input)
+}
+
+/// Compute SHA3-224 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken least significant first, `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha3_224_bits;
+/// const H: [u8; 28] = sha3_224_bits(&[0b0000_1101], 4);
+/// ```
+pub const fn sha3_224_bits(input: &[u8], bit_len: usize) -> [u8; 28] {
+    KeccakSponge::<144, 0x06>::hash_bits(input, bit_len)
 }
 
 /// Compute SHA3-256 digest.
@@ -15,7 +56,46 @@ pub const fn sha3_224(input: &[u8]) -> [u8; 28] {
 /// const H: [u8; 32] = sha3_256(b"data");
 /// ```
 pub const fn sha3_256(input: &[u8]) -> [u8; 32] {
-    keccak::<32, 136>(input, 0x06)
+    KeccakSponge::<136, 0x06>::hash(input)
+}
+
+/// Compute SHA3-256 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 32 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::sha3_256_trunc;
+/// const H: [u8; 8] = sha3_256_trunc(b"data");
+/// assert_eq!(H, cthash::sha3_256(b"data")[..8]);
+/// ```
+pub const fn sha3_256_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 32, "truncated length must not exceed digest size") }
+
+    KeccakSponge::<136, 0x06>::hash(input)
+}
+
+/// Compute SHA3-256 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing, e.g. of another digest.
+/// # Examples
+/// ```
+/// use cthash::{sha3_256, sha3_256_fixed};
+/// const H: [u8; 32] = sha3_256_fixed(&sha3_256(b"data"));
+/// ```
+pub const fn sha3_256_fixed<const N: usize>(input: &[u8; N]) -> [u8; 32] {
+    KeccakSponge::<136, 0x06>::hash_fixed(input)
+}
+
+/// Compute SHA3-256 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken least significant first, `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha3_256_bits;
+/// const H: [u8; 32] = sha3_256_bits(&[0b0000_1101], 4);
+/// ```
+pub const fn sha3_256_bits(input: &[u8], bit_len: usize) -> [u8; 32] {
+    KeccakSponge::<136, 0x06>::hash_bits(input, bit_len)
 }
 
 /// Compute SHA3-384 digest.
@@ -25,7 +105,46 @@ pub const fn sha3_256(input: &[u8]) -> [u8; 32] {
 /// const H: [u8; 48] = sha3_384(b"data");
 /// ```
 pub const fn sha3_384(input: &[u8]) -> [u8; 48] {
-    keccak::<48, 104>(input, 0x06)
+    KeccakSponge::<104, 0x06>::hash(input)
+}
+
+/// Compute SHA3-384 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 48 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::sha3_384_trunc;
+/// const H: [u8; 8] = sha3_384_trunc(b"data");
+/// assert_eq!(H, cthash::sha3_384(b"data")[..8]);
+/// ```
+pub const fn sha3_384_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 48, "truncated length must not exceed digest size") }
+
+    KeccakSponge::<104, 0x06>::hash(input)
+}
+
+/// Compute SHA3-384 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing, e.g. of another digest.
+/// # Examples
+/// ```
+/// use cthash::{sha3_384, sha3_384_fixed};
+/// const H: [u8; 48] = sha3_384_fixed(&sha3_384(b"data"));
+/// ```
+pub const fn sha3_384_fixed<const N: usize>(input: &[u8; N]) -> [u8; 48] {
+    KeccakSponge::<104, 0x06>::hash_fixed(input)
+}
+
+/// Compute SHA3-384 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken least significant first, `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha3_384_bits;
+/// const H: [u8; 48] = sha3_384_bits(&[0b0000_1101], 4);
+/// ```
+pub const fn sha3_384_bits(input: &[u8], bit_len: usize) -> [u8; 48] {
+    KeccakSponge::<104, 0x06>::hash_bits(input, bit_len)
 }
 
 /// Compute SHA3-512 digest.
@@ -35,7 +154,46 @@ pub const fn sha3_384(input: &[u8]) -> [u8; 48] {
 /// const H: [u8; 64] = sha3_512(b"data");
 /// ```
 pub const fn sha3_512(input: &[u8]) -> [u8; 64] {
-    keccak::<64, 72>(input, 0x06)
+    KeccakSponge::<72, 0x06>::hash(input)
+}
+
+/// Compute SHA3-512 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 64 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::sha3_512_trunc;
+/// const H: [u8; 8] = sha3_512_trunc(b"data");
+/// assert_eq!(H, cthash::sha3_512(b"data")[..8]);
+/// ```
+pub const fn sha3_512_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 64, "truncated length must not exceed digest size") }
+
+    KeccakSponge::<72, 0x06>::hash(input)
+}
+
+/// Compute SHA3-512 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing, e.g. of another digest.
+/// # Examples
+/// ```
+/// use cthash::{sha3_512, sha3_512_fixed};
+/// const H: [u8; 64] = sha3_512_fixed(&sha3_512(b"data"));
+/// ```
+pub const fn sha3_512_fixed<const N: usize>(input: &[u8; N]) -> [u8; 64] {
+    KeccakSponge::<72, 0x06>::hash_fixed(input)
+}
+
+/// Compute SHA3-512 digest of the first `bit_len` bits of `input`.
+///
+/// Bits are taken least significant first, `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+/// # Examples
+/// ```
+/// use cthash::sha3_512_bits;
+/// const H: [u8; 64] = sha3_512_bits(&[0b0000_1101], 4);
+/// ```
+pub const fn sha3_512_bits(input: &[u8], bit_len: usize) -> [u8; 64] {
+    KeccakSponge::<72, 0x06>::hash_bits(input, bit_len)
 }
 
 /// Compute pre-NISE Keccak-224 digest.
@@ -45,7 +203,34 @@ pub const fn sha3_512(input: &[u8]) -> [u8; 64] {
 /// const H: [u8; 28] = keccak_224(b"data");
 /// ```
 pub const fn keccak_224(input: &[u8]) -> [u8; 28] {
-    keccak::<28, 144>(input, 0x01)
+    KeccakSponge::<144, 0x01>::hash(input)
+}
+
+/// Compute pre-NISE Keccak-224 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 28 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::keccak_224_trunc;
+/// const H: [u8; 8] = keccak_224_trunc(b"data");
+/// assert_eq!(H, cthash::keccak_224(b"data")[..8]);
+/// ```
+pub const fn keccak_224_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 28, "truncated length must not exceed digest size") }
+
+    KeccakSponge::<144, 0x01>::hash(input)
+}
+
+/// Compute pre-NISE Keccak-224 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing, e.g. of another digest.
+/// # Examples
+/// ```
+/// use cthash::{keccak_224, keccak_224_fixed};
+/// const H: [u8; 28] = keccak_224_fixed(&keccak_224(b"data"));
+/// ```
+pub const fn keccak_224_fixed<const N: usize>(input: &[u8; N]) -> [u8; 28] {
+    KeccakSponge::<144, 0x01>::hash_fixed(input)
 }
 
 /// Compute pre-NISE Keccak-256 digest.
@@ -55,7 +240,34 @@ pub const fn keccak_224(input: &[u8]) -> [u8; 28] {
 /// const H: [u8; 32] = keccak_256(b"data");
 /// ```
 pub const fn keccak_256(input: &[u8]) -> [u8; 32] {
-    keccak::<32, 136>(input, 0x01)
+    KeccakSponge::<136, 0x01>::hash(input)
+}
+
+/// Compute pre-NISE Keccak-256 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 32 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::keccak_256_trunc;
+/// const H: [u8; 8] = keccak_256_trunc(b"data");
+/// assert_eq!(H, cthash::keccak_256(b"data")[..8]);
+/// ```
+pub const fn keccak_256_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 32, "truncated length must not exceed digest size") }
+
+    KeccakSponge::<136, 0x01>::hash(input)
+}
+
+/// Compute pre-NISE Keccak-256 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing, e.g. of another digest.
+/// # Examples
+/// ```
+/// use cthash::{keccak_256, keccak_256_fixed};
+/// const H: [u8; 32] = keccak_256_fixed(&keccak_256(b"data"));
+/// ```
+pub const fn keccak_256_fixed<const N: usize>(input: &[u8; N]) -> [u8; 32] {
+    KeccakSponge::<136, 0x01>::hash_fixed(input)
 }
 
 /// Compute pre-NISE Keccak-384 digest.
@@ -65,7 +277,34 @@ pub const fn keccak_256(input: &[u8]) -> [u8; 32] {
 /// const H: [u8; 48] = keccak_384(b"data");
 /// ```
 pub const fn keccak_384(input: &[u8]) -> [u8; 48] {
-    keccak::<48, 104>(input, 0x01)
+    KeccakSponge::<104, 0x01>::hash(input)
+}
+
+/// Compute pre-NISE Keccak-384 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 48 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::keccak_384_trunc;
+/// const H: [u8; 8] = keccak_384_trunc(b"data");
+/// assert_eq!(H, cthash::keccak_384(b"data")[..8]);
+/// ```
+pub const fn keccak_384_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 48, "truncated length must not exceed digest size") }
+
+    KeccakSponge::<104, 0x01>::hash(input)
+}
+
+/// Compute pre-NISE Keccak-384 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing, e.g. of another digest.
+/// # Examples
+/// ```
+/// use cthash::{keccak_384, keccak_384_fixed};
+/// const H: [u8; 48] = keccak_384_fixed(&keccak_384(b"data"));
+/// ```
+pub const fn keccak_384_fixed<const N: usize>(input: &[u8; N]) -> [u8; 48] {
+    KeccakSponge::<104, 0x01>::hash_fixed(input)
 }
 
 /// Compute pre-NISE Keccak-512 digest.
@@ -75,69 +314,312 @@ pub const fn keccak_384(input: &[u8]) -> [u8; 48] {
 /// const H: [u8; 64] = keccak_512(b"data");
 /// ```
 pub const fn keccak_512(input: &[u8]) -> [u8; 64] {
-    keccak::<64, 72>(input, 0x01)
+    KeccakSponge::<72, 0x01>::hash(input)
 }
 
-const fn keccak<const OUT: usize, const RATE: usize>(input: &[u8], domain: u8) -> [u8; OUT] {
-    let mut state = [0u64; 25];
+/// Compute pre-NISE Keccak-512 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 64 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::keccak_512_trunc;
+/// const H: [u8; 8] = keccak_512_trunc(b"data");
+/// assert_eq!(H, cthash::keccak_512(b"data")[..8]);
+/// ```
+pub const fn keccak_512_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 64, "truncated length must not exceed digest size") }
 
-    let (blocks, rem) = input.as_chunks();
+    KeccakSponge::<72, 0x01>::hash(input)
+}
 
-    // Absorb full blocks
-    let mut i = 0usize;
-    while i < blocks.len() {
-        absorb_block::<RATE>(&mut state, &blocks[i]);
-        keccak_f1600(&mut state);
-        i += 1;
+/// Compute pre-NISE Keccak-512 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing, e.g. of another digest.
+/// # Examples
+/// ```
+/// use cthash::{keccak_512, keccak_512_fixed};
+/// const H: [u8; 64] = keccak_512_fixed(&keccak_512(b"data"));
+/// ```
+pub const fn keccak_512_fixed<const N: usize>(input: &[u8; N]) -> [u8; 64] {
+    KeccakSponge::<72, 0x01>::hash_fixed(input)
+}
+
+/// Keccak-f\[1600\] sponge with a configurable rate and domain-separation suffix.
+///
+/// `RATE` is the number of bytes absorbed and squeezed per permutation, the
+/// capacity is the remaining `200 - RATE` bytes of the state. `SUFFIX` is the
+/// domain-separation suffix in the "delimited" form used by the Keccak Code
+/// Package: the suffix bits followed by a single `1` bit, least significant
+/// bit first. The final `1` bit of the pad10*1 padding is added by the sponge.
+///
+/// | Function      | `SUFFIX` |
+/// |---------------|----------|
+/// | Keccak        | `0x01`   |
+/// | SHA-3         | `0x06`   |
+/// | SHAKE         | `0x1f`   |
+///
+/// Invalid configurations (a rate of zero or not below 200 bytes, an empty
+/// suffix) are rejected at compile time.
+///
+/// # Examples
+/// ```
+/// use cthash::KeccakSponge;
+///
+/// // SHAKE128 with a 64-byte output
+/// type Shake128 = KeccakSponge<168, 0x1f>;
+/// const H: [u8; 64] = Shake128::hash(b"data");
+///
+/// // SHA3-256 is a sponge with 136-byte rate and `01` suffix
+/// const A: [u8; 32] = KeccakSponge::<136, 0x06>::hash(b"data");
+/// assert_eq!(A, cthash::sha3_256(b"data"));
+/// ```
+///
+/// ```compile_fail
+/// use cthash::KeccakSponge;
+/// // rate must be below the 200-byte state size
+/// const H: [u8; 32] = KeccakSponge::<200, 0x06>::hash(b"data");
+/// ```
+pub struct KeccakSponge<const RATE: usize, const SUFFIX: u8>;
+
+impl<const RATE: usize, const SUFFIX: u8> KeccakSponge<RATE, SUFFIX> {
+    /// Number of bytes of the state never touched by input or output.
+    pub const CAPACITY: usize = 200 - RATE;
+
+    /// Absorb `input`, apply padding and squeeze `OUT` bytes.
+    ///
+    /// `OUT` is not limited by the state size, the sponge permutes the state
+    /// again once every `RATE` bytes of output.
+    pub const fn hash<const OUT: usize>(input: &[u8]) -> [u8; OUT] {
+        let mut state = Self::absorb(input, 0, 0);
+        Self::squeeze(&mut state)
     }
 
-    // Last block with padding
-    let mut block = [0u8; RATE];
+    /// Absorb the first `bit_len` bits of `input`, apply padding and squeeze `OUT` bytes.
+    ///
+    /// Bits are taken least significant first as in FIPS 202, so the trailing
+    /// bits of a message whose length is not a multiple of 8 occupy the low
+    /// bits of its last byte. `bit_len` saturates at `8 * input.len()`, hashing the whole input.
+    pub const fn hash_bits<const OUT: usize>(input: &[u8], bit_len: usize) -> [u8; OUT] {
+        let (input, last, last_bits) = split_bits(input, bit_len);
+        let mut state = Self::absorb(input, last, last_bits);
+        Self::squeeze(&mut state)
+    }
 
-    // copy remainder
-    block.split_at_mut(rem.len()).0.copy_from_slice(rem);
+    /// Absorb a message of `N` bytes, with the position of the suffix and
+    /// whether the padding spills into another block known at compile time,
+    /// then squeeze `OUT` bytes.
+    #[inline(always)]
+    const fn hash_fixed<const N: usize, const OUT: usize>(input: &[u8; N]) -> [u8; OUT] {
+        Self::check();
 
-    block[rem.len()] ^= domain;
-    block[RATE - 1] ^= 0x80;
+        let mut state = [0u64; 25];
 
-    absorb_block::<RATE>(&mut state, &block);
-    keccak_f1600(&mut state);
+        let (blocks, rem) = input.as_chunks::<RATE>();
+        let mut i = 0;
+        while i < blocks.len() {
+            absorb_block(&mut state, &blocks[i]);
+            keccak_f1600(&mut state);
+            i += 1;
+        }
 
-    // Squeeze
+        // the delimiter bit of the suffix takes the last bit of the block
+        // only when the suffix starts in its last byte with the top bit set
+        let pad_two_blocks = const { N % RATE == RATE - 1 && SUFFIX >= 0x80 };
 
-    // This check should be optimized out at compile time
-    // If OUT is bigger, the squeezing phase would need one or more permutations
-    // which is not implemented here.
-    assert!(OUT <= 25 * 8, "output length must be less than state size");
+        let mut block = [0u8; RATE];
+        if let Some((head, _)) = block.split_at_mut_checked(N % RATE) {
+            head.copy_from_slice(rem);
+        }
+        block[N % RATE] ^= SUFFIX;
 
-    let mut out = [0u8; OUT];
-    let (out_chunks, out_rem) = out.as_chunks_mut();
+        if pad_two_blocks {
+            let mut next = [0u8; RATE];
+            next[RATE - 1] ^= 0x80;
+            absorb_block(&mut state, &block);
+            keccak_f1600(&mut state);
+            absorb_block(&mut state, &next);
+            keccak_f1600(&mut state);
+        } else {
+            block[RATE - 1] ^= 0x80;
+            absorb_block(&mut state, &block);
+            keccak_f1600(&mut state);
+        }
+
+        Self::squeeze(&mut state)
+    }
 
-    let mut i = 0;
-    while i < out_chunks.len() {
-        out_chunks[i] = state[i].to_le_bytes();
-        i += 1;
+    /// Reject invalid configurations at compile time.
+    #[inline(always)]
+    const fn check() {
+        const {
+            assert!(RATE > 0, "rate must not be zero");
+            assert!(RATE < 200, "rate must be less than state size");
+            assert!(SUFFIX != 0, "suffix must contain the delimiter bit");
+        }
     }
-    if !out_rem.is_empty() {
-        let last_state = state[i].to_le_bytes();
-        let (last_state, _) = last_state.split_at(out_rem.len());
-        out_rem.copy_from_slice(last_state);
+
+    /// Absorb whole bytes of `input` followed by `last_bits` low bits of `last`.
+    #[inline(always)]
+    const fn absorb(input: &[u8], last: u8, last_bits: u32) -> [u64; 25] {
+        Self::check();
+
+        let mut state = [0u64; 25];
+
+        let (blocks, rem) = input.as_chunks::<RATE>();
+
+        // Absorb full blocks
+        let mut i = 0usize;
+        while i < blocks.len() {
+            absorb_block(&mut state, &blocks[i]);
+            keccak_f1600(&mut state);
+            i += 1;
+        }
+
+        Self::absorb_last(&mut state, rem, last, last_bits);
+        state
     }
 
-    out
+    /// Absorb the last partial block `rem`, followed by `last_bits` low bits
+    /// of `last` and the padding.
+    #[inline(always)]
+    const fn absorb_last(state: &mut [u64; 25], rem: &[u8], last: u8, last_bits: u32) {
+        // Last block with padding, the suffix may spill into one more block
+        let mut block = [0u8; RATE];
+        let mut next = [0u8; RATE];
+
+        // copy remainder, always shorter than a block
+        let len = rem.len();
+        if let Some((head, _)) = block.split_at_mut_checked(len) {
+            head.copy_from_slice(rem);
+        }
+
+        // trailing message bits followed by the suffix
+        let tail = (last as u16 & ((1 << last_bits) - 1)) | (SUFFIX as u16) << last_bits;
+        let [lo, hi] = tail.to_le_bytes();
+        if len < RATE {
+            block[len] ^= lo;
+        }
+        if len + 1 < RATE {
+            block[len + 1] ^= hi;
+        } else {
+            next[0] ^= hi;
+        }
+
+        // position of the delimiter bit in the last block
+        let delim_pos = 8 * rem.len() + last_bits as usize + 7 - SUFFIX.leading_zeros() as usize;
+
+        // the final padding bit goes to the next block when the delimiter
+        // bit took the last bit of this one
+        let count = if delim_pos < 8 * RATE - 1 {
+            block[RATE - 1] ^= 0x80;
+            1
+        } else {
+            next[RATE - 1] ^= 0x80;
+            2
+        };
+
+        let last = [block, next];
+        let mut i = 0;
+        while i < count && i < last.len() {
+            absorb_block(state, &last[i]);
+            keccak_f1600(state);
+            i += 1;
+        }
+    }
+
+    #[inline(always)]
+    const fn squeeze<const OUT: usize>(state: &mut [u64; 25]) -> [u8; OUT] {
+        let mut out = [0u8; OUT];
+        let (blocks, rem) = out.as_chunks_mut::<RATE>();
+        let mut i = 0;
+        while i < blocks.len() {
+            squeeze_block(state, &mut blocks[i]);
+            i += 1;
+            if i < blocks.len() || !rem.is_empty() {
+                keccak_f1600(state);
+            }
+        }
+        if !rem.is_empty() {
+            squeeze_block(state, rem);
+        }
+
+        out
+    }
+}
+
+/// Incremental state of a Keccak sponge with a fixed output.
+#[derive(Clone, Debug)]
+pub(crate) struct KeccakCore<const RATE: usize, const SUFFIX: u8> {
+    state: [u64; 25],
+    buffer: BlockBuffer<RATE>,
+}
+
+impl<const RATE: usize, const SUFFIX: u8> KeccakCore<RATE, SUFFIX> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            state: [0; 25],
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let (block, data) = self.buffer.fill(data);
+        if let Some(block) = block {
+            absorb_block(&mut self.state, block);
+            keccak_f1600(&mut self.state);
+        }
+
+        let (blocks, rem) = data.as_chunks::<RATE>();
+        let mut i = 0;
+        while i < blocks.len() {
+            absorb_block(&mut self.state, &blocks[i]);
+            keccak_f1600(&mut self.state);
+            i += 1;
+        }
+        self.buffer.keep(rem);
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        let mut state = self.state;
+        KeccakSponge::<RATE, SUFFIX>::absorb_last(&mut state, self.buffer.pending(), 0, 0);
+        KeccakSponge::<RATE, SUFFIX>::squeeze(&mut state)
+    }
 }
 
 #[inline(always)]
 const fn absorb_block<const RATE: usize>(s: &mut [u64; 25], block: &[u8; RATE]) {
     let (chunks, rem) = block.as_chunks();
     let mut k = 0usize;
-    while k < chunks.len() {
+    while k < chunks.len() && k < 25 {
         s[k] ^= u64::from_le_bytes(chunks[k]);
         k += 1;
     }
 
-    // This check should be optimized out at compile time
-    assert!(rem.is_empty(), "block length must be multiple of 8");
+    // Rate is not a multiple of lane size
+    if !rem.is_empty() && k < 25 {
+        let mut lane = [0u8; 8];
+        if let Some((head, _)) = lane.split_at_mut_checked(rem.len()) {
+            head.copy_from_slice(rem);
+        }
+        s[k] ^= u64::from_le_bytes(lane);
+    }
+}
+
+#[inline(always)]
+const fn squeeze_block(s: &[u64; 25], out: &mut [u8]) {
+    let (out_chunks, out_rem) = out.as_chunks_mut();
+
+    let mut k = 0;
+    while k < out_chunks.len() && k < 25 {
+        out_chunks[k] = s[k].to_le_bytes();
+        k += 1;
+    }
+    if !out_rem.is_empty() && k < 25 {
+        let last_state = s[k].to_le_bytes();
+        if let Some((last_state, _)) = last_state.split_at_checked(out_rem.len()) {
+            out_rem.copy_from_slice(last_state);
+        }
+    }
 }
 
 const RC: [u64; 24] = [
@@ -169,6 +651,7 @@ const RC: [u64; 24] = [
 
 const RHO: [u32; 25] = [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
 
+#[inline(always)]
 const fn keccak_f1600(a: &mut [u64; 25]) {
     let mut round = 0;
     while round < 24 {
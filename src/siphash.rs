@@ -0,0 +1,258 @@
+use core::hash::{BuildHasher, Hasher};
+
+/// Compute 64-bit SipHash-2-4 of `data` with a 128-bit key.
+/// # Examples
+/// ```
+/// use cthash::siphash24;
+/// const KEY: [u8; 16] = *b"0123456789abcdef";
+/// const BUCKET: u64 = siphash24(&KEY, b"data") % 64;
+/// ```
+pub const fn siphash24(key: &[u8; 16], data: &[u8]) -> u64 {
+    let mut hasher = SipHasher24::new(key);
+    hasher.update(data);
+    hasher.finish64()
+}
+
+/// Compute 128-bit SipHash-2-4 of `data` with a 128-bit key.
+///
+/// The 16 output bytes of the reference implementation are `siphash24_128(..).to_le_bytes()`.
+pub const fn siphash24_128(key: &[u8; 16], data: &[u8]) -> u128 {
+    let mut hasher = SipHasher24::new_128(key);
+    hasher.update(data);
+    hasher.finish128()
+}
+
+/// Compute 64-bit SipHash-1-3 of `data` with a 128-bit key.
+/// # Examples
+/// ```
+/// use cthash::siphash13;
+/// const H: u64 = siphash13(b"0123456789abcdef", b"data");
+/// ```
+pub const fn siphash13(key: &[u8; 16], data: &[u8]) -> u64 {
+    let mut hasher = SipHasher13::new(key);
+    hasher.update(data);
+    hasher.finish64()
+}
+
+/// Compute 128-bit SipHash-1-3 of `data` with a 128-bit key.
+///
+/// The 16 output bytes of the reference implementation are `siphash13_128(..).to_le_bytes()`.
+pub const fn siphash13_128(key: &[u8; 16], data: &[u8]) -> u128 {
+    let mut hasher = SipHasher13::new_128(key);
+    hasher.update(data);
+    hasher.finish128()
+}
+
+/// Streaming SipHash with `C` compression and `D` finalization rounds,
+/// computing the same 64-bit hash as [`siphash24`] or [`siphash13`] over
+/// all bytes written.
+///
+/// Values hashed through [`Hash`](core::hash::Hash) are fed as the bytes
+/// their implementation writes: integers in native byte order, `str` followed
+/// by a `0xff` byte, slices prefixed by their length.
+///
+/// # Examples
+/// ```
+/// use core::hash::{BuildHasher, Hasher};
+/// use cthash::{SipBuildHasher24, SipHasher24, siphash24};
+///
+/// const KEY: [u8; 16] = *b"0123456789abcdef";
+/// const H: u64 = siphash24(&KEY, b"data\xff");
+///
+/// let mut hasher = SipHasher24::new(&KEY);
+/// hasher.write(b"data\xff");
+/// assert_eq!(hasher.finish(), H);
+///
+/// // the same hash for `HashMap<&str, _, SipBuildHasher24>` keys
+/// let build = SipBuildHasher24::new(&KEY);
+/// assert_eq!(build.hash_one("data"), H);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SipHasher<const C: usize, const D: usize> {
+    v: [u64; 4],
+    buffer: [u8; 8],
+    buffered: usize,
+    length: usize,
+}
+
+/// SipHash-2-4 hasher.
+pub type SipHasher24 = SipHasher<2, 4>;
+
+/// SipHash-1-3 hasher.
+pub type SipHasher13 = SipHasher<1, 3>;
+
+impl<const C: usize, const D: usize> SipHasher<C, D> {
+    /// Create a hasher with a 128-bit key.
+    pub const fn new(key: &[u8; 16]) -> Self {
+        let (k0, k1) = key.split_at(8);
+        let k0 = read_u64(k0);
+        let k1 = read_u64(k1);
+        Self {
+            v: [k0 ^ 0x736f6d6570736575, k1 ^ 0x646f72616e646f6d, k0 ^ 0x6c7967656e657261, k1 ^ 0x7465646279746573],
+            buffer: [0; 8],
+            buffered: 0,
+            length: 0,
+        }
+    }
+
+    /// Hasher for the 128-bit output, which uses a different initial state.
+    const fn new_128(key: &[u8; 16]) -> Self {
+        let mut hasher = Self::new(key);
+        hasher.v[1] ^= 0xee;
+        hasher
+    }
+
+    /// Absorb `data`, keeping a partial word buffered for the next call.
+    pub const fn update(&mut self, data: &[u8]) {
+        self.length = self.length.wrapping_add(data.len());
+
+        let mut buffered = self.buffered;
+        let mut i = 0;
+        while buffered != 0 && buffered < 8 && i < data.len() {
+            self.buffer[buffered] = data[i];
+            buffered += 1;
+            i += 1;
+            if buffered == 8 {
+                compress(&mut self.v, u64::from_le_bytes(self.buffer), C);
+                buffered = 0;
+            }
+        }
+
+        let (_, data) = data.split_at(if i < data.len() { i } else { data.len() });
+        let (words, rem) = data.as_chunks::<8>();
+        let mut j = 0;
+        while j < words.len() {
+            compress(&mut self.v, u64::from_le_bytes(words[j]), C);
+            j += 1;
+        }
+        let mut j = 0;
+        while j < rem.len() && j < 8 {
+            self.buffer[j] = rem[j];
+            j += 1;
+        }
+        if !rem.is_empty() {
+            buffered = rem.len();
+        }
+        self.buffered = buffered;
+    }
+
+    /// 64-bit hash of the bytes absorbed so far.
+    pub const fn finish64(&self) -> u64 {
+        let mut v = self.last_block();
+        v[2] ^= 0xff;
+        rounds(&mut v, D);
+        v[0] ^ v[1] ^ v[2] ^ v[3]
+    }
+
+    const fn finish128(&self) -> u128 {
+        let mut v = self.last_block();
+        v[2] ^= 0xee;
+        rounds(&mut v, D);
+        let h1 = v[0] ^ v[1] ^ v[2] ^ v[3];
+        v[1] ^= 0xdd;
+        rounds(&mut v, D);
+        let h2 = v[0] ^ v[1] ^ v[2] ^ v[3];
+        (h2 as u128) << 64 | h1 as u128
+    }
+
+    /// State after the final block: buffered bytes and the length modulo 256.
+    #[inline(always)]
+    const fn last_block(&self) -> [u64; 4] {
+        let mask = (1u64 << (8 * (self.buffered % 8))) - 1;
+        let last = (self.length as u64) << 56 | (u64::from_le_bytes(self.buffer) & mask);
+        let mut v = self.v;
+        compress(&mut v, last, C);
+        v
+    }
+}
+
+impl<const C: usize, const D: usize> Hasher for SipHasher<C, D> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.finish64()
+    }
+}
+
+/// [`BuildHasher`] creating [`SipHasher`]s with a fixed key, to back
+/// `HashMap`s with the same function as compile-time tables.
+///
+/// There is no `Default`: a key known in advance is the point of this type,
+/// and a made-up default one would silently give up SipHash's protection
+/// against chosen keys. Build maps with `HashMap::with_hasher` instead.
+///
+/// # Examples
+/// ```
+/// use core::hash::BuildHasher;
+/// use cthash::{SipBuildHasher13, siphash13};
+///
+/// const KEY: [u8; 16] = [7; 16];
+/// let build = SipBuildHasher13::new(&KEY);
+/// assert_eq!(build.hash_one(42u64), siphash13(&KEY, &42u64.to_ne_bytes()));
+///
+/// // the builder can be a constant too
+/// const BUILD: SipBuildHasher13 = SipBuildHasher13::new(&KEY);
+/// let mut map = std::collections::HashMap::with_hasher(BUILD);
+/// map.insert("key", 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SipBuildHasher<const C: usize, const D: usize> {
+    key: [u8; 16],
+}
+
+/// [`BuildHasher`] for SipHash-2-4.
+pub type SipBuildHasher24 = SipBuildHasher<2, 4>;
+
+/// [`BuildHasher`] for SipHash-1-3.
+pub type SipBuildHasher13 = SipBuildHasher<1, 3>;
+
+impl<const C: usize, const D: usize> SipBuildHasher<C, D> {
+    /// Create a builder for hashers with a 128-bit key, usable in `const`
+    /// and `static` items.
+    pub const fn new(key: &[u8; 16]) -> Self {
+        Self { key: *key }
+    }
+}
+
+impl<const C: usize, const D: usize> BuildHasher for SipBuildHasher<C, D> {
+    type Hasher = SipHasher<C, D>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        SipHasher::new(&self.key)
+    }
+}
+
+#[inline(always)]
+const fn compress(v: &mut [u64; 4], m: u64, count: usize) {
+    v[3] ^= m;
+    rounds(v, count);
+    v[0] ^= m;
+}
+
+#[inline(always)]
+const fn rounds(v: &mut [u64; 4], count: usize) {
+    let mut i = 0;
+    while i < count {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+        i += 1;
+    }
+}
+
+#[inline(always)]
+const fn read_u64(bytes: &[u8]) -> u64 {
+    match bytes.first_chunk::<8>() {
+        Some(chunk) => u64::from_le_bytes(*chunk),
+        None => 0,
+    }
+}
@@ -0,0 +1,171 @@
+use crate::threefish::{encrypt, from_bytes, to_bytes};
+
+/// Compute Skein-256 digest of `N` bytes.
+/// # Examples
+/// ```
+/// use cthash::skein256;
+/// const H: [u8; 32] = skein256(b"data");
+/// ```
+pub const fn skein256<const N: usize>(input: &[u8]) -> [u8; N] {
+    Skein256::hash(input)
+}
+
+/// Compute Skein-512 digest of `N` bytes.
+/// # Examples
+/// ```
+/// use cthash::skein512;
+/// const H: [u8; 64] = skein512(b"data");
+/// const H_256: [u8; 32] = skein512(b"data");
+/// ```
+pub const fn skein512<const N: usize>(input: &[u8]) -> [u8; N] {
+    Skein512::hash(input)
+}
+
+/// Compute Skein-1024 digest of `N` bytes.
+/// # Examples
+/// ```
+/// use cthash::skein1024;
+/// const H: [u8; 128] = skein1024(b"data");
+/// ```
+pub const fn skein1024<const N: usize>(input: &[u8]) -> [u8; N] {
+    Skein1024::hash(input)
+}
+
+/// Skein with an internal state of `WORDS` 64-bit words, see [`Skein256`],
+/// [`Skein512`] and [`Skein1024`].
+///
+/// Digests can have any non-zero length, longer outputs are produced by the
+/// output transform in counter mode.
+/// # Examples
+/// ```
+/// use cthash::{Skein512, skein512};
+///
+/// const H: [u8; 64] = Skein512::hash(b"data");
+/// assert_eq!(H, skein512::<64>(b"data"));
+///
+/// // MAC
+/// const MAC: [u8; 32] = Skein512::hash_keyed(b"data", b"secret");
+///
+/// // key and personalization string
+/// const P: [u8; 32] = Skein512::hash_with(b"data", b"secret", b"20261019 me@example.org app/v1");
+/// ```
+///
+/// Only the three standard state sizes are available:
+///
+/// ```compile_fail
+/// const H: [u8; 32] = cthash::Skein::<6>::hash(b"data");
+/// ```
+pub struct Skein<const WORDS: usize>;
+
+/// Skein-256, 256-bit internal state.
+pub type Skein256 = Skein<4>;
+
+/// Skein-512, 512-bit internal state.
+pub type Skein512 = Skein<8>;
+
+/// Skein-1024, 1024-bit internal state.
+pub type Skein1024 = Skein<16>;
+
+impl<const WORDS: usize> Skein<WORDS> {
+    /// Compute Skein digest of `N` bytes.
+    pub const fn hash<const N: usize>(input: &[u8]) -> [u8; N] {
+        Self::hash_with(input, &[], &[])
+    }
+
+    /// Compute Skein-MAC of `N` bytes with a key of any length.
+    pub const fn hash_keyed<const N: usize>(input: &[u8], key: &[u8]) -> [u8; N] {
+        Self::hash_with(input, key, &[])
+    }
+
+    /// Compute Skein digest of `N` bytes with optional (empty) key and
+    /// personalization string.
+    pub const fn hash_with<const N: usize>(input: &[u8], key: &[u8], personalization: &[u8]) -> [u8; N] {
+        const {
+            assert!(WORDS == 4 || WORDS == 8 || WORDS == 16, "Skein state must be 4, 8 or 16 words");
+            assert!(N > 0, "digest must not be empty");
+        }
+
+        let mut chain = if key.is_empty() {
+            // the unkeyed configuration only depends on the digest size
+            const { config::<WORDS, N>(&[0; WORDS]) }
+        } else {
+            config::<WORDS, N>(&ubi(&[0; WORDS], key, TYPE_KEY))
+        };
+        if !personalization.is_empty() {
+            chain = ubi(&chain, personalization, TYPE_PRS);
+        }
+        chain = ubi(&chain, input, TYPE_MSG);
+
+        output(&chain)
+    }
+}
+
+/// Chaining value after processing the configuration block.
+const fn config<const W: usize, const N: usize>(chain: &[u64; W]) -> [u64; W] {
+    let mut block = [0u8; 32];
+    let (words, _) = block.as_chunks_mut::<8>();
+    // schema identifier "SHA3", version 1
+    words[0] = 0x0000_0001_3341_4853u64.to_le_bytes();
+    words[1] = (8 * N as u64).to_le_bytes();
+    ubi(chain, &block, TYPE_CFG)
+}
+
+/// Output transform, the chaining value encrypts a counter for each block of output.
+const fn output<const W: usize, const N: usize>(chain: &[u64; W]) -> [u8; N] {
+    let mut out = [0u8; N];
+    let mut rest: &mut [u8] = &mut out;
+    let mut counter = 0u64;
+    while !rest.is_empty() {
+        let block = to_bytes::<W, 128>(&ubi(chain, &counter.to_le_bytes(), TYPE_OUT));
+        let len = if rest.len() < 8 * W { rest.len() } else { 8 * W };
+        let (head, tail) = rest.split_at_mut(len);
+        head.copy_from_slice(block.split_at(len).0);
+        rest = tail;
+        counter = counter.wrapping_add(1);
+    }
+    out
+}
+
+/// Unique Block Iteration: chain Threefish over a message of any type.
+#[inline]
+const fn ubi<const W: usize>(chain: &[u64; W], message: &[u8], ty: u64) -> [u64; W] {
+    let mut h = *chain;
+    let mut rest = message;
+    let mut position = 0u64;
+    let mut first = true;
+    loop {
+        let (chunk, tail) = match rest.split_at_checked(8 * W) {
+            Some((chunk, tail)) => (chunk, tail),
+            None => (rest, &[] as &[u8]),
+        };
+        rest = tail;
+        position = position.wrapping_add(chunk.len() as u64);
+
+        let mut bytes = [0u8; 128];
+        if let Some((head, _)) = bytes.split_at_mut_checked(chunk.len()) {
+            head.copy_from_slice(chunk);
+        }
+        let block = from_bytes::<W, 128>(&bytes);
+
+        let last = rest.is_empty();
+        let tweak = [position, ty << 56 | (first as u64) << 62 | (last as u64) << 63];
+        let e = encrypt(&h, &tweak, &block);
+        let mut i = 0;
+        while i < W {
+            h[i] = e[i] ^ block[i];
+            i += 1;
+        }
+
+        if last {
+            break;
+        }
+        first = false;
+    }
+    h
+}
+
+const TYPE_KEY: u64 = 0;
+const TYPE_CFG: u64 = 4;
+const TYPE_PRS: u64 = 8;
+const TYPE_MSG: u64 = 48;
+const TYPE_OUT: u64 = 63;
@@ -0,0 +1,253 @@
+/// Encrypt one block with Threefish-256.
+/// # Examples
+/// ```
+/// use cthash::hazmat::{threefish256_decrypt, threefish256_encrypt};
+/// const C: [u8; 32] = threefish256_encrypt(&[0; 32], &[0; 16], b"thirty-two bytes of plaintext...");
+/// assert_eq!(&threefish256_decrypt(&[0; 32], &[0; 16], &C), b"thirty-two bytes of plaintext...");
+/// ```
+pub const fn threefish256_encrypt(key: &[u8; 32], tweak: &[u8; 16], block: &[u8; 32]) -> [u8; 32] {
+    to_bytes::<4, 32>(&encrypt(&from_bytes(key), &from_bytes(tweak), &from_bytes(block)))
+}
+
+/// Decrypt one block with Threefish-256.
+pub const fn threefish256_decrypt(key: &[u8; 32], tweak: &[u8; 16], block: &[u8; 32]) -> [u8; 32] {
+    to_bytes::<4, 32>(&decrypt(&from_bytes(key), &from_bytes(tweak), &from_bytes(block)))
+}
+
+/// Encrypt one block with Threefish-512.
+/// # Examples
+/// ```
+/// use cthash::hazmat::threefish512_encrypt;
+/// const C: [u8; 64] = threefish512_encrypt(&[0; 64], &[0; 16], &[0; 64]);
+/// assert_eq!(C[..4], [0xb1, 0xa2, 0xbb, 0xc6]);
+/// ```
+pub const fn threefish512_encrypt(key: &[u8; 64], tweak: &[u8; 16], block: &[u8; 64]) -> [u8; 64] {
+    to_bytes::<8, 64>(&encrypt(&from_bytes(key), &from_bytes(tweak), &from_bytes(block)))
+}
+
+/// Decrypt one block with Threefish-512.
+pub const fn threefish512_decrypt(key: &[u8; 64], tweak: &[u8; 16], block: &[u8; 64]) -> [u8; 64] {
+    to_bytes::<8, 64>(&decrypt(&from_bytes(key), &from_bytes(tweak), &from_bytes(block)))
+}
+
+/// Encrypt one block with Threefish-1024.
+pub const fn threefish1024_encrypt(key: &[u8; 128], tweak: &[u8; 16], block: &[u8; 128]) -> [u8; 128] {
+    to_bytes::<16, 128>(&encrypt(&from_bytes(key), &from_bytes(tweak), &from_bytes(block)))
+}
+
+/// Decrypt one block with Threefish-1024.
+pub const fn threefish1024_decrypt(key: &[u8; 128], tweak: &[u8; 16], block: &[u8; 128]) -> [u8; 128] {
+    to_bytes::<16, 128>(&decrypt(&from_bytes(key), &from_bytes(tweak), &from_bytes(block)))
+}
+
+/// Threefish encryption of a block of `W` words (4, 8 or 16).
+#[inline]
+pub(crate) const fn encrypt<const W: usize>(key: &[u64; W], tweak: &[u64; 2], block: &[u64; W]) -> [u64; W] {
+    let schedule = KeySchedule::new(key, tweak);
+    let rounds = rounds::<W>();
+
+    let mut v = *block;
+    let mut d = 0;
+    while d < rounds {
+        if d.is_multiple_of(4) {
+            schedule.add(&mut v, d / 4);
+        }
+
+        let mut f = [0u64; W];
+        let mut j = 0;
+        while j < W / 2 {
+            let (x0, x1) = (v[2 * j], v[2 * j + 1]);
+            let y0 = x0.wrapping_add(x1);
+            f[2 * j] = y0;
+            f[2 * j + 1] = x1.rotate_left(rotation::<W>(d, j)) ^ y0;
+            j += 1;
+        }
+
+        let mut i = 0;
+        while i < W {
+            v[i] = f[permutation::<W>(i)];
+            i += 1;
+        }
+        d += 1;
+    }
+    schedule.add(&mut v, rounds / 4);
+
+    v
+}
+
+/// Threefish decryption of a block of `W` words (4, 8 or 16).
+pub(crate) const fn decrypt<const W: usize>(key: &[u64; W], tweak: &[u64; 2], block: &[u64; W]) -> [u64; W] {
+    let schedule = KeySchedule::new(key, tweak);
+    let rounds = rounds::<W>();
+
+    let mut v = *block;
+    schedule.sub(&mut v, rounds / 4);
+
+    let mut d = rounds;
+    while d > 0 {
+        d -= 1;
+
+        let mut f = [0u64; W];
+        let mut i = 0;
+        while i < W {
+            f[permutation::<W>(i)] = v[i];
+            i += 1;
+        }
+
+        let mut j = 0;
+        while j < W / 2 {
+            let (y0, y1) = (f[2 * j], f[2 * j + 1]);
+            let x1 = (y1 ^ y0).rotate_right(rotation::<W>(d, j));
+            v[2 * j] = y0.wrapping_sub(x1);
+            v[2 * j + 1] = x1;
+            j += 1;
+        }
+
+        if d.is_multiple_of(4) {
+            schedule.sub(&mut v, d / 4);
+        }
+    }
+
+    v
+}
+
+/// Extended key and tweak, subkeys are derived on the fly.
+struct KeySchedule {
+    // key words followed by their parity word, at most 16 + 1
+    key: [u64; 17],
+    tweak: [u64; 3],
+}
+
+impl KeySchedule {
+    #[inline(always)]
+    const fn new<const W: usize>(key: &[u64; W], tweak: &[u64; 2]) -> Self {
+        let mut extended = [0u64; 17];
+        let mut parity = C240;
+        let mut i = 0;
+        while i < W && i < 16 {
+            extended[i] = key[i];
+            parity ^= key[i];
+            i += 1;
+        }
+        extended[W % 17] = parity;
+
+        Self {
+            key: extended,
+            tweak: [tweak[0], tweak[1], tweak[0] ^ tweak[1]],
+        }
+    }
+
+    /// Word `i` of subkey `s`.
+    #[inline(always)]
+    const fn word<const W: usize>(&self, s: usize, i: usize) -> u64 {
+        let k = self.key[(s + i) % (W + 1) % 17];
+        if i == W - 3 {
+            k.wrapping_add(self.tweak[s % 3])
+        } else if i == W - 2 {
+            k.wrapping_add(self.tweak[(s + 1) % 3])
+        } else if i == W - 1 {
+            k.wrapping_add(s as u64)
+        } else {
+            k
+        }
+    }
+
+    #[inline(always)]
+    const fn add<const W: usize>(&self, v: &mut [u64; W], s: usize) {
+        let mut i = 0;
+        while i < W {
+            v[i] = v[i].wrapping_add(self.word::<W>(s, i));
+            i += 1;
+        }
+    }
+
+    #[inline(always)]
+    const fn sub<const W: usize>(&self, v: &mut [u64; W], s: usize) {
+        let mut i = 0;
+        while i < W {
+            v[i] = v[i].wrapping_sub(self.word::<W>(s, i));
+            i += 1;
+        }
+    }
+}
+
+#[inline(always)]
+const fn rounds<const W: usize>() -> usize {
+    const { assert!(W == 4 || W == 8 || W == 16, "Threefish block must be 4, 8 or 16 words") }
+
+    if W == 16 { 80 } else { 72 }
+}
+
+/// Rotation constant of round `d` for word pair `j`.
+#[inline(always)]
+const fn rotation<const W: usize>(d: usize, j: usize) -> u32 {
+    match W {
+        4 => R_256[d % 8][j % 2],
+        8 => R_512[d % 8][j % 4],
+        _ => R_1024[d % 8][j % 8],
+    }
+}
+
+/// Source word of word `i` after the round permutation.
+#[inline(always)]
+const fn permutation<const W: usize>(i: usize) -> usize {
+    match W {
+        4 => PI_256[i % 4],
+        8 => PI_512[i % 8],
+        _ => PI_1024[i % 16],
+    }
+}
+
+#[inline(always)]
+pub(crate) const fn from_bytes<const W: usize, const B: usize>(bytes: &[u8; B]) -> [u64; W] {
+    let (chunks, _) = bytes.as_chunks::<8>();
+    let mut out = [0u64; W];
+    let mut i = 0;
+    while i < W && i < chunks.len() {
+        out[i] = u64::from_le_bytes(chunks[i]);
+        i += 1;
+    }
+    out
+}
+
+#[inline(always)]
+pub(crate) const fn to_bytes<const W: usize, const B: usize>(words: &[u64; W]) -> [u8; B] {
+    let mut out = [0u8; B];
+    let (chunks, _) = out.as_chunks_mut::<8>();
+    let mut i = 0;
+    while i < W && i < chunks.len() {
+        chunks[i] = words[i].to_le_bytes();
+        i += 1;
+    }
+    out
+}
+
+const C240: u64 = 0x1bd11bdaa9fc1a22;
+
+const R_256: [[u32; 2]; 8] = [[14, 16], [52, 57], [23, 40], [5, 37], [25, 33], [46, 12], [58, 22], [32, 32]];
+
+const R_512: [[u32; 4]; 8] = [
+    [46, 36, 19, 37],
+    [33, 27, 14, 42],
+    [17, 49, 36, 39],
+    [44, 9, 54, 56],
+    [39, 30, 34, 24],
+    [13, 50, 10, 17],
+    [25, 29, 39, 43],
+    [8, 35, 56, 22],
+];
+
+const R_1024: [[u32; 8]; 8] = [
+    [24, 13, 8, 47, 8, 17, 22, 37],
+    [38, 19, 10, 55, 49, 18, 23, 52],
+    [33, 4, 51, 13, 34, 41, 59, 17],
+    [5, 20, 48, 41, 47, 28, 16, 25],
+    [41, 9, 37, 31, 12, 47, 44, 30],
+    [16, 34, 56, 51, 4, 53, 42, 41],
+    [31, 44, 47, 46, 19, 42, 44, 25],
+    [9, 48, 35, 52, 23, 31, 37, 20],
+];
+
+const PI_256: [usize; 4] = [0, 3, 2, 1];
+const PI_512: [usize; 8] = [2, 1, 4, 7, 6, 5, 0, 3];
+const PI_1024: [usize; 16] = [0, 9, 2, 13, 6, 11, 4, 15, 10, 7, 12, 3, 14, 5, 8, 1];
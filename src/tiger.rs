@@ -0,0 +1,400 @@
+use crate::block_api::{BlockBuffer, eager_split_pad, fixed_split_pad};
+
+/// Compute Tiger digest.
+/// # Examples
+/// ```
+/// use cthash::tiger;
+/// const H: [u8; 24] = tiger(b"data");
+/// ```
+pub const fn tiger(input: &[u8]) -> [u8; 24] {
+    let mut state = IV;
+
+    tiger_pad_and_run(input, TIGER_DELIM, (input.len() as u64).wrapping_mul(8), &mut state);
+
+    output(&state)
+}
+
+/// Compute Tiger digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 24 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::tiger_trunc;
+/// const H: [u8; 16] = tiger_trunc(b"data");
+/// assert_eq!(H, cthash::tiger(b"data")[..16]);
+/// ```
+pub const fn tiger_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 24, "truncated length must not exceed digest size") }
+
+    let mut state = IV;
+
+    tiger_pad_and_run(input, TIGER_DELIM, (input.len() as u64).wrapping_mul(8), &mut state);
+
+    output(&state)
+}
+
+/// Compute Tiger digest of a message with length known at compile time.
+///
+/// Intended for nested hashing: the digest of another hash function is fed
+/// into the compression function with its padding laid out at compile time.
+/// # Examples
+/// ```
+/// use cthash::{tiger, tiger_fixed};
+/// const H: [u8; 24] = tiger_fixed(&tiger(b"data"));
+/// ```
+pub const fn tiger_fixed<const N: usize>(input: &[u8; N]) -> [u8; 24] {
+    let mut state = IV;
+
+    tiger_fixed_pad_and_run(input, TIGER_DELIM, &mut state);
+
+    output(&state)
+}
+
+/// Compute Tiger2 digest, which differs from Tiger only in the padding byte.
+/// # Examples
+/// ```
+/// use cthash::tiger2;
+/// const H: [u8; 24] = tiger2(b"data");
+/// ```
+pub const fn tiger2(input: &[u8]) -> [u8; 24] {
+    let mut state = IV;
+
+    tiger_pad_and_run(input, TIGER2_DELIM, (input.len() as u64).wrapping_mul(8), &mut state);
+
+    output(&state)
+}
+
+/// Compute Tiger2 digest truncated to its first `N` bytes.
+///
+/// `N` must not exceed the digest size of 24 bytes, which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::tiger2_trunc;
+/// const H: [u8; 16] = tiger2_trunc(b"data");
+/// assert_eq!(H, cthash::tiger2(b"data")[..16]);
+/// ```
+pub const fn tiger2_trunc<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 24, "truncated length must not exceed digest size") }
+
+    let mut state = IV;
+
+    tiger_pad_and_run(input, TIGER2_DELIM, (input.len() as u64).wrapping_mul(8), &mut state);
+
+    output(&state)
+}
+
+/// Compute Tiger2 digest of a message with length known at compile time.
+///
+/// Intended for nested hashing: the digest of another hash function is fed
+/// into the compression function with its padding laid out at compile time.
+/// # Examples
+/// ```
+/// use cthash::{tiger2, tiger2_fixed};
+/// const H: [u8; 24] = tiger2_fixed(&tiger2(b"data"));
+/// ```
+pub const fn tiger2_fixed<const N: usize>(input: &[u8; N]) -> [u8; 24] {
+    let mut state = IV;
+
+    tiger_fixed_pad_and_run(input, TIGER2_DELIM, &mut state);
+
+    output(&state)
+}
+
+/// Compute Tiger Tree Hash (TTH) root as used by Direct Connect and Gnutella.
+///
+/// The input is split into 1024-byte leaves hashed as `tiger(0x00 || leaf)`,
+/// pairs of nodes are combined as `tiger(0x01 || left || right)` and an odd
+/// node is promoted to the next level unchanged. The root is usually shown
+/// in base32.
+/// # Examples
+/// ```
+/// use cthash::tiger_tree;
+/// const H: [u8; 24] = tiger_tree(b"");
+/// assert_eq!(H[..4], [0x5d, 0x9e, 0xd0, 0x0a]); // LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ
+/// ```
+#[inline]
+pub const fn tiger_tree(input: &[u8]) -> [u8; 24] {
+    // pending subtree roots and their heights, a binary counter over leaves:
+    // heights are distinct and there are fewer than 2^64 leaves, so 64 entries
+    // are never exceeded and the `depth` checks below only make that explicit
+    let mut stack = [[0u8; 24]; 64];
+    let mut heights = [0u8; 64];
+    let mut depth = 0;
+
+    let (leaves, rem) = input.as_chunks::<1024>();
+    let count = leaves.len() + (!rem.is_empty() || leaves.is_empty()) as usize;
+
+    let mut i = 0;
+    while i < count {
+        let leaf: &[u8] = if i < leaves.len() { &leaves[i] } else { rem };
+        let mut node = tth_leaf(leaf);
+        let mut height = 0;
+        while depth > 0 && depth <= 64 && heights[depth - 1] == height {
+            depth -= 1;
+            node = tth_node(&stack[depth], &node);
+            height += 1;
+        }
+        if depth < 64 {
+            stack[depth] = node;
+            heights[depth] = height;
+            depth += 1;
+        }
+        i += 1;
+    }
+
+    // fold the remaining subtrees from the right, smaller ones were promoted
+    let mut root = if depth > 0 && depth <= 64 { stack[depth - 1] } else { [0u8; 24] };
+    while depth > 1 && depth <= 64 {
+        depth -= 1;
+        root = tth_node(&stack[depth - 1], &root);
+    }
+    root
+}
+
+#[inline(always)]
+const fn tth_leaf(leaf: &[u8]) -> [u8; 24] {
+    let mut buf = [0u8; 1025];
+    let (data, _) = leaf.split_at(if leaf.len() < 1024 { leaf.len() } else { 1024 });
+    buf.split_at_mut(1).1.split_at_mut(data.len()).0.copy_from_slice(data);
+    let (msg, _) = buf.split_at(data.len() + 1);
+
+    // the message is at most 1025 bytes, so its bit length can't overflow
+    let mut state = IV;
+    tiger_pad_and_run(msg, TIGER_DELIM, (msg.len() as u64).wrapping_mul(8), &mut state);
+    output(&state)
+}
+
+#[inline(always)]
+const fn tth_node(left: &[u8; 24], right: &[u8; 24]) -> [u8; 24] {
+    let mut buf = [0u8; 49];
+    buf[0] = 0x01;
+    let (l, r) = buf.split_at_mut(25);
+    l.split_at_mut(1).1.copy_from_slice(left);
+    r.copy_from_slice(right);
+    tiger_fixed(&buf)
+}
+
+const TIGER_DELIM: u8 = 0x01;
+const TIGER2_DELIM: u8 = 0x80;
+
+const IV: [u64; 3] = [0x0123456789abcdef, 0xfedcba9876543210, 0xf096a5b4c3b2e187];
+
+/// Incremental state of Tiger and Tiger2.
+#[derive(Clone, Debug)]
+pub(crate) struct TigerCore {
+    state: [u64; 3],
+    buffer: BlockBuffer<64>,
+    delim: u8,
+}
+
+impl TigerCore {
+    pub(crate) const TIGER: Self = Self::new(TIGER_DELIM);
+    pub(crate) const TIGER2: Self = Self::new(TIGER2_DELIM);
+
+    const fn new(delim: u8) -> Self {
+        Self {
+            state: IV,
+            buffer: BlockBuffer::new(),
+            delim,
+        }
+    }
+
+    pub(crate) const fn update(&mut self, data: &[u8]) {
+        let (block, data) = self.buffer.fill(data);
+        if let Some(block) = block {
+            compress(&mut self.state, block, &S);
+        }
+
+        let (blocks, rem) = data.as_chunks();
+        let mut i = 0;
+        while i < blocks.len() {
+            compress(&mut self.state, &blocks[i], &S);
+            i += 1;
+        }
+        self.buffer.keep(rem);
+    }
+
+    pub(crate) const fn finalize<const OUT: usize>(&self) -> [u8; OUT] {
+        let mut state = self.state;
+        tiger_pad_and_run(
+            self.buffer.pending(),
+            self.delim,
+            (self.buffer.len() as u64).wrapping_mul(8),
+            &mut state,
+        );
+        output(&state)
+    }
+}
+
+/// Serialize the first `OUT` bytes of the state.
+#[inline(always)]
+const fn output<const OUT: usize>(state: &[u64; 3]) -> [u8; OUT] {
+    let mut out = [0u8; OUT];
+    let (out_chunks, out_rem) = out.as_chunks_mut();
+    let mut i = 0;
+    while i < out_chunks.len() {
+        out_chunks[i] = state[i].to_le_bytes();
+        i += 1;
+    }
+    if !out_rem.is_empty() {
+        let last_word = state[i].to_le_bytes();
+        out_rem.copy_from_slice(last_word.split_at(out_rem.len()).0);
+    }
+    out
+}
+
+#[inline(always)]
+const fn tiger_pad_and_run(input: &[u8], delim: u8, bit_len: u64, state: &mut [u64; 3]) {
+    let mut b0 = [0; _];
+    let mut b1 = [0; _];
+    let bit_len = bit_len.to_le_bytes();
+    let (blocks, pad_two_blocks) = eager_split_pad(input, &bit_len, delim, &mut b0, &mut b1);
+
+    let mut i = 0;
+    while i < blocks.len() {
+        compress(state, &blocks[i], &S);
+        i += 1;
+    }
+
+    if pad_two_blocks {
+        compress(state, &b0, &S);
+        compress(state, &b1, &S);
+    } else {
+        compress(state, &b0, &S);
+    }
+}
+
+#[inline(always)]
+const fn tiger_fixed_pad_and_run<const N: usize>(input: &[u8; N], delim: u8, state: &mut [u64; 3]) {
+    let mut b0 = [0; _];
+    let mut b1 = [0; _];
+    let bit_len = const { ((N as u64) * 8).to_le_bytes() };
+    let (blocks, pad_two_blocks) = fixed_split_pad(input, &bit_len, delim, &mut b0, &mut b1);
+
+    let mut i = 0;
+    while i < blocks.len() {
+        compress(state, &blocks[i], &S);
+        i += 1;
+    }
+
+    if pad_two_blocks {
+        compress(state, &b0, &S);
+        compress(state, &b1, &S);
+    } else {
+        compress(state, &b0, &S);
+    }
+}
+
+/// Compression function, parametrized by S-boxes so it can also generate them.
+const fn compress(state: &mut [u64; 3], block: &[u8; 64], s: &[[u64; 256]; 4]) {
+    #[inline(always)]
+    const fn round(a: &mut u64, b: &mut u64, c: &mut u64, x: u64, mul: u64, s: &[[u64; 256]; 4]) {
+        *c ^= x;
+        let c_bytes = c.to_le_bytes();
+        *a = a.wrapping_sub(s[0][c_bytes[0] as usize] ^ s[1][c_bytes[2] as usize] ^ s[2][c_bytes[4] as usize] ^ s[3][c_bytes[6] as usize]);
+        *b = b.wrapping_add(s[3][c_bytes[1] as usize] ^ s[2][c_bytes[3] as usize] ^ s[1][c_bytes[5] as usize] ^ s[0][c_bytes[7] as usize]);
+        *b = b.wrapping_mul(mul);
+    }
+
+    #[inline(always)]
+    const fn pass(a: &mut u64, b: &mut u64, c: &mut u64, x: &[u64; 8], mul: u64, s: &[[u64; 256]; 4]) {
+        round(a, b, c, x[0], mul, s);
+        round(b, c, a, x[1], mul, s);
+        round(c, a, b, x[2], mul, s);
+        round(a, b, c, x[3], mul, s);
+        round(b, c, a, x[4], mul, s);
+        round(c, a, b, x[5], mul, s);
+        round(a, b, c, x[6], mul, s);
+        round(b, c, a, x[7], mul, s);
+    }
+
+    #[inline(always)]
+    const fn key_schedule(x: &mut [u64; 8]) {
+        x[0] = x[0].wrapping_sub(x[7] ^ 0xa5a5a5a5a5a5a5a5);
+        x[1] ^= x[0];
+        x[2] = x[2].wrapping_add(x[1]);
+        x[3] = x[3].wrapping_sub(x[2] ^ (!x[1]) << 19);
+        x[4] ^= x[3];
+        x[5] = x[5].wrapping_add(x[4]);
+        x[6] = x[6].wrapping_sub(x[5] ^ (!x[4]) >> 23);
+        x[7] ^= x[6];
+        x[0] = x[0].wrapping_add(x[7]);
+        x[1] = x[1].wrapping_sub(x[0] ^ (!x[7]) << 19);
+        x[2] ^= x[1];
+        x[3] = x[3].wrapping_add(x[2]);
+        x[4] = x[4].wrapping_sub(x[3] ^ (!x[2]) >> 23);
+        x[5] ^= x[4];
+        x[6] = x[6].wrapping_add(x[5]);
+        x[7] = x[7].wrapping_sub(x[6] ^ 0x0123456789abcdef);
+    }
+
+    let (chunks, _) = block.as_chunks::<8>();
+    let mut x = [0u64; 8];
+    let mut i = 0;
+    while i < 8 {
+        x[i] = u64::from_le_bytes(chunks[i]);
+        i += 1;
+    }
+
+    let [mut a, mut b, mut c] = *state;
+
+    pass(&mut a, &mut b, &mut c, &x, 5, s);
+    key_schedule(&mut x);
+    pass(&mut c, &mut a, &mut b, &x, 7, s);
+    key_schedule(&mut x);
+    pass(&mut b, &mut c, &mut a, &x, 9, s);
+
+    // feedforward
+    *state = [a ^ state[0], b.wrapping_sub(state[1]), c.wrapping_add(state[2])];
+}
+
+/// S-boxes generated at compile time as in `sboxes.c` of the Tiger
+/// reference: identity tables shuffled bytewise by five passes, driven by
+/// compressing a fixed string with the tables built so far.
+const S: [[u64; 256]; 4] = {
+    const SEED: &[u8; 64] = b"Tiger - A Fast New Hash Function, by Ross Anderson and Eli Biham";
+
+    let mut s = [[0u64; 256]; 4];
+    let mut i = 0;
+    while i < 256 {
+        let mut sb = 0;
+        while sb < 4 {
+            s[sb][i] = (i as u64) * 0x0101010101010101;
+            sb += 1;
+        }
+        i += 1;
+    }
+
+    let mut state = IV;
+    let mut abc = 2;
+    let mut pass = 0;
+    while pass < 5 {
+        let mut i = 0;
+        while i < 256 {
+            let mut sb = 0;
+            while sb < 4 {
+                abc += 1;
+                if abc == 3 {
+                    abc = 0;
+                    compress(&mut state, SEED, &s);
+                }
+
+                // swap every byte column of entry `i` with the one selected by the state
+                let select = state[abc].to_le_bytes();
+                let mut col = 0;
+                while col < 8 {
+                    let j = select[col] as usize;
+                    let mask = 0xffu64 << (8 * col);
+                    let (bi, bj) = (s[sb][i] & mask, s[sb][j] & mask);
+                    s[sb][i] = s[sb][i] & !mask | bj;
+                    s[sb][j] = s[sb][j] & !mask | bi;
+                    col += 1;
+                }
+                sb += 1;
+            }
+            i += 1;
+        }
+        pass += 1;
+    }
+    s
+};
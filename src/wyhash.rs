@@ -0,0 +1,103 @@
+/// Default secret `_wyp` of wyhash final4, also the first words of the rapidhash secret.
+pub(crate) const SECRET: [u64; 4] = [0x2d358dccaa6c78a5, 0x8bb84b93962eacc9, 0x4b33a62ed433d4a3, 0x4d5a2da51de1aa47];
+
+/// Compute 64-bit wyhash (final4) of `data` with the default secret.
+/// # Examples
+/// ```
+/// use cthash::wyhash;
+/// const H: u64 = wyhash(0, b"data");
+/// assert_eq!(wyhash(1, b"a"), 0xc5bac3db178713c4);
+/// ```
+#[inline]
+pub const fn wyhash(seed: u64, data: &[u8]) -> u64 {
+    let len = data.len();
+    let mut seed = seed ^ mix(seed ^ SECRET[0], SECRET[1]);
+    let a;
+    let b;
+
+    if len <= 16 {
+        if len >= 4 {
+            let quarter = (len >> 3) << 2;
+            a = (read_u32(data, 0) << 32) | read_u32(data, quarter);
+            let end = len.saturating_sub(4);
+            b = (read_u32(data, end) << 32) | read_u32(data, end.saturating_sub(quarter));
+        } else {
+            let [first, middle, last] = read_small(data);
+            a = (first as u64) << 16 | (middle as u64) << 8 | last as u64;
+            b = 0;
+        }
+    } else {
+        let mut rest = data;
+        if len >= 48 {
+            let mut see1 = seed;
+            let mut see2 = seed;
+            while let Some((block, tail)) = rest.split_first_chunk::<48>() {
+                seed = mix(read_u64(block, 0) ^ SECRET[1], read_u64(block, 8) ^ seed);
+                see1 = mix(read_u64(block, 16) ^ SECRET[2], read_u64(block, 24) ^ see1);
+                see2 = mix(read_u64(block, 32) ^ SECRET[3], read_u64(block, 40) ^ see2);
+                rest = tail;
+            }
+            seed ^= see1 ^ see2;
+        }
+        while let Some((block, tail)) = rest.split_first_chunk::<16>()
+            && !tail.is_empty()
+        {
+            seed = mix(read_u64(block, 0) ^ SECRET[1], read_u64(block, 8) ^ seed);
+            rest = tail;
+        }
+        // the last 16 bytes, overlapping the ones already absorbed
+        a = read_u64(data, len - 16);
+        b = read_u64(data, len - 8);
+    }
+
+    let (a, b) = mum(a ^ SECRET[1], b ^ seed);
+    mix(a ^ SECRET[0] ^ len as u64, b ^ SECRET[1])
+}
+
+/// Full 128-bit product of `a` and `b` as its low and high word.
+#[inline(always)]
+pub(crate) const fn mum(a: u64, b: u64) -> (u64, u64) {
+    let r = a as u128 * b as u128;
+    (r as u64, (r >> 64) as u64)
+}
+
+/// Both words of the 128-bit product folded together.
+#[inline(always)]
+pub(crate) const fn mix(a: u64, b: u64) -> u64 {
+    let (low, high) = mum(a, b);
+    low ^ high
+}
+
+/// The first, middle and last byte of inputs shorter than 4 bytes, all zero
+/// when empty.
+#[inline(always)]
+pub(crate) const fn read_small(bytes: &[u8]) -> [u8; 3] {
+    match *bytes {
+        [a] => [a, a, a],
+        [a, b] => [a, b, b],
+        [a, b, c] => [a, b, c],
+        _ => [0; 3],
+    }
+}
+
+#[inline(always)]
+pub(crate) const fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    match bytes.split_at_checked(offset) {
+        Some((_, tail)) => match tail.first_chunk::<8>() {
+            Some(chunk) => u64::from_le_bytes(*chunk),
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+#[inline(always)]
+pub(crate) const fn read_u32(bytes: &[u8], offset: usize) -> u64 {
+    match bytes.split_at_checked(offset) {
+        Some((_, tail)) => match tail.first_chunk::<4>() {
+            Some(chunk) => u32::from_le_bytes(*chunk) as u64,
+            None => 0,
+        },
+        None => 0,
+    }
+}
@@ -0,0 +1,586 @@
+/// Compute XXH32 hash with `seed`.
+///
+/// The canonical byte representation of the hash is `xxh32(..).to_be_bytes()`.
+/// # Examples
+/// ```
+/// use cthash::xxh32;
+/// const H: u32 = xxh32(b"data", 0);
+/// ```
+#[inline]
+pub const fn xxh32(input: &[u8], seed: u32) -> u32 {
+    let (stripes, rem) = input.as_chunks::<16>();
+
+    let mut h = if stripes.is_empty() {
+        seed.wrapping_add(P32_5)
+    } else {
+        let mut v = [seed.wrapping_add(P32_1).wrapping_add(P32_2), seed.wrapping_add(P32_2), seed, seed.wrapping_sub(P32_1)];
+        let mut i = 0;
+        while i < stripes.len() {
+            let (lanes, _) = stripes[i].as_chunks::<4>();
+            let mut j = 0;
+            while j < 4 {
+                v[j] = xxh32_round(v[j], u32::from_le_bytes(lanes[j]));
+                j += 1;
+            }
+            i += 1;
+        }
+        v[0].rotate_left(1)
+            .wrapping_add(v[1].rotate_left(7))
+            .wrapping_add(v[2].rotate_left(12))
+            .wrapping_add(v[3].rotate_left(18))
+    };
+    h = h.wrapping_add(input.len() as u32);
+
+    let (lanes, rem) = rem.as_chunks::<4>();
+    let mut i = 0;
+    while i < lanes.len() {
+        h = h.wrapping_add(u32::from_le_bytes(lanes[i]).wrapping_mul(P32_3));
+        h = h.rotate_left(17).wrapping_mul(P32_4);
+        i += 1;
+    }
+    let mut i = 0;
+    while i < rem.len() {
+        h = h.wrapping_add((rem[i] as u32).wrapping_mul(P32_5));
+        h = h.rotate_left(11).wrapping_mul(P32_1);
+        i += 1;
+    }
+
+    h ^= h >> 15;
+    h = h.wrapping_mul(P32_2);
+    h ^= h >> 13;
+    h = h.wrapping_mul(P32_3);
+    h ^ h >> 16
+}
+
+/// Compute XXH64 hash with `seed`.
+///
+/// The canonical byte representation of the hash is `xxh64(..).to_be_bytes()`.
+/// # Examples
+/// ```
+/// use cthash::xxh64;
+/// const H: u64 = xxh64(b"data", 0);
+/// ```
+#[inline]
+pub const fn xxh64(input: &[u8], seed: u64) -> u64 {
+    let (stripes, rem) = input.as_chunks::<32>();
+
+    let mut h = if stripes.is_empty() {
+        seed.wrapping_add(P64_5)
+    } else {
+        let mut v = [seed.wrapping_add(P64_1).wrapping_add(P64_2), seed.wrapping_add(P64_2), seed, seed.wrapping_sub(P64_1)];
+        let mut i = 0;
+        while i < stripes.len() {
+            let (lanes, _) = stripes[i].as_chunks::<8>();
+            let mut j = 0;
+            while j < 4 {
+                v[j] = xxh64_round(v[j], u64::from_le_bytes(lanes[j]));
+                j += 1;
+            }
+            i += 1;
+        }
+        let mut h = v[0]
+            .rotate_left(1)
+            .wrapping_add(v[1].rotate_left(7))
+            .wrapping_add(v[2].rotate_left(12))
+            .wrapping_add(v[3].rotate_left(18));
+        let mut j = 0;
+        while j < 4 {
+            h = (h ^ xxh64_round(0, v[j])).wrapping_mul(P64_1).wrapping_add(P64_4);
+            j += 1;
+        }
+        h
+    };
+    h = h.wrapping_add(input.len() as u64);
+
+    let (lanes, rem) = rem.as_chunks::<8>();
+    let mut i = 0;
+    while i < lanes.len() {
+        h ^= xxh64_round(0, u64::from_le_bytes(lanes[i]));
+        h = h.rotate_left(27).wrapping_mul(P64_1).wrapping_add(P64_4);
+        i += 1;
+    }
+    let (lanes, rem) = rem.as_chunks::<4>();
+    if let Some(lane) = lanes.first() {
+        h ^= (u32::from_le_bytes(*lane) as u64).wrapping_mul(P64_1);
+        h = h.rotate_left(23).wrapping_mul(P64_2).wrapping_add(P64_3);
+    }
+    let mut i = 0;
+    while i < rem.len() {
+        h ^= (rem[i] as u64).wrapping_mul(P64_5);
+        h = h.rotate_left(11).wrapping_mul(P64_1);
+        i += 1;
+    }
+
+    xxh64_avalanche(h)
+}
+
+/// Compute 64-bit XXH3 hash with `seed`, seed 0 gives the unseeded XXH3.
+/// # Examples
+/// ```
+/// use cthash::xxh3_64;
+/// const H: u64 = xxh3_64(b"data", 0);
+///
+/// const fn route(key: &[u8]) -> u8 {
+///     match xxh3_64(key, 0) {
+///         h if h == xxh3_64(b"users", 0) => 1,
+///         h if h == xxh3_64(b"orders", 0) => 2,
+///         _ => 0,
+///     }
+/// }
+/// assert_eq!(route(b"orders"), 2);
+/// ```
+#[inline]
+pub const fn xxh3_64(input: &[u8], seed: u64) -> u64 {
+    if input.len() <= MID_SIZE_MAX {
+        xxh3_64_short(input, &DEFAULT_SECRET, seed)
+    } else if seed == 0 {
+        xxh3_64_long(input, &DEFAULT_SECRET)
+    } else {
+        xxh3_64_long(input, &custom_secret(seed))
+    }
+}
+
+/// Compute 64-bit XXH3 hash with a custom secret of at least 136 bytes,
+/// which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::xxh3_64_with_secret;
+/// const SECRET: [u8; 136] = [0x5a; 136];
+/// const H: u64 = xxh3_64_with_secret(b"data", &SECRET);
+/// ```
+pub const fn xxh3_64_with_secret<const S: usize>(input: &[u8], secret: &[u8; S]) -> u64 {
+    const { assert!(S >= SECRET_SIZE_MIN, "secret must be at least 136 bytes") }
+
+    if input.len() <= MID_SIZE_MAX {
+        xxh3_64_short(input, secret, 0)
+    } else {
+        xxh3_64_long(input, secret)
+    }
+}
+
+/// Compute 128-bit XXH3 hash with `seed`, seed 0 gives the unseeded XXH3.
+///
+/// The canonical byte representation of the hash is `xxh3_128(..).to_be_bytes()`.
+/// # Examples
+/// ```
+/// use cthash::xxh3_128;
+/// const H: u128 = xxh3_128(b"data", 0);
+/// ```
+#[inline]
+pub const fn xxh3_128(input: &[u8], seed: u64) -> u128 {
+    if input.len() <= MID_SIZE_MAX {
+        xxh3_128_short(input, &DEFAULT_SECRET, seed)
+    } else if seed == 0 {
+        xxh3_128_long(input, &DEFAULT_SECRET)
+    } else {
+        xxh3_128_long(input, &custom_secret(seed))
+    }
+}
+
+/// Compute 128-bit XXH3 hash with a custom secret of at least 136 bytes,
+/// which is checked at compile time.
+/// # Examples
+/// ```
+/// use cthash::xxh3_128_with_secret;
+/// const SECRET: [u8; 136] = [0x5a; 136];
+/// const H: u128 = xxh3_128_with_secret(b"data", &SECRET);
+/// ```
+pub const fn xxh3_128_with_secret<const S: usize>(input: &[u8], secret: &[u8; S]) -> u128 {
+    const { assert!(S >= SECRET_SIZE_MIN, "secret must be at least 136 bytes") }
+
+    if input.len() <= MID_SIZE_MAX {
+        xxh3_128_short(input, secret, 0)
+    } else {
+        xxh3_128_long(input, secret)
+    }
+}
+
+#[inline(always)]
+const fn xxh32_round(acc: u32, lane: u32) -> u32 {
+    acc.wrapping_add(lane.wrapping_mul(P32_2)).rotate_left(13).wrapping_mul(P32_1)
+}
+
+#[inline(always)]
+const fn xxh64_round(acc: u64, lane: u64) -> u64 {
+    acc.wrapping_add(lane.wrapping_mul(P64_2)).rotate_left(31).wrapping_mul(P64_1)
+}
+
+#[inline(always)]
+const fn xxh64_avalanche(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(P64_2);
+    h ^= h >> 29;
+    h = h.wrapping_mul(P64_3);
+    h ^ h >> 32
+}
+
+#[inline(always)]
+const fn xxh3_avalanche(mut h: u64) -> u64 {
+    h ^= h >> 37;
+    h = h.wrapping_mul(0x165667919e3779f9);
+    h ^ h >> 32
+}
+
+#[inline(always)]
+const fn rrmxmx(mut h: u64, len: u64) -> u64 {
+    h ^= h.rotate_left(49) ^ h.rotate_left(24);
+    h = h.wrapping_mul(0x9fb21c651e98df25);
+    h ^= (h >> 35).wrapping_add(len);
+    h = h.wrapping_mul(0x9fb21c651e98df25);
+    h ^ h >> 28
+}
+
+#[inline(always)]
+const fn mul128_fold64(a: u64, b: u64) -> u64 {
+    let product = a as u128 * b as u128;
+    product as u64 ^ (product >> 64) as u64
+}
+
+#[inline(always)]
+const fn mix16(input: &[u8], offset: usize, secret: &[u8], secret_offset: usize, seed: u64) -> u64 {
+    let lo = read_u64(input, offset) ^ read_u64(secret, secret_offset).wrapping_add(seed);
+    let hi = read_u64(input, offset + 8) ^ read_u64(secret, secret_offset + 8).wrapping_sub(seed);
+    mul128_fold64(lo, hi)
+}
+
+/// XXH3 64-bit for inputs of up to 240 bytes.
+#[inline]
+const fn xxh3_64_short(input: &[u8], secret: &[u8], seed: u64) -> u64 {
+    let len = input.len();
+    if len == 0 {
+        return xxh64_avalanche(seed ^ read_u64(secret, 56) ^ read_u64(secret, 64));
+    }
+    if len <= 3 {
+        let combined = read_u8(input, 0) << 16 | read_u8(input, len >> 1) << 24 | read_u8(input, len - 1) | (len as u32) << 8;
+        let bitflip = ((read_u32(secret, 0) ^ read_u32(secret, 4)) as u64).wrapping_add(seed);
+        return xxh64_avalanche(combined as u64 ^ bitflip);
+    }
+    if len <= 8 {
+        let seed = seed ^ ((seed as u32).swap_bytes() as u64) << 32;
+        let input64 = (read_u32(input, len - 4) as u64).wrapping_add((read_u32(input, 0) as u64) << 32);
+        let bitflip = (read_u64(secret, 8) ^ read_u64(secret, 16)).wrapping_sub(seed);
+        return rrmxmx(input64 ^ bitflip, len as u64);
+    }
+    if len <= 16 {
+        let bitflip1 = (read_u64(secret, 24) ^ read_u64(secret, 32)).wrapping_add(seed);
+        let bitflip2 = (read_u64(secret, 40) ^ read_u64(secret, 48)).wrapping_sub(seed);
+        let lo = read_u64(input, 0) ^ bitflip1;
+        let hi = read_u64(input, len - 8) ^ bitflip2;
+        let acc = (len as u64)
+            .wrapping_add(lo.swap_bytes())
+            .wrapping_add(hi)
+            .wrapping_add(mul128_fold64(lo, hi));
+        return xxh3_avalanche(acc);
+    }
+
+    let mut acc = (len as u64).wrapping_mul(P64_1);
+    if len <= 128 {
+        // pairs of 16-byte blocks from both ends, working inwards
+        let mut i = (len - 1) / 32;
+        loop {
+            acc = acc.wrapping_add(mix16(input, 16 * i, secret, 32 * i, seed));
+            acc = acc.wrapping_add(mix16(input, len.saturating_sub(16 * (i + 1)), secret, 32 * i + 16, seed));
+            if i == 0 {
+                break;
+            }
+            i -= 1;
+        }
+        return xxh3_avalanche(acc);
+    }
+
+    let rounds = len / 16;
+    let mut i = 0;
+    while i < 8 {
+        acc = acc.wrapping_add(mix16(input, 16 * i, secret, 16 * i, seed));
+        i += 1;
+    }
+    acc = xxh3_avalanche(acc);
+    while i < rounds {
+        acc = acc.wrapping_add(mix16(input, 16 * i, secret, 16 * (i - 8) + MID_SIZE_START_OFFSET, seed));
+        i += 1;
+    }
+    acc = acc.wrapping_add(mix16(input, len - 16, secret, SECRET_SIZE_MIN - MID_SIZE_LAST_OFFSET, seed));
+    xxh3_avalanche(acc)
+}
+
+/// XXH3 128-bit for inputs of up to 240 bytes.
+#[inline]
+const fn xxh3_128_short(input: &[u8], secret: &[u8], seed: u64) -> u128 {
+    let len = input.len();
+    if len == 0 {
+        let lo = xxh64_avalanche(seed ^ read_u64(secret, 64) ^ read_u64(secret, 72));
+        let hi = xxh64_avalanche(seed ^ read_u64(secret, 80) ^ read_u64(secret, 88));
+        return (hi as u128) << 64 | lo as u128;
+    }
+    if len <= 3 {
+        let combined_lo = read_u8(input, 0) << 16 | read_u8(input, len >> 1) << 24 | read_u8(input, len - 1) | (len as u32) << 8;
+        let combined_hi = combined_lo.swap_bytes().rotate_left(13);
+        let bitflip_lo = ((read_u32(secret, 0) ^ read_u32(secret, 4)) as u64).wrapping_add(seed);
+        let bitflip_hi = ((read_u32(secret, 8) ^ read_u32(secret, 12)) as u64).wrapping_sub(seed);
+        let lo = xxh64_avalanche(combined_lo as u64 ^ bitflip_lo);
+        let hi = xxh64_avalanche(combined_hi as u64 ^ bitflip_hi);
+        return (hi as u128) << 64 | lo as u128;
+    }
+    if len <= 8 {
+        let seed = seed ^ ((seed as u32).swap_bytes() as u64) << 32;
+        let input64 = (read_u32(input, 0) as u64).wrapping_add((read_u32(input, len - 4) as u64) << 32);
+        let bitflip = (read_u64(secret, 16) ^ read_u64(secret, 24)).wrapping_add(seed);
+        let m = (input64 ^ bitflip) as u128 * P64_1.wrapping_add((len as u64) << 2) as u128;
+        let (mut lo, mut hi) = (m as u64, (m >> 64) as u64);
+        hi = hi.wrapping_add(lo << 1);
+        lo ^= hi >> 3;
+        lo ^= lo >> 35;
+        lo = lo.wrapping_mul(0x9fb21c651e98df25);
+        lo ^= lo >> 28;
+        hi = xxh3_avalanche(hi);
+        return (hi as u128) << 64 | lo as u128;
+    }
+    if len <= 16 {
+        let bitflip_lo = (read_u64(secret, 32) ^ read_u64(secret, 40)).wrapping_sub(seed);
+        let bitflip_hi = (read_u64(secret, 48) ^ read_u64(secret, 56)).wrapping_add(seed);
+        let input_lo = read_u64(input, 0);
+        let mut input_hi = read_u64(input, len - 8);
+        let m = (input_lo ^ input_hi ^ bitflip_lo) as u128 * P64_1 as u128;
+        let (mut lo, mut hi) = (m as u64, (m >> 64) as u64);
+        lo = lo.wrapping_add(((len - 1) as u64) << 54);
+        input_hi ^= bitflip_hi;
+        hi = hi
+            .wrapping_add(input_hi)
+            .wrapping_add((input_hi as u32 as u64).wrapping_mul((P32_2 - 1) as u64));
+        lo ^= hi.swap_bytes();
+        let h = lo as u128 * P64_2 as u128;
+        let lo = xxh3_avalanche(h as u64);
+        let hi = xxh3_avalanche(((h >> 64) as u64).wrapping_add(hi.wrapping_mul(P64_2)));
+        return (hi as u128) << 64 | lo as u128;
+    }
+
+    let mut acc = ((len as u64).wrapping_mul(P64_1), 0u64);
+    if len <= 128 {
+        let mut i = (len - 1) / 32;
+        loop {
+            acc = mix32(acc, input, 16 * i, len.saturating_sub(16 * (i + 1)), secret, 32 * i, seed);
+            if i == 0 {
+                break;
+            }
+            i -= 1;
+        }
+    } else {
+        let rounds = len / 32;
+        let mut i = 0;
+        while i < 4 {
+            acc = mix32(acc, input, 32 * i, 32 * i + 16, secret, 32 * i, seed);
+            i += 1;
+        }
+        acc = (xxh3_avalanche(acc.0), xxh3_avalanche(acc.1));
+        while i < rounds {
+            acc = mix32(acc, input, 32 * i, 32 * i + 16, secret, 32 * (i - 4) + MID_SIZE_START_OFFSET, seed);
+            i += 1;
+        }
+        acc = mix32(
+            acc,
+            input,
+            len - 16,
+            len - 32,
+            secret,
+            SECRET_SIZE_MIN - MID_SIZE_LAST_OFFSET - 16,
+            0u64.wrapping_sub(seed),
+        );
+    }
+
+    let lo = acc.0.wrapping_add(acc.1);
+    let hi = acc
+        .0
+        .wrapping_mul(P64_1)
+        .wrapping_add(acc.1.wrapping_mul(P64_4))
+        .wrapping_add((len as u64).wrapping_sub(seed).wrapping_mul(P64_2));
+    (0u64.wrapping_sub(xxh3_avalanche(hi)) as u128) << 64 | xxh3_avalanche(lo) as u128
+}
+
+#[inline(always)]
+const fn mix32(acc: (u64, u64), input: &[u8], first: usize, second: usize, secret: &[u8], secret_offset: usize, seed: u64) -> (u64, u64) {
+    let mut lo = acc.0.wrapping_add(mix16(input, first, secret, secret_offset, seed));
+    lo ^= read_u64(input, second).wrapping_add(read_u64(input, second + 8));
+    let mut hi = acc.1.wrapping_add(mix16(input, second, secret, secret_offset + 16, seed));
+    hi ^= read_u64(input, first).wrapping_add(read_u64(input, first + 8));
+    (lo, hi)
+}
+
+const fn xxh3_64_long<const S: usize>(input: &[u8], secret: &[u8; S]) -> u64 {
+    let acc = hash_long(input, secret);
+    merge_accumulators(&acc, secret, SECRET_MERGE_ACCS_START, (input.len() as u64).wrapping_mul(P64_1))
+}
+
+const fn xxh3_128_long<const S: usize>(input: &[u8], secret: &[u8; S]) -> u128 {
+    let acc = hash_long(input, secret);
+    let lo = merge_accumulators(&acc, secret, SECRET_MERGE_ACCS_START, (input.len() as u64).wrapping_mul(P64_1));
+    let hi = merge_accumulators(
+        &acc,
+        secret,
+        const { S - STRIPE_LEN - SECRET_MERGE_ACCS_START },
+        !(input.len() as u64).wrapping_mul(P64_2),
+    );
+    (hi as u128) << 64 | lo as u128
+}
+
+/// Accumulate stripes of 64 bytes, scrambling the accumulators after each block.
+const fn hash_long<const S: usize>(input: &[u8], secret: &[u8; S]) -> [u64; 8] {
+    let mut acc = [P32_3 as u64, P64_1, P64_2, P64_3, P64_4, P32_2 as u64, P64_5, P32_1 as u64];
+
+    let stripes_per_block = const { (S - STRIPE_LEN) / SECRET_CONSUME_RATE };
+    let block_len = STRIPE_LEN * stripes_per_block;
+
+    // every block but the last one, even if that one is full
+    let mut rest = input;
+    while let Some((block, tail)) = rest.split_at_checked(block_len)
+        && !tail.is_empty()
+    {
+        accumulate(&mut acc, block, secret, stripes_per_block);
+        scramble(&mut acc, secret, const { S - STRIPE_LEN });
+        rest = tail;
+    }
+
+    // partial last block and the last stripe, which may overlap the previous one
+    accumulate(&mut acc, rest, secret, rest.len().saturating_sub(1) / STRIPE_LEN);
+    if let Some(stripe) = input.last_chunk::<STRIPE_LEN>() {
+        accumulate_stripe(&mut acc, stripe, secret, const { S - STRIPE_LEN - SECRET_LAST_ACC_START });
+    }
+
+    acc
+}
+
+#[inline(always)]
+const fn accumulate(acc: &mut [u64; 8], block: &[u8], secret: &[u8], stripes: usize) {
+    let (chunks, _) = block.as_chunks::<STRIPE_LEN>();
+    let mut n = 0;
+    while n < stripes && n < chunks.len() {
+        accumulate_stripe(acc, &chunks[n], secret, n * SECRET_CONSUME_RATE);
+        n += 1;
+    }
+}
+
+#[inline(always)]
+const fn accumulate_stripe(acc: &mut [u64; 8], stripe: &[u8; STRIPE_LEN], secret: &[u8], secret_offset: usize) {
+    let (values, _) = stripe.as_chunks::<8>();
+    let secret = match secret.split_at_checked(secret_offset) {
+        Some((_, tail)) => tail,
+        None => &[],
+    };
+    let mut i = 0;
+    while i < 8 {
+        let value = u64::from_le_bytes(values[i]);
+        let key = value ^ read_u64(secret, 8 * i);
+        acc[i ^ 1] = acc[i ^ 1].wrapping_add(value);
+        acc[i] = acc[i].wrapping_add((key & 0xffffffff).wrapping_mul(key >> 32));
+        i += 1;
+    }
+}
+
+#[inline(always)]
+const fn scramble(acc: &mut [u64; 8], secret: &[u8], secret_offset: usize) {
+    let mut i = 0;
+    while i < 8 {
+        let mut a = acc[i];
+        a ^= a >> 47;
+        a ^= read_u64(secret, secret_offset + 8 * i);
+        acc[i] = a.wrapping_mul(P32_1 as u64);
+        i += 1;
+    }
+}
+
+#[inline(always)]
+const fn merge_accumulators(acc: &[u64; 8], secret: &[u8], secret_offset: usize, start: u64) -> u64 {
+    let mut result = start;
+    let mut i = 0;
+    while i < 4 {
+        let lo = acc[2 * i] ^ read_u64(secret, secret_offset + 16 * i);
+        let hi = acc[2 * i + 1] ^ read_u64(secret, secret_offset + 16 * i + 8);
+        result = result.wrapping_add(mul128_fold64(lo, hi));
+        i += 1;
+    }
+    xxh3_avalanche(result)
+}
+
+/// Default secret with the seed added to and subtracted from alternate words.
+const fn custom_secret(seed: u64) -> [u8; 192] {
+    let mut secret = DEFAULT_SECRET;
+    let (words, _) = secret.as_chunks_mut::<16>();
+    let mut i = 0;
+    while i < words.len() {
+        let (lo, hi) = words[i].split_at_mut(8);
+        let lo_value = read_u64(lo, 0).wrapping_add(seed);
+        let hi_value = read_u64(hi, 0).wrapping_sub(seed);
+        lo.copy_from_slice(&lo_value.to_le_bytes());
+        hi.copy_from_slice(&hi_value.to_le_bytes());
+        i += 1;
+    }
+    secret
+}
+
+/// Little-endian word at `offset`, callers keep it in bounds.
+#[inline(always)]
+const fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    match bytes.split_at_checked(offset) {
+        Some((_, tail)) => match tail.first_chunk::<8>() {
+            Some(chunk) => u64::from_le_bytes(*chunk),
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+/// Byte at `offset`, callers keep it in bounds.
+#[inline(always)]
+const fn read_u8(bytes: &[u8], offset: usize) -> u32 {
+    match bytes.split_at_checked(offset) {
+        Some((_, tail)) => match tail.first() {
+            Some(&byte) => byte as u32,
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+/// Little-endian word at `offset`, callers keep it in bounds.
+#[inline(always)]
+const fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    match bytes.split_at_checked(offset) {
+        Some((_, tail)) => match tail.first_chunk::<4>() {
+            Some(chunk) => u32::from_le_bytes(*chunk),
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+const P32_1: u32 = 0x9e3779b1;
+const P32_2: u32 = 0x85ebca77;
+const P32_3: u32 = 0xc2b2ae3d;
+const P32_4: u32 = 0x27d4eb2f;
+const P32_5: u32 = 0x165667b1;
+
+const P64_1: u64 = 0x9e3779b185ebca87;
+const P64_2: u64 = 0xc2b2ae3d27d4eb4f;
+const P64_3: u64 = 0x165667b19e3779f9;
+const P64_4: u64 = 0x85ebca77c2b2ae63;
+const P64_5: u64 = 0x27d4eb2f165667c5;
+
+const STRIPE_LEN: usize = 64;
+const SECRET_CONSUME_RATE: usize = 8;
+const SECRET_SIZE_MIN: usize = 136;
+const SECRET_MERGE_ACCS_START: usize = 11;
+const SECRET_LAST_ACC_START: usize = 7;
+const MID_SIZE_MAX: usize = 240;
+const MID_SIZE_START_OFFSET: usize = 3;
+const MID_SIZE_LAST_OFFSET: usize = 17;
+
+const DEFAULT_SECRET: [u8; 192] = [
+    0xb8, 0xfe, 0x6c, 0x39, 0x23, 0xa4, 0x4b, 0xbe, 0x7c, 0x01, 0x81, 0x2c, 0xf7, 0x21, 0xad, 0x1c, 0xde, 0xd4, 0x6d, 0xe9, 0x83, 0x90,
+    0x97, 0xdb, 0x72, 0x40, 0xa4, 0xa4, 0xb7, 0xb3, 0x67, 0x1f, 0xcb, 0x79, 0xe6, 0x4e, 0xcc, 0xc0, 0xe5, 0x78, 0x82, 0x5a, 0xd0, 0x7d,
+    0xcc, 0xff, 0x72, 0x21, 0xb8, 0x08, 0x46, 0x74, 0xf7, 0x43, 0x24, 0x8e, 0xe0, 0x35, 0x90, 0xe6, 0x81, 0x3a, 0x26, 0x4c, 0x3c, 0x28,
+    0x52, 0xbb, 0x91, 0xc3, 0x00, 0xcb, 0x88, 0xd0, 0x65, 0x8b, 0x1b, 0x53, 0x2e, 0xa3, 0x71, 0x64, 0x48, 0x97, 0xa2, 0x0d, 0xf9, 0x4e,
+    0x38, 0x19, 0xef, 0x46, 0xa9, 0xde, 0xac, 0xd8, 0xa8, 0xfa, 0x76, 0x3f, 0xe3, 0x9c, 0x34, 0x3f, 0xf9, 0xdc, 0xbb, 0xc7, 0xc7, 0x0b,
+    0x4f, 0x1d, 0x8a, 0x51, 0xe0, 0x4b, 0xcd, 0xb4, 0x59, 0x31, 0xc8, 0x9f, 0x7e, 0xc9, 0xd9, 0x78, 0x73, 0x64, 0xea, 0xc5, 0xac, 0x83,
+    0x34, 0xd3, 0xeb, 0xc3, 0xc5, 0x81, 0xa0, 0xff, 0xfa, 0x13, 0x63, 0xeb, 0x17, 0x0d, 0xdd, 0x51, 0xb7, 0xf0, 0xda, 0x49, 0xd3, 0x16,
+    0x55, 0x26, 0x29, 0xd4, 0x68, 0x9e, 0x2b, 0x16, 0xbe, 0x58, 0x7d, 0x47, 0xa1, 0xfc, 0x8f, 0xf8, 0xb8, 0xd1, 0x7a, 0xd0, 0x31, 0xce,
+    0x45, 0xcb, 0x3a, 0x8f, 0x95, 0x16, 0x04, 0x28, 0xaf, 0xd7, 0xfb, 0xca, 0xbb, 0x4b, 0x40, 0x7e,
+];
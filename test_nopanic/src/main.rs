@@ -18,6 +18,12 @@ fn check_ct_sha2_256(s: &[u8]) -> [u8; 32] {
     cthash::sha2_256(s)
 }
 
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_sha2_256d(s: &[u8]) -> [u8; 32] {
+    cthash::sha2_256d(s)
+}
+
 #[no_panic]
 #[unsafe(no_mangle)]
 fn check_ct_sha2_384(s: &[u8]) -> [u8; 48] {
@@ -30,12 +36,36 @@ fn check_ct_sha2_512(s: &[u8]) -> [u8; 64] {
     cthash::sha2_512(s)
 }
 
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_sha2_256_trunc(s: &[u8]) -> [u8; 18] {
+    cthash::sha2_256_trunc(s)
+}
+
 #[no_panic]
 #[unsafe(no_mangle)]
 fn check_ct_sha3_512(s: &[u8]) -> [u8; 64] {
     cthash::sha3_512(s)
 }
 
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_sha2_256_bits(s: &[u8], bit_len: usize) -> [u8; 32] {
+    cthash::sha2_256_bits(s, bit_len)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_sha3_256_bits(s: &[u8], bit_len: usize) -> [u8; 32] {
+    cthash::sha3_256_bits(s, bit_len)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_keccak_sponge(s: &[u8]) -> [u8; 300] {
+    cthash::KeccakSponge::<137, 0x1f>::hash(s)
+}
+
 #[no_panic]
 #[unsafe(no_mangle)]
 fn check_ct_md4(s: &[u8]) -> [u8; 16] {
@@ -48,14 +78,352 @@ fn check_ct_md5(s: &[u8]) -> [u8; 16] {
     cthash::md5(s)
 }
 
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_ascon_hash256(s: &[u8]) -> [u8; 32] {
+    cthash::ascon_hash256(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_ascon_cxof128(s: &[u8]) -> [u8; 40] {
+    cthash::ascon_cxof128(s, b"customization")
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_poseidon(a: u64, b: u64) -> [u8; 32] {
+    let f = cthash::BN254_FR;
+    f.to_be_bytes(&cthash::POSEIDON_BN254_X5_3.hash(&[f.from_u64(a), f.from_u64(b)]))
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_poseidon2(a: u64, b: u64) -> [u8; 32] {
+    let f = cthash::BN254_FR;
+    f.to_be_bytes(&cthash::POSEIDON2_BN254_X5_3.hash(&[f.from_u64(a), f.from_u64(b)]))
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_md2(s: &[u8]) -> [u8; 16] {
+    cthash::md2(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_md6(s: &[u8]) -> [u8; 32] {
+    cthash::md6(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_md6_seq(s: &[u8]) -> [u8; 32] {
+    cthash::Md6::<104, 1>::hash_keyed(s, b"key")
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_tiger(s: &[u8]) -> [u8; 24] {
+    cthash::tiger(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_tiger_tree(s: &[u8]) -> [u8; 24] {
+    cthash::tiger_tree(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_skein512(s: &[u8]) -> [u8; 64] {
+    cthash::skein512(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_skein1024_keyed(s: &[u8], key: &[u8]) -> [u8; 200] {
+    cthash::Skein1024::hash_with(s, key, b"personalization")
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_threefish256_decrypt(key: &[u8; 32], tweak: &[u8; 16], block: &[u8; 32]) -> [u8; 32] {
+    cthash::hazmat::threefish256_decrypt(key, tweak, block)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_crc32(s: &[u8]) -> [u8; 4] {
+    cthash::CRC_32_ISO_HDLC.checksum(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_crc12(s: &[u8]) -> [u8; 2] {
+    cthash::CRC_12_UMTS.checksum(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_xxh64(s: &[u8]) -> u64 {
+    cthash::xxh64(s, 1)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_xxh3_64(s: &[u8], seed: u64) -> u64 {
+    cthash::xxh3_64(s, seed)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_xxh3_128_with_secret(s: &[u8], secret: &[u8; 150]) -> u128 {
+    cthash::xxh3_128_with_secret(s, secret)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_siphash24(key: &[u8; 16], s: &[u8]) -> u64 {
+    cthash::siphash24(key, s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_siphash13_128(key: &[u8; 16], s: &[u8]) -> u128 {
+    cthash::siphash13_128(key, s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_siphasher_update(hasher: &mut cthash::SipHasher24, s: &[u8]) {
+    hasher.update(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_kafka_partition(s: &[u8], partitions: core::num::NonZeroU32) -> u32 {
+    cthash::kafka_partition(s, partitions)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_murmur3_32(s: &[u8]) -> u32 {
+    cthash::murmur3_32(s, 0)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_murmur3_x64_128(s: &[u8]) -> u128 {
+    cthash::murmur3_x64_128(s, 0)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_cassandra_token(s: &[u8]) -> i64 {
+    cthash::cassandra_token(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_fnv1a_128(s: &[u8]) -> [u8; 16] {
+    cthash::fnv1a_128(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_jenkins_oaat(s: &[u8]) -> u32 {
+    cthash::jenkins_oaat_value(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_lookup3_hashlittle2(s: &[u8], seed: u64) -> u64 {
+    cthash::lookup3_hashlittle2_value(s, seed)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_cityhash128(s: &[u8]) -> u128 {
+    cthash::cityhash128(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_farmhash_fingerprint64(s: &[u8]) -> u64 {
+    cthash::farmhash_fingerprint64(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_highwayhash256(key: &[u64; 4], s: &[u8]) -> [u64; 4] {
+    cthash::highwayhash256(key, s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_wyhash(seed: u64, s: &[u8]) -> u64 {
+    cthash::wyhash(seed, s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_rapidhash(seed: u64, s: &[u8]) -> u64 {
+    cthash::rapidhash(seed, s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_base64(s: &[u8; 32]) -> cthash::encoding::Base64<32> {
+    cthash::encoding::base64(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_base32hex(s: &[u8; 20]) -> cthash::encoding::Base32<20> {
+    cthash::encoding::base32hex(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_base58(s: &[u8; 32]) -> cthash::encoding::Base58<32> {
+    cthash::encoding::base58(s)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_hash_kind(kind: cthash::HashKind, s: &[u8]) -> usize {
+    cthash::hash(kind, s).as_bytes().len()
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_sha256_incremental(a: &[u8], b: &[u8]) -> [u8; 32] {
+    let mut hasher = cthash::Sha256Hasher::new();
+    hasher.update(a);
+    hasher.update(b);
+    hasher.finalize()
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_sha512_incremental(a: &[u8], b: &[u8]) -> [u8; 64] {
+    let mut hasher = cthash::Sha512Hasher::new();
+    hasher.update(a);
+    hasher.update(b);
+    hasher.finalize()
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_sha3_384_incremental(a: &[u8], b: &[u8]) -> [u8; 48] {
+    let mut hasher = cthash::Sha3_384Hasher::new();
+    hasher.update(a);
+    hasher.update(b);
+    hasher.finalize()
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_md5_incremental(a: &[u8], b: &[u8]) -> [u8; 16] {
+    let mut hasher = cthash::Md5Hasher::new();
+    hasher.update(a);
+    hasher.update(b);
+    hasher.finalize()
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_dyn_hasher(kind: cthash::HashKind, a: &[u8], b: &[u8]) -> cthash::DynDigest {
+    let mut hasher = cthash::DynHasher::new(kind);
+    hasher.update(a);
+    hasher.update(b);
+    hasher.finalize()
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_blake2b(data: &[u8]) -> [u8; 64] {
+    cthash::blake2b(data)
+}
+
+#[no_panic]
+#[unsafe(no_mangle)]
+fn check_ct_blake2b_incremental(a: &[u8], b: &[u8]) -> [u8; 64] {
+    let mut hasher = cthash::Blake2b512Hasher::new();
+    hasher.update(a);
+    hasher.update(b);
+    hasher.finalize()
+}
+
 fn main() {
     let _ = check_ct_sha1(&[]);
     let _ = check_ct_sha2_224(&[]);
     let _ = check_ct_sha2_256(&[]);
+    let _ = check_ct_sha2_256d(&[]);
     let _ = check_ct_sha2_384(&[]);
     let _ = check_ct_sha2_512(&[]);
+    let _ = check_ct_sha2_256_trunc(&[]);
     let _ = check_ct_sha3_512(&[]);
+    let _ = check_ct_sha2_256_bits(&[], 0);
+    let _ = check_ct_sha3_256_bits(&[], 0);
+    let _ = check_ct_keccak_sponge(&[]);
+    let _ = check_ct_ascon_hash256(&[]);
+    let _ = check_ct_ascon_cxof128(&[]);
+    let _ = check_ct_poseidon(1, 2);
+    let _ = check_ct_poseidon2(1, 2);
+
+    let _ = check_ct_skein512(&[]);
+    let _ = check_ct_skein1024_keyed(&[], &[]);
+    let _ = check_ct_threefish256_decrypt(&[0; 32], &[0; 16], &[0; 32]);
+
+    let _ = check_ct_tiger(&[]);
+    let _ = check_ct_tiger_tree(&[]);
+
+    let _ = check_ct_crc32(&[]);
+    let _ = check_ct_crc12(&[]);
+
+    let _ = check_ct_xxh64(&[]);
+    let _ = check_ct_xxh3_64(&[], 0);
+    let _ = check_ct_xxh3_128_with_secret(&[], &[0; 150]);
+
+    let _ = check_ct_siphash24(&[0; 16], &[]);
+    let _ = check_ct_siphash13_128(&[0; 16], &[]);
+    check_ct_siphasher_update(&mut cthash::SipHasher24::new(&[0; 16]), &[]);
+
+    let _ = check_ct_kafka_partition(&[], core::num::NonZeroU32::MIN);
+    let _ = check_ct_murmur3_32(&[]);
+    let _ = check_ct_murmur3_x64_128(&[]);
+    let _ = check_ct_cassandra_token(&[]);
+
+    let _ = check_ct_fnv1a_128(&[]);
+    let _ = check_ct_jenkins_oaat(&[]);
+    let _ = check_ct_lookup3_hashlittle2(&[], 0);
+
+    let _ = check_ct_cityhash128(&[]);
+    let _ = check_ct_farmhash_fingerprint64(&[]);
+    let _ = check_ct_highwayhash256(&[0; 4], &[]);
+
+    let _ = check_ct_wyhash(0, &[]);
+    let _ = check_ct_rapidhash(0, &[]);
+
+    let _ = check_ct_base64(&[0; 32]);
+    let _ = check_ct_base32hex(&[0; 20]);
+    let _ = check_ct_base58(&[0; 32]);
+
+    let _ = check_ct_hash_kind(cthash::HashKind::Sha256, &[]);
+
+    let _ = check_ct_sha256_incremental(&[], &[]);
+    let _ = check_ct_sha512_incremental(&[], &[]);
+    let _ = check_ct_sha3_384_incremental(&[], &[]);
+    let _ = check_ct_md5_incremental(&[], &[]);
+    let _ = check_ct_dyn_hasher(cthash::HashKind::Md2, &[], &[]);
+    let _ = check_ct_blake2b(&[]);
+    let _ = check_ct_blake2b_incremental(&[], &[]);
 
+    let _ = check_ct_md2(&[]);
     let _ = check_ct_md4(&[]);
     let _ = check_ct_md5(&[]);
+    let _ = check_ct_md6(&[]);
+    let _ = check_ct_md6_seq(&[]);
 }
@@ -122,6 +122,14 @@ mod reference_impls {
         result.into()
     }
 
+    pub fn md2(data: &[u8]) -> [u8; 16] {
+        use md2::{Digest, Md2};
+        let mut hasher = Md2::new();
+        hasher.update(data);
+        let result = hasher.finalize();
+        result.into()
+    }
+
     pub fn md4(data: &[u8]) -> [u8; 16] {
         use md4::{Digest, Md4};
         let mut hasher = Md4::new();
@@ -230,6 +238,13 @@ fn keccak_512() {
     }
 }
 
+#[test]
+fn md2() {
+    for data in TEST_DATA.iter() {
+        cmp_fn_results(data, cthash::md2, reference_impls::md2);
+    }
+}
+
 #[test]
 fn md4() {
     for data in TEST_DATA.iter() {
@@ -243,3 +258,2055 @@ fn md5() {
         cmp_fn_results(data, cthash::md5, reference_impls::md5);
     }
 }
+
+#[test]
+fn keccak_sponge_shake() {
+    use sha3::digest::{ExtendableOutput, Update};
+
+    for data in TEST_DATA.iter() {
+        let mut reference = [0u8; 500];
+        sha3::Shake128::default().chain(data).finalize_xof_into(&mut reference);
+        assert_eq!(cthash::KeccakSponge::<168, 0x1f>::hash::<500>(data), reference);
+
+        let mut reference = [0u8; 500];
+        sha3::Shake256::default().chain(data).finalize_xof_into(&mut reference);
+        assert_eq!(cthash::KeccakSponge::<136, 0x1f>::hash::<500>(data), reference);
+    }
+}
+
+#[test]
+fn keccak_sponge_custom() {
+    use const_hex::decode_to_array as hex;
+
+    // rate is not a multiple of lane size
+    assert_eq!(
+        cthash::KeccakSponge::<137, 0x06>::hash(b"The quick brown fox jumps over the lazy dog"),
+        hex::<_, 32>("339ce2a931aea993ad1e84e05e3b28063656b6ac214d9b0e739d5206214f907c").unwrap()
+    );
+    // delimiter bit of the suffix takes the last bit of the block
+    assert_eq!(
+        cthash::KeccakSponge::<7, 0x87>::hash(b"abcdef"),
+        hex::<_, 40>("bd787a115e2f2f2328cfd555fe1afc74dd586a6e9812f463187c0d752673e597a9348df45c50230b").unwrap()
+    );
+    assert_eq!(
+        cthash::KeccakSponge::<7, 0x87>::hash(b"abcdefg"),
+        hex::<_, 40>("72b84beb582e042937cc6c051c956d88aed45262cbb537025c7148c35b72afd1a7b07b14fa769750").unwrap()
+    );
+    assert_eq!(
+        cthash::KeccakSponge::<1, 0x01>::hash(b""),
+        hex::<_, 16>("03c09c557208d7468f894b66a1b1cb09").unwrap()
+    );
+}
+
+#[test]
+fn bits_whole_bytes() {
+    for data in TEST_DATA.iter() {
+        let bit_len = data.len() * 8;
+        assert_eq!(cthash::sha1_bits(data, bit_len), cthash::sha1(data));
+        assert_eq!(cthash::sha2_224_bits(data, bit_len), cthash::sha2_224(data));
+        assert_eq!(cthash::sha2_256_bits(data, bit_len), cthash::sha2_256(data));
+        assert_eq!(cthash::sha2_384_bits(data, bit_len), cthash::sha2_384(data));
+        assert_eq!(cthash::sha2_512_bits(data, bit_len), cthash::sha2_512(data));
+        assert_eq!(cthash::sha3_224_bits(data, bit_len), cthash::sha3_224(data));
+        assert_eq!(cthash::sha3_256_bits(data, bit_len), cthash::sha3_256(data));
+        assert_eq!(cthash::sha3_384_bits(data, bit_len), cthash::sha3_384(data));
+        assert_eq!(cthash::sha3_512_bits(data, bit_len), cthash::sha3_512(data));
+
+        // bit length saturates at the input length
+        assert_eq!(cthash::sha2_256_bits(data, bit_len + 5), cthash::sha2_256(data));
+        assert_eq!(cthash::sha3_256_bits(data, bit_len + 5), cthash::sha3_256(data));
+        assert_eq!(cthash::sha1_bits(data, usize::MAX), cthash::sha1(data));
+        assert_eq!(cthash::sha2_224_bits(data, usize::MAX), cthash::sha2_224(data));
+        assert_eq!(cthash::sha2_256_bits(data, usize::MAX), cthash::sha2_256(data));
+        assert_eq!(cthash::sha2_384_bits(data, usize::MAX), cthash::sha2_384(data));
+        assert_eq!(cthash::sha2_512_bits(data, usize::MAX), cthash::sha2_512(data));
+        assert_eq!(cthash::sha3_224_bits(data, usize::MAX), cthash::sha3_224(data));
+        assert_eq!(cthash::sha3_256_bits(data, usize::MAX), cthash::sha3_256(data));
+        assert_eq!(cthash::sha3_384_bits(data, usize::MAX), cthash::sha3_384(data));
+        assert_eq!(cthash::sha3_512_bits(data, usize::MAX), cthash::sha3_512(data));
+    }
+}
+
+#[test]
+fn bits_partial_byte() {
+    use const_hex::decode_to_array as hex;
+
+    // FIPS 202 examples, 5-bit message `11001`
+    assert_eq!(
+        cthash::sha3_224_bits(&[0x13], 5),
+        hex::<_, 28>("ffbad5da96bad71789330206dc6768ecaeb1b32dca6b3301489674ab").unwrap()
+    );
+    assert_eq!(
+        cthash::sha3_256_bits(&[0x13], 5),
+        hex::<_, 32>("7b0047cf5a456882363cbf0fb05322cf65f4b7059a46365e830132e3b5d957af").unwrap()
+    );
+
+    // Unused bits of the last byte are ignored
+    assert_eq!(cthash::sha2_256_bits(&[0b1011_0000], 4), cthash::sha2_256_bits(&[0b1011_1111], 4));
+    assert_eq!(cthash::sha3_256_bits(&[0b0000_1101], 4), cthash::sha3_256_bits(&[0b1111_1101], 4));
+
+    let data = &[b'a'; 200];
+
+    const SHA1: [(usize, &str); 3] = [
+        (5, "80c0e3041a384f9edd3a4b03cc351af075b9069e"),
+        (447, "b9bc2df7dd523914ab18b2209dcb02515aaeab5f"),
+        (1023, "ba0600aa6253843b56be6b06d1214c4a75764f6e"),
+    ];
+    for (bit_len, h) in SHA1 {
+        assert_eq!(cthash::sha1_bits(data, bit_len), hex(h).unwrap());
+    }
+
+    const SHA2_224: [(usize, &str); 3] = [
+        (5, "edb48e2eb088ef0d4db6c541bf11ff5318335a794a9ff573e182fdd6"),
+        (447, "3c90abe889494d04913c72f282a4df859874305d05a9c78bba0e0c24"),
+        (1023, "a45848f56babeb9243eb5eee7e05468699c62c06d202822988a97322"),
+    ];
+    for (bit_len, h) in SHA2_224 {
+        assert_eq!(cthash::sha2_224_bits(data, bit_len), hex(h).unwrap());
+    }
+
+    const SHA2_256: [(usize, &str); 3] = [
+        (5, "db40996a6c4a5e7903269befb8fec4f30180f78a0ae9d994ed4ba569985439e1"),
+        (447, "299a83f9d722033e70407fc9e316ed019e58a401a4ab5cb9675bcf2a230e5449"),
+        (1023, "634ed224d1d50627c1fbfee6b921a75f82883f01da6f5c97e3ea81dadf295d86"),
+    ];
+    for (bit_len, h) in SHA2_256 {
+        assert_eq!(cthash::sha2_256_bits(data, bit_len), hex(h).unwrap());
+    }
+
+    const SHA2_384: [(usize, &str); 3] = [
+        (
+            5,
+            "211317c6d42f7be0f073407f82f0b0f773a48a473ad6dabb9ca4bdd231784231762c088ed04f0bf0a32378b528d9651a",
+        ),
+        (
+            447,
+            "164538374dbdaae41469853ac25461f679c8d83f2500fed5465d156e7429e7eacd809e217dc265bb4f5724cda7a93f82",
+        ),
+        (
+            1023,
+            "9df54997d1f4e254ce5d2335c3affce63568ad13dae61b9e436d8161a2cf6f0ee2a29954e4c979ca6405c4df08bd32dd",
+        ),
+    ];
+    for (bit_len, h) in SHA2_384 {
+        assert_eq!(cthash::sha2_384_bits(data, bit_len), hex(h).unwrap());
+    }
+
+    const SHA2_512: [(usize, &str); 3] = [
+        (
+            5,
+            "c3afcc1b92b535a69aeb71f4588b5cd90da4d5c19b63ef5a8c9262ffea8d1f99cea7a76ece93b66641d4f81bf0d053c07423b3ce08ac2e067895a01069f43ffd",
+        ),
+        (
+            447,
+            "310d9466ad6f2961d32a9885170bc08fece74a362403bd0add4795cf12bf1e85b01bf7c0c96b1e09b9c23932391917f1b4fd133b1f3e684dab7bcd702a7c78b0",
+        ),
+        (
+            1023,
+            "ba7514e35e4bba5af1426d2a61ea5792b137c6c0797877e53edd187d187d3f1e9eb06333d524aab1d028e19a50cdf674f222176307c8394672086a9047aaa646",
+        ),
+    ];
+    for (bit_len, h) in SHA2_512 {
+        assert_eq!(cthash::sha2_512_bits(data, bit_len), hex(h).unwrap());
+    }
+
+    // 1086 bits spill the SHA-3 suffix over the 136-byte SHA3-256 block
+    const SHA3_224: [(usize, &str); 3] = [
+        (5, "f73ef2dba3589441587a2bae77cfe2d0eefa7e62979e263268c9c869"),
+        (1085, "90ccd4a32c07d5d1b4b207539c7abd8595bb101819e632d717c4b15d"),
+        (1086, "539265f70f7e55af6f3b7ca5ac7b4700bbd7ba8ad79f24ddf8928976"),
+    ];
+    for (bit_len, h) in SHA3_224 {
+        assert_eq!(cthash::sha3_224_bits(data, bit_len), hex(h).unwrap());
+    }
+
+    const SHA3_256: [(usize, &str); 3] = [
+        (5, "795a56f643ad6340cb9211a0d26445dff0d43512b16aa77962c2ce208d7a7f01"),
+        (1085, "ea8373bef577d71d78a5448a6a96601c2559964bee9d2f86fd4cce626fc16b7f"),
+        (1086, "87256260d7f76a48302b15550070a0f7e979f65a288b95536187254463357ecb"),
+    ];
+    for (bit_len, h) in SHA3_256 {
+        assert_eq!(cthash::sha3_256_bits(data, bit_len), hex(h).unwrap());
+    }
+
+    const SHA3_384: [(usize, &str); 3] = [
+        (
+            5,
+            "ec97cc65ad709dd438e41125a1fb80831270a7b28ffa3e887baa3cfb0af3916228d603ddfaa5c1e074ac8884d01a5329",
+        ),
+        (
+            1085,
+            "0a9c9dc8d3aa860c2d56817413664e200bb5bab04ae94dc03e2a89b7aa144dce713a4a9f4ce02981adbf026d5be11d22",
+        ),
+        (
+            1086,
+            "556837eace71bbfa88d9b6232317d9dc4dded24e2ad4ddf5d8fd54714008f66d8971acd6bc79e0c8f45fc104b9bf313c",
+        ),
+    ];
+    for (bit_len, h) in SHA3_384 {
+        assert_eq!(cthash::sha3_384_bits(data, bit_len), hex(h).unwrap());
+    }
+
+    const SHA3_512: [(usize, &str); 3] = [
+        (
+            5,
+            "3ca19981ba49ca39bb2410b6dc6287814d79cd01eb303464285a3d1b769a4f2310a43da329e107edef8628a9dbeedbbc9a21ebc71b900fc95b60fee2510c8526",
+        ),
+        (
+            1085,
+            "1d7a396711ca6bf5121a5cc8c04eb0e1a4d4fd3258da093e2ae5a0948b9d9c1d8745b10d16c8fb80d4d43adc73424161f847164fb5cc032ced8866302f9b48ce",
+        ),
+        (
+            1086,
+            "f11aae9b555b4989e382c773e7902f47bd63a5c837aa9431353df03127bf7fa92b986be39ed3a9c39cfbb16b089646a2c3018f5eb291f4b1046e858fefbb09d5",
+        ),
+    ];
+    for (bit_len, h) in SHA3_512 {
+        assert_eq!(cthash::sha3_512_bits(data, bit_len), hex(h).unwrap());
+    }
+}
+
+#[test]
+fn truncated() {
+    fn cmp_prefix<const N: usize, const M: usize>(data: &[u8], trunc_fn: fn(&[u8]) -> [u8; M], full_fn: fn(&[u8]) -> [u8; N]) {
+        assert_eq!(trunc_fn(data), full_fn(data)[..M]);
+    }
+
+    for data in TEST_DATA.iter() {
+        cmp_prefix(data, cthash::tiger_trunc::<16>, cthash::tiger);
+        cmp_prefix(data, cthash::tiger2_trunc::<20>, cthash::tiger2);
+        cmp_prefix(data, cthash::md4_trunc::<5>, cthash::md4);
+        cmp_prefix(data, cthash::md5_trunc::<16>, cthash::md5);
+        cmp_prefix(data, cthash::sha1_trunc::<10>, cthash::sha1);
+        cmp_prefix(data, cthash::sha2_224_trunc::<27>, cthash::sha2_224);
+        cmp_prefix(data, cthash::sha2_256_trunc::<20>, cthash::sha2_256);
+        cmp_prefix(data, cthash::sha2_384_trunc::<13>, cthash::sha2_384);
+        cmp_prefix(data, cthash::sha2_512_trunc::<0>, cthash::sha2_512);
+        cmp_prefix(data, cthash::sha3_224_trunc::<28>, cthash::sha3_224);
+        cmp_prefix(data, cthash::sha3_256_trunc::<16>, cthash::sha3_256);
+        cmp_prefix(data, cthash::sha3_384_trunc::<1>, cthash::sha3_384);
+        cmp_prefix(data, cthash::sha3_512_trunc::<63>, cthash::sha3_512);
+        cmp_prefix(data, cthash::keccak_224_trunc::<7>, cthash::keccak_224);
+        cmp_prefix(data, cthash::keccak_256_trunc::<4>, cthash::keccak_256);
+        cmp_prefix(data, cthash::keccak_384_trunc::<45>, cthash::keccak_384);
+        cmp_prefix(data, cthash::keccak_512_trunc::<9>, cthash::keccak_512);
+    }
+
+    // Ethereum function selector
+    const SELECTOR: [u8; 4] = cthash::keccak_256_trunc(b"transfer(address,uint256)");
+    assert_eq!(SELECTOR, [0xa9, 0x05, 0x9c, 0xbb]);
+}
+
+#[test]
+fn fixed() {
+    fn cmp_fixed<const N: usize, const M: usize>(fixed_fn: fn(&[u8; N]) -> [u8; M], slice_fn: fn(&[u8]) -> [u8; M]) {
+        let data: [u8; N] = core::array::from_fn(|i| i as u8);
+        assert_eq!(fixed_fn(&data), slice_fn(&data));
+    }
+
+    // lengths around the padding boundaries of 64 and 128-byte blocks, and of
+    // the 72, 104, 136 and 144-byte rates of SHA-3
+    macro_rules! cmp_fixed_lens {
+        ($fixed:ident, $slice:ident) => {
+            cmp_fixed::<0, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<20, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<55, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<56, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<64, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<71, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<72, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<103, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<135, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<143, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<144, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<111, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<112, _>(cthash::$fixed, cthash::$slice);
+            cmp_fixed::<200, _>(cthash::$fixed, cthash::$slice);
+        };
+    }
+
+    cmp_fixed_lens!(tiger_fixed, tiger);
+    cmp_fixed_lens!(tiger2_fixed, tiger2);
+    cmp_fixed_lens!(md4_fixed, md4);
+    cmp_fixed_lens!(md5_fixed, md5);
+    cmp_fixed_lens!(sha1_fixed, sha1);
+    cmp_fixed_lens!(sha2_224_fixed, sha2_224);
+    cmp_fixed_lens!(sha2_256_fixed, sha2_256);
+    cmp_fixed_lens!(sha2_384_fixed, sha2_384);
+    cmp_fixed_lens!(sha2_512_fixed, sha2_512);
+    cmp_fixed_lens!(sha3_224_fixed, sha3_224);
+    cmp_fixed_lens!(sha3_256_fixed, sha3_256);
+    cmp_fixed_lens!(sha3_384_fixed, sha3_384);
+    cmp_fixed_lens!(sha3_512_fixed, sha3_512);
+    cmp_fixed_lens!(keccak_224_fixed, keccak_224);
+    cmp_fixed_lens!(keccak_256_fixed, keccak_256);
+    cmp_fixed_lens!(keccak_384_fixed, keccak_384);
+    cmp_fixed_lens!(keccak_512_fixed, keccak_512);
+}
+
+#[test]
+fn sha256d() {
+    for data in TEST_DATA.iter() {
+        assert_eq!(cthash::sha2_256d(data), reference_impls::sha256(&reference_impls::sha256(data)));
+    }
+
+    // Bitcoin genesis block header
+    let header: [u8; 80] = const_hex::decode_to_array(
+        "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c",
+    )
+    .unwrap();
+    let mut block_hash = cthash::sha2_256d(&header);
+    block_hash.reverse();
+    assert_eq!(
+        const_hex::encode(block_hash),
+        "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+    );
+}
+
+fn field_hex(field: &cthash::PrimeField, element: &cthash::FieldElement) -> String {
+    const_hex::encode(field.to_be_bytes(element))
+}
+
+#[test]
+fn prime_field() {
+    // expected values computed with Python big integers
+    let cases = [
+        (
+            cthash::BN254_FR,
+            [
+                "1f37631a3d9cbfac8f5f7492fcfd4f45af982f6f0c8d1edd783c14d81fffffeb",
+                "121787263c2b879fc94f37ed118d30b30a805b2887620893ab517511e4169ea0",
+                "205aa881c7488d9b9082b9fa86a91d34b0bb74d69638eccc795f5a3fa9abcdda",
+                "1e141db2b3f0f1bd8e3c2f2b73518156ae74ea0782e150ee7718cf70965431fc",
+                "19e2ba3101ac046306ed902c9966b0b8eda05027f7ff2dc82d1a4971d4885643",
+                "2db628152179e55e396c36b2dcd4128c1ab5bc36029a4a48c49a3f0c9e477c46",
+            ],
+        ),
+        (
+            cthash::BLS12_381_FR,
+            [
+                "0c1258acd66282b7ccc627f7f65e27faac425bfd0001a40100000000ffffffec",
+                "1b82e9629d2385ce26f5fd6d0b6017533b4dba6bf19e2fb549e8018adad6e56c",
+                "0d359e14600e50a6cde96d5f8009f5e9ad65a16489ad71f00123456889abcddb",
+                "0aef13454cb6b4c8cba2e2906cb25a0bab1f16957655d611fedcba99765431fd",
+                "04d3b0397353e213a15e26fab13967476fe0904322d46a45aa114213c0ac5770",
+                "65a4806d711b0ff4690b09bcc025b289d81fb4aaf1f7acfc9898ad4bc12cc856",
+            ],
+        ),
+    ];
+
+    for (f, [reduced, mul, add, sub, inv, pow]) in cases {
+        // 2^255 - 19 is above both moduli and gets reduced
+        let mut a_bytes = [0xff; 32];
+        a_bytes[0] = 0x7f;
+        a_bytes[31] = 0xed;
+        let a = f.from_be_bytes(&a_bytes);
+        let b = f.from_be_bytes(&const_hex::decode_to_array("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef").unwrap());
+
+        assert_eq!(field_hex(&f, &a), reduced);
+        assert_eq!(field_hex(&f, &f.mul(&a, &b)), mul);
+        assert_eq!(field_hex(&f, &f.add(&a, &b)), add);
+        assert_eq!(field_hex(&f, &f.sub(&a, &b)), sub);
+        assert_eq!(field_hex(&f, &f.inv(&a)), inv);
+        assert_eq!(field_hex(&f, &f.pow(&a, &[0x0123456789abcdef; 4])), pow);
+
+        assert_eq!(f.mul(&a, &f.inv(&a)), f.one());
+        assert_eq!(f.add(&a, &f.neg(&a)), f.zero());
+        assert_eq!(f.square(&a), f.mul(&a, &a));
+        assert_eq!(f.pow_u64(&f.from_u64(3), 5), f.from_u64(243));
+        assert_eq!(f.inv(&f.zero()), f.zero());
+        assert!(f.is_zero(&f.from_be_bytes(&f.modulus())));
+
+        let mut le = f.to_be_bytes(&a);
+        le.reverse();
+        assert_eq!(f.to_le_bytes(&a), le);
+        assert_eq!(f.from_le_bytes(&le), a);
+    }
+}
+
+#[test]
+fn poseidon() {
+    use cthash::{BLS12_381_FR, BN254_FR};
+
+    // circomlib poseidon([1]) and poseidon([1, 2])
+    let h = cthash::POSEIDON_BN254_X5_2.hash(&[BN254_FR.from_u64(1)]);
+    assert_eq!(
+        field_hex(&BN254_FR, &h),
+        "29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133"
+    );
+    let h = cthash::POSEIDON_BN254_X5_3.hash(&[BN254_FR.from_u64(1), BN254_FR.from_u64(2)]);
+    assert_eq!(
+        field_hex(&BN254_FR, &h),
+        "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a"
+    );
+
+    // permutation vectors of the reference implementation
+    let mut state = [0, 1, 2].map(|x| BN254_FR.from_u64(x));
+    cthash::POSEIDON_BN254_X5_3.permute(&mut state);
+    assert_eq!(
+        state.map(|x| field_hex(&BN254_FR, &x)),
+        [
+            "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a",
+            "0fca49b798923ab0239de1c9e7a4a9a2210312b6a2f616d18b5a87f9b628ae29",
+            "0e7ae82e40091e63cbd4f16a6d16310b3729d4b6e138fcf54110e2867045a30c",
+        ]
+    );
+
+    let mut state = [0, 1, 2, 3, 4].map(|x| BN254_FR.from_u64(x));
+    cthash::POSEIDON_BN254_X5_5.permute(&mut state);
+    assert_eq!(
+        field_hex(&BN254_FR, &state[0]),
+        "299c867db6c1fdd79dcefa40e4510b9837e60ebb1ce0663dbaa525df65250465"
+    );
+
+    let mut state = [0, 1, 2].map(|x| BLS12_381_FR.from_u64(x));
+    cthash::POSEIDON_BLS12_381_X5_3.permute(&mut state);
+    assert_eq!(
+        field_hex(&BLS12_381_FR, &state[0]),
+        "28ce19420fc246a05553ad1e8c98f5c9d67166be2c18e9e4cb4b4e317dd2a78a"
+    );
+
+    let mut state = [0, 1, 2, 3, 4].map(|x| BLS12_381_FR.from_u64(x));
+    cthash::POSEIDON_BLS12_381_X5_5.permute(&mut state);
+    assert_eq!(
+        field_hex(&BLS12_381_FR, &state[0]),
+        "2a918b9c9f9bd7bb509331c81e297b5707f6fc7393dcee1b13901a0b22202e18"
+    );
+
+    // sponge vectors computed with a Python model of the reference implementation
+    let out: [_; 3] = cthash::POSEIDON_BN254_X5_3.sponge(&[1, 2, 3, 4, 5].map(|x| BN254_FR.from_u64(x)));
+    assert_eq!(
+        out.map(|x| field_hex(&BN254_FR, &x)),
+        [
+            "1b868606ec40d58d0795340121a47ccf2bad34bfeb2610c3c4ac632f04b2f5d6",
+            "13a8da57e37597b25234a98c45d7efd1acbe0e4bb2eb724ab1c65e5e342f8e44",
+            "1a5423de557d979f694a8350e497b598b2e5da45d50d875e5b095a96ce04ff4a",
+        ]
+    );
+    let [out] = cthash::POSEIDON_BN254_X5_3.sponge::<0, 1>(&[]);
+    assert_eq!(
+        field_hex(&BN254_FR, &out),
+        "13a545a13f1d91dddb87f46679dfaec0900ce24791a924bee7fa4d69a9569d85"
+    );
+}
+
+#[test]
+fn poseidon2() {
+    use cthash::{BLS12_381_FR, BN254_FR};
+
+    // permutation vectors of the reference implementation
+    let mut state = [0, 1, 2].map(|x| BN254_FR.from_u64(x));
+    cthash::POSEIDON2_BN254_X5_3.permute(&mut state);
+    assert_eq!(
+        state.map(|x| field_hex(&BN254_FR, &x)),
+        [
+            "0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033",
+            "303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570",
+            "1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8",
+        ]
+    );
+
+    let mut state = [0, 1, 2].map(|x| BLS12_381_FR.from_u64(x));
+    cthash::POSEIDON2_BLS12_381_X5_3.permute(&mut state);
+    assert_eq!(
+        state.map(|x| field_hex(&BLS12_381_FR, &x)),
+        [
+            "1b152349b1950b6a8ca75ee4407b6e26ca5cca5650534e56ef3fd45761fbf5f0",
+            "4c5793c87d51bdc2c08a32108437dc0000bd0275868f09ebc5f36919af5b3891",
+            "1fc8ed171e67902ca49863159fe5ba6325318843d13976143b8125f08b50dc6b",
+        ]
+    );
+
+    // hash is the first element of the permuted [0, inputs..]
+    let mut state = [0, 1, 2].map(|x| BN254_FR.from_u64(x));
+    cthash::POSEIDON2_BN254_X5_3.permute(&mut state);
+    assert_eq!(
+        cthash::POSEIDON2_BN254_X5_3.hash(&[BN254_FR.from_u64(1), BN254_FR.from_u64(2)]),
+        state[0]
+    );
+}
+
+#[test]
+fn ascon() {
+    // NIST SP 800-232 KATs, messages 00 01 02 ...
+    let hash256 = [
+        "0b3be5850f2f6b98caf29f8fdea89b64a1fa70aa249b8f839bd53baa304d92b2",
+        "0728621035af3ed2bca03bf6fde900f9456f5330e4b5ee23e7f6a1e70291bc80",
+        "6115e7c9c4081c2797fc8fe1bc57a836afa1c5381e556dd583860ca2dfb48dd2",
+        "265ab89a609f5a05dca57e83fbba700f9a2d2c4211ba4cc9f0a1a369e17b915c",
+        "d7e4c7ed9b8a325cd08b9ef259f8877054ecd8304fe1b2d7fd847137df6727ee",
+    ];
+    let data: [u8; 4] = [0, 1, 2, 3];
+    for (len, expected) in hash256.into_iter().enumerate() {
+        assert_eq!(const_hex::encode(cthash::ascon_hash256(&data[..len])), expected, "{len}");
+    }
+    assert_eq!(
+        const_hex::encode(cthash::ascon_xof128::<32>(b"")),
+        "473d5e6164f58b39dfd84aacdb8ae42ec2d91fed33388ee0d960d9b3993295c6"
+    );
+    assert_eq!(
+        const_hex::encode(cthash::ascon_cxof128::<32, 0>(b"", &[])),
+        "4f50159ef70bb3dad8807e034eaebd44c4fa2cbbc8cf1f05511ab66cdcc52990"
+    );
+
+    // the customization string takes up to 256 bytes
+    let customization = [0x5a; 256];
+    assert_ne!(
+        cthash::ascon_cxof128::<32, 256>(b"", &customization),
+        cthash::ascon_cxof128::<32, 255>(b"", customization.first_chunk().unwrap())
+    );
+    let data = [b'a'; 1000];
+    assert_eq!(cthash::ascon_xof128::<7>(&data), cthash::ascon_xof128::<100>(&data)[..7]);
+}
+
+#[test]
+fn blake2b() {
+    use blake2::digest::{Update, VariableOutput};
+
+    // RFC 7693, appendix A
+    assert_eq!(
+        const_hex::encode(cthash::blake2b::<64>(b"abc")),
+        "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+    );
+
+    for data in TEST_DATA {
+        cmp_fn_results(data, cthash::blake2b, |data| {
+            use blake2::Digest;
+            blake2::Blake2b512::digest(data).into()
+        });
+    }
+
+    fn check<const N: usize>() {
+        for len in [0, 1, 127, 128, 129, 255, 256, 257, 1000] {
+            let data = vec![0x5a; len];
+            let mut reference = [0u8; N];
+            let mut hasher = blake2::Blake2bVar::new(N).unwrap();
+            hasher.update(&data);
+            hasher.finalize_variable(&mut reference).unwrap();
+            assert_eq!(cthash::blake2b::<N>(&data), reference, "{N} bytes of {len}");
+        }
+    }
+    check::<1>();
+    check::<20>();
+    check::<32>();
+    check::<48>();
+    check::<63>();
+}
+
+#[test]
+fn md6() {
+    // examples from the MD6 specification and outputs of the reference `md6sum`
+    assert_eq!(
+        const_hex::encode(cthash::md6::<64>(b"")),
+        "6b7f33821a2c060ecdd81aefddea2fd3c4720270e18654f4cb08ece49ccb469f8beeee7c831206bd577f9f2630d9177979203a9489e47e04df4e6deaa0f8e0c0"
+    );
+    assert_eq!(
+        const_hex::encode(cthash::md6::<32>(b"")),
+        "bca38b24a804aa37d821d31af00f5598230122c5bbfc4c4ad5ed40e4258f04ca"
+    );
+    assert_eq!(
+        const_hex::encode(cthash::md6::<32>(b"abc")),
+        "230637d4e6845cf0d092b558e87625f03881dd53a7439da34cf3b94ed0d8b2c5"
+    );
+
+    assert_eq!(
+        const_hex::encode(cthash::md6::<64>(b"abc")),
+        "00918245271e377a7ffb202b90f3bda5477d8feab12d8a3a8994ebc55fe6e74ca8341520032eeea3fdef892f2882378f636212af4b2683ccf80bf025b7d9b457"
+    );
+    assert_eq!(
+        const_hex::encode(cthash::md6::<32>(b"The quick brown fox jumps over the lazy dog")),
+        "977592608c45c9923340338450fdcccc21a68888e1e6350e133c5186cd9736ee"
+    );
+}
+
+#[test]
+fn tiger() {
+    // NESSIE and reference implementation outputs, Tiger2 only changes the padding byte
+    let expected = [
+        (
+            "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3",
+            "4441be75f6018773c206c22745374b924aa8313fef919f41",
+        ),
+        (
+            "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93",
+            "f68d7bc5af4b43a06e048d7829560d4a9415658bb0b1f3bf",
+        ),
+        (
+            "0f7bf9a19b9c58f2b7610df7e84f0ac3a71c631e7b53f78e",
+            "a6737f3997e8fbb63d20d2df88f86376b5fe2d5ce36646a9",
+        ),
+        (
+            "ecce1e3610505fce94f732ee25e8cb7afaf7fcc8888866fd",
+            "2933e3294e58dd6af7b1a2f59c9cd031c1f7bc9cb0d9ae2f",
+        ),
+        (
+            "6d12a41e72e644f017b6f0e2f7b44c6285f06dd5d2c5b075",
+            "976abff8062a2e9dcea3a1ace966ed9c19cb85558b4976d8",
+        ),
+        (
+            "a8f04b0f7201a0d728101c9d26525b31764a3493fcd8458f",
+            "09c11330283a27efb51930aa7dc1ec624ff738a8d9bdd3df",
+        ),
+        (
+            "6db0e2729cbead93d715c6a7d36302e9b3cee0d2bc314b41",
+            "e068281f060f551628cc5715b9d0226796914d45f7717cf4",
+        ),
+    ];
+    for (data, (tiger, tiger2)) in TEST_DATA.iter().zip(expected) {
+        assert_eq!(const_hex::encode(cthash::tiger(data)), tiger);
+        assert_eq!(const_hex::encode(cthash::tiger2(data)), tiger2);
+    }
+}
+
+#[test]
+fn tiger_tree() {
+    // THEX examples for the empty file and a single zero byte, the rest span several leaves
+    let mut pattern = [0u8; 10241];
+    for (i, b) in pattern.iter_mut().enumerate() {
+        *b = if i < 10240 { i as u8 } else { b'x' };
+    }
+    let cases: [(&[u8], &str); 6] = [
+        (b"", "5d9ed00a030e638bdb753a6a24fb900e5a63b8e73e6c25b6"),
+        (&[0], "aabbcca084acecd0511d1f6232a17bfaefa441b2982e5548"),
+        (&[b'a'; 1024], "0c781aa42c38cb51151cb05f25fb76eced7b5432177fc0ad"),
+        (&[b'a'; 1025], "10f18d3ade2f873308fb879ac90d728d7ef6b00bd8883d8c"),
+        (&[b'a'; 5000], "7ddc046e45beb7ee2ba1a0ccd408f88678286e377629c72c"),
+        (&pattern, "1dde2eb20a90dcb9682cb535dd186475615ffa102e25add5"),
+    ];
+    for (data, expected) in cases {
+        assert_eq!(const_hex::encode(cthash::tiger_tree(data)), expected);
+    }
+}
+
+#[test]
+fn skein() {
+    // reference implementation outputs for the full-size digests
+    let expected = [
+        (
+            "c8877087da56e072870daa843f176e9453115929094c3a40c463a196c29bf7ba",
+            "bc5b4c50925519c290cc634277ae3d6257212395cba733bbad37a4af0fa06af41fca7903d06564fea7a2d3730dbdb80c1f85562dfcc070334ea4d1d9e72cba7a",
+            "0fff9563bb3279289227ac77d319b6fff8d7e9f09da1247b72a0a265cd6d2a62645ad547ed8193db48cff847c06494a03f55666d3b47eb4c20456c9373c86297d630d5578ebd34cb40991578f9f52b18003efa35d3da6553ff35db91b81ab890bec1b189b7f52cb2a783ebb7d823d725b0b4a71f6824e88f68f982eefc6d19c6",
+        ),
+        (
+            "258bdec343b9fde1639221a5ae0144a96e552e5288753c5fec76c05fc2fc1870",
+            "8f5dd9ec798152668e35129496b029a960c9a9b88662f7f9482f110b31f9f93893ecfb25c009baad9e46737197d5630379816a886aa05526d3a70df272d96e75",
+            "35a599a0f91abcdb4cb73c19b8cb8d947742d82c309137a7caed29e8e0a2ca7a9ff9a90c34c1908cc7e7fd99bb15032fb86e76df21b72628399b5f7c3cc209d7bb31c99cd4e19465622a049afbb87c03b5ce3888d17e6e667279ec0aa9b3e2712624c01b5f5bbe1a564220bdcf6990af0c2539019f313fdd7406cca3892a1f1f",
+        ),
+        (
+            "21cdd023e2b2e90a1f137d050c5a81bc84545048e1ed035d87e9e721f19ffea2",
+            "ded9469f2dbb2bd32390d2a3396045bb33c706291954f66d4f296ade2c09a61eeb51a72d86e392c489b53a90536045222b40d9355d1aa187d59041b7b98e521a",
+            "07b03a80f11f09294da9346bfe5499a5253ccf69c9fb338f190dda93103b82ca89d34dfa3de778a8c1a92f0fe5ced5a856815e2ca5e86289a5529fcc886781068a3b257ec7cb6ec127d5244d897d64490e9e1dfcfa48463974c0555baf517280c34b761d160301e2e9b24c4b1ba850f1b3a2396c807bd5412fdd5002eec05cf2",
+        ),
+        (
+            "f5c65594cb4c68afc445a4cf4107dbe2f92eff0b9990afbbdb9d2b3acdedba0f",
+            "106521be7d48c73dbe2567e7609550ca5edaf0dd5058230b95711fe19294629fc7a263fbf62bea6b01f98b22d7aef979740aee851b4a36837fed3e3732e3c84a",
+            "4372c2ccd805f51560302e36a6750aaba5b8ebbff9c3ab08f6d31048b0f017c46147e0dbe4519f0b12798b1f074cdef997821b45ae15daac9e2d252d04bf8d9495dc4af018e3f619194124feaeabab209fe7096e8eb0450ba86d8e5d25b513ca82957581f79e75e90fa79ce8a7d1acb187a52b687c4421f012b2f6e889147f75",
+        ),
+        (
+            "c0fbd7d779b20f0a4614a66697f9e41859eaf382f14bf857e8cdb210adb9b3fe",
+            "94c2ae036dba8783d0b3f7d6cc111ff810702f5c77707999be7e1c9486ff238a7044de734293147359b4ac7e1d09cd247c351d69826b78dcddd951f0ef912713",
+            "4cf6152f1a7e598098d28f04e13d7742ba39b7fadbbcf2167bda4e1615d551f3f6b4edbbb391ffa09e6cc0a4af1eb366b30b5f107b437e2ea5cb586afb0341bd97dabe7cc46e7be3a054aa605395e43b243654c01ffc14c8b5443488f35d80b504a612f3d29d767106d0d9249aaa4fd99b67a94fb8661a3520004501192d84fa",
+        ),
+        (
+            "fb2f2f2deed0e1dd7ee2b91cee34e2d1c22072e1f5eaee288c35a0723eb653cd",
+            "7f81113575e4b4d3441940e87aca331e6d63d103fe5107f29cd877af0d0f5e0ea34164258c60da5190189d0872e63a96596d2ef25e709099842da71d64111e0f",
+            "de3358f4bece3deec3dc0018e0c1db919f18b03421eaafa8864dee3d74ed8d1cd8390f8cc8476cf88ad80f38090c615c5dcf4cebec9a7f246b5a3bb56bf0398e75424bd825afb87de46ea18927f76644c82464d57418eeb25f0eb07d268b43c267da44ce0ff6f63e888bef2de453c818a68ab462ae10465dbcfd4e486f7e5dcb",
+        ),
+        (
+            "570c70901e31994c1f7b960f3fbdcf8db003e533396d48389f46d37c3ed14738",
+            "c9d41b77b77b77e954284185af682a5a8b25b9d31e6d58eb9fd329f5bcca34d7b285ab130a9c14c872192bcdf2b67d883280a754acba942a7cf448e841a74ed2",
+            "6cb2954aeddedc5067008db1945333bd9cfe9e46c1075723cb57c56c1cc376a8ce4da195f42e01bc0098b4817ab686b981c937e0537e11eddf62f04422ae6f1eda87ef09299d87852faf89b6cae3d36e63914069806f823bbce436b3c5f4ab6c8aa69316467c59a1cbdbc35dd97440323c4c70ef17fb1bfcfc4ef6e3e1d7a260",
+        ),
+    ];
+    for (data, (s256, s512, s1024)) in TEST_DATA.iter().zip(expected) {
+        assert_eq!(const_hex::encode(cthash::skein256::<32>(data)), s256);
+        assert_eq!(const_hex::encode(cthash::skein512::<64>(data)), s512);
+        assert_eq!(const_hex::encode(cthash::skein1024::<128>(data)), s1024);
+    }
+
+    // digest sizes other than the state size, Skein-512-256 of "" is a published vector
+    assert_eq!(
+        const_hex::encode(cthash::skein512::<32>(b"")),
+        "39ccc4554a8b31853b9de7a1fe638a24cce6b35a55f2431009e18780335d2621"
+    );
+    assert_eq!(
+        const_hex::encode(cthash::skein512::<20>(b"abc")),
+        "a603e7cd34c4338c4cb8ce6074020c78ad005b19"
+    );
+    assert_eq!(
+        const_hex::encode(cthash::skein256::<100>(b"abc")),
+        "22c78d117aae88fb266af69a122096b705392b1d51136b19bdbb793a63fd5a8abad3bc76c557fafe2500d7f106c1ac4505d5a2d589a79061ec6bd5915e4104011bcb6dc02772062222960c0fdcada54e634f47a89b89963ff1d35d7d3dafb4aa4b9e62a3"
+    );
+    assert_eq!(const_hex::encode(cthash::skein1024::<1>(b"abc")), "6d");
+    assert_eq!(
+        const_hex::encode(cthash::skein256::<32>(&[0xff])),
+        "0b98dcd198ea0e50a7a244c444e25c23da30c10fc9a1f270a6637f1f34e67ed2"
+    );
+}
+
+#[test]
+fn skein_keyed() {
+    use cthash::{Skein256, Skein512, Skein1024};
+
+    assert_eq!(
+        const_hex::encode(Skein512::hash_keyed::<32>(b"data", b"secret")),
+        "1654a5c2d791638fa78a2a794ae366ebbb3c4922ac80103a2b8e15f8b9e0501b"
+    );
+    assert_eq!(
+        const_hex::encode(Skein512::hash_with::<32>(b"data", b"secret", b"20261019 me@example.org app/v1")),
+        "31698df9bd173bcb05afca7aedff5f36b438b367396bb971b6af4d642fc2c5bb"
+    );
+    assert_eq!(
+        const_hex::encode(Skein512::hash_with::<32>(b"data", b"", b"pers")),
+        "d4ab77a30b5a77395b478ecdcfd13ef1ee284b3f6f683d3309ff4548d7ec4d50"
+    );
+    // keys longer than a block
+    assert_eq!(
+        const_hex::encode(Skein256::hash_keyed::<32>(b"data", &[b'k'; 100])),
+        "514a76bb789db4ce81e0831cb777f34a974ffaf3f7427dab786777833ebcf1eb"
+    );
+    let key: [u8; 128] = core::array::from_fn(|i| i as u8);
+    assert_eq!(
+        const_hex::encode(Skein1024::hash_keyed::<64>(b"data", &key)),
+        "77e6e68ac59d420ea4d1df3d787d222ca595a71b0e709f86d6100e879ca1e207550fbc77fbc64f326f12a20993534ca47867f638474e901470db3597cd3d15f7"
+    );
+}
+
+#[test]
+fn threefish() {
+    use cthash::hazmat::*;
+
+    // all-zero key, tweak and block
+    assert_eq!(
+        const_hex::encode(threefish256_encrypt(&[0; 32], &[0; 16], &[0; 32])),
+        "84da2a1f8beaee947066ae3e3103f1ad536db1f4a1192495116b9f3ce6133fd8"
+    );
+    assert_eq!(
+        const_hex::encode(threefish512_encrypt(&[0; 64], &[0; 16], &[0; 64])),
+        "b1a2bbc6ef6025bc40eb3822161f36e375d1bb0aee3186fbd19e47c5d479947b7bc2f8586e35f0cff7e7f03084b0b7b1f1ab3961a580a3e97eb41ea14a6d7bbe"
+    );
+    assert_eq!(
+        const_hex::encode(threefish1024_encrypt(&[0; 128], &[0; 16], &[0; 128])),
+        "f05c3d0a3d05b304f785ddc7d1e036015c8aa76e2f217b06c6e1544c0bc1a90df0accb9473c24e0fd54fea68057f43329cb454761d6df5cf7b2e9b3614fbd5a20b2e4760b40603540d82eabc5482c171c832afbe68406bc39500367a592943fa9a5b4a43286ca3c4cf46104b443143d560a4b230488311df4feef7e1dfe8391e"
+    );
+
+    let key: [u8; 32] = core::array::from_fn(|i| 16 + i as u8);
+    let tweak: [u8; 16] = core::array::from_fn(|i| i as u8);
+    let block: [u8; 32] = core::array::from_fn(|i| 255 - i as u8);
+    let encrypted = threefish256_encrypt(&key, &tweak, &block);
+    assert_eq!(
+        const_hex::encode(encrypted),
+        "e0d091ff0eea8fdfc98192e62ed80ad59d865d08588df476657056b5955e97df"
+    );
+    assert_eq!(threefish256_decrypt(&key, &tweak, &encrypted), block);
+
+    let key: [u8; 64] = core::array::from_fn(|i| (i as u8).wrapping_mul(3));
+    let block: [u8; 64] = core::array::from_fn(|i| (i as u8).wrapping_mul(7));
+    assert_eq!(
+        threefish512_decrypt(&key, &tweak, &threefish512_encrypt(&key, &tweak, &block)),
+        block
+    );
+
+    let key: [u8; 128] = core::array::from_fn(|i| (i as u8).wrapping_mul(5));
+    let block: [u8; 128] = core::array::from_fn(|i| (i as u8).wrapping_mul(11));
+    assert_eq!(
+        threefish1024_decrypt(&key, &tweak, &threefish1024_encrypt(&key, &tweak, &block)),
+        block
+    );
+}
+
+#[test]
+fn crc() {
+    macro_rules! compare {
+        ($($name:ident: $ty:ty, $n:literal;)*) => {$(
+            let reference = crc::Crc::<$ty>::new(&crc::$name);
+            assert_eq!(cthash::$name.check(), crc::$name.check as u64, stringify!($name));
+            for data in TEST_DATA.iter() {
+                let value = reference.checksum(data);
+                assert_eq!(cthash::$name.value(data), value as u64, stringify!($name));
+                let bytes = cthash::$name.checksum::<$n>(data);
+                assert_eq!(bytes[..], value.to_be_bytes()[size_of::<$ty>() - $n..], stringify!($name));
+            }
+        )*};
+    }
+    compare! {
+        CRC_3_GSM: u8, 1;
+        CRC_5_USB: u8, 1;
+        CRC_8_AUTOSAR: u8, 1;
+        CRC_8_MAXIM_DOW: u8, 1;
+        CRC_8_SMBUS: u8, 1;
+        CRC_12_UMTS: u16, 2;
+        CRC_16_ARC: u16, 2;
+        CRC_16_IBM_3740: u16, 2;
+        CRC_16_IBM_SDLC: u16, 2;
+        CRC_16_KERMIT: u16, 2;
+        CRC_16_MODBUS: u16, 2;
+        CRC_16_USB: u16, 2;
+        CRC_16_XMODEM: u16, 2;
+        CRC_24_OPENPGP: u32, 3;
+        CRC_32_AUTOSAR: u32, 4;
+        CRC_32_BZIP2: u32, 4;
+        CRC_32_CKSUM: u32, 4;
+        CRC_32_ISCSI: u32, 4;
+        CRC_32_ISO_HDLC: u32, 4;
+        CRC_32_MPEG_2: u32, 4;
+        CRC_64_ECMA_182: u64, 8;
+        CRC_64_GO_ISO: u64, 8;
+        CRC_64_NVME: u64, 8;
+        CRC_64_XZ: u64, 8;
+    }
+
+    // parameters as given, masked to the width
+    const CRC_16: cthash::Crc<16> = cthash::Crc::new(0x1_8005, 0, true, true, 0);
+    assert_eq!(CRC_16.poly(), 0x8005);
+    assert_eq!(CRC_16.width(), 16);
+    assert_eq!(CRC_16.check(), cthash::CRC_16_ARC.check());
+}
+
+#[test]
+fn xxhash() {
+    use xxhash_rust::{xxh3, xxh32, xxh64};
+
+    // published hashes of the empty input
+    assert_eq!(cthash::xxh32(b"", 0), 0x02cc5d05);
+    assert_eq!(cthash::xxh64(b"", 0), 0xef46db3751d8e999);
+    assert_eq!(cthash::xxh3_64(b"", 0), 0x2d06800538d394c2);
+    assert_eq!(cthash::xxh3_128(b"", 0), 0x99aa06d3014798d86001c324468d497f);
+
+    // every length class of XXH3, including several blocks with the default secret
+    let data: [u8; 2500] = core::array::from_fn(|i| (i as u8).wrapping_mul(31) ^ (i >> 8) as u8);
+    let secret: [u8; 150] = core::array::from_fn(|i| (i as u8).wrapping_mul(7).wrapping_add(3));
+    for len in (0..=300).chain([1023, 1024, 1025, 2048, 2500]) {
+        let data = &data[..len];
+        for seed in [0, 1, 0x9e3779b97f4a7c15] {
+            assert_eq!(cthash::xxh32(data, seed as u32), xxh32::xxh32(data, seed as u32), "xxh32 {len}");
+            assert_eq!(cthash::xxh64(data, seed), xxh64::xxh64(data, seed), "xxh64 {len}");
+            assert_eq!(cthash::xxh3_64(data, seed), xxh3::xxh3_64_with_seed(data, seed), "xxh3_64 {len}");
+            assert_eq!(cthash::xxh3_128(data, seed), xxh3::xxh3_128_with_seed(data, seed), "xxh3_128 {len}");
+        }
+        assert_eq!(
+            cthash::xxh3_64_with_secret(data, &secret),
+            xxh3::xxh3_64_with_secret(data, &secret),
+            "xxh3_64 {len}"
+        );
+        assert_eq!(
+            cthash::xxh3_128_with_secret(data, &secret),
+            xxh3::xxh3_128_with_secret(data, &secret),
+            "xxh3_128 {len}"
+        );
+    }
+}
+
+#[test]
+fn siphash() {
+    use core::hash::{BuildHasher, Hash, Hasher};
+    use cthash::{SipBuildHasher13, SipBuildHasher24, SipHasher13, SipHasher24};
+
+    let key: [u8; 16] = core::array::from_fn(|i| i as u8);
+    let data: [u8; 64] = core::array::from_fn(|i| i as u8);
+
+    // SipHash paper, appendix A, and the first 128-bit reference vector
+    assert_eq!(cthash::siphash24(&key, &data[..15]), 0xa129ca6149be45e5);
+    assert_eq!(
+        cthash::siphash24_128(&key, b"").to_le_bytes(),
+        *b"\xa3\x81\x7f\x04\xba\x25\xa8\xe6\x6d\xf6\x72\x14\xc7\x55\x02\x93"
+    );
+
+    for len in 0..=data.len() {
+        let data = &data[..len];
+
+        let mut reference = siphasher::sip::SipHasher24::new_with_key(&key);
+        reference.write(data);
+        assert_eq!(cthash::siphash24(&key, data), reference.finish(), "{len}");
+        let mut reference = siphasher::sip::SipHasher13::new_with_key(&key);
+        reference.write(data);
+        assert_eq!(cthash::siphash13(&key, data), reference.finish(), "{len}");
+
+        use siphasher::sip128::Hasher128;
+        let mut reference = siphasher::sip128::SipHasher24::new_with_key(&key);
+        reference.write(data);
+        assert_eq!(cthash::siphash24_128(&key, data), reference.finish128().as_u128(), "{len}");
+        let mut reference = siphasher::sip128::SipHasher13::new_with_key(&key);
+        reference.write(data);
+        assert_eq!(cthash::siphash13_128(&key, data), reference.finish128().as_u128(), "{len}");
+
+        // streaming in pieces gives the one-shot result
+        for split in 0..=len {
+            let (a, b) = data.split_at(split);
+            let mut hasher = SipHasher24::new(&key);
+            hasher.write(a);
+            hasher.write(b);
+            assert_eq!(hasher.finish(), cthash::siphash24(&key, data), "{len} {split}");
+            let mut hasher = SipHasher13::new(&key);
+            for byte in data {
+                hasher.write_u8(*byte);
+            }
+            assert_eq!(hasher.finish(), cthash::siphash13(&key, data), "{len}");
+        }
+    }
+
+    // `Hash` impls go through the same bytes as the const functions
+    let mut expected = SipHasher24::new(&key);
+    (1u32, "key").hash(&mut expected);
+    assert_eq!(SipBuildHasher24::new(&key).hash_one((1u32, "key")), expected.finish());
+    assert_eq!(SipBuildHasher13::new(&key).hash_one("key"), cthash::siphash13(&key, b"key\xff"));
+}
+
+#[test]
+fn murmur() {
+    use core::num::NonZeroU32;
+
+    // `Utils.murmur2` test cases of the Kafka Java client, as signed integers
+    let kafka: [(&[u8], i32); 6] = [
+        (b"21", -973932308),
+        (b"foobar", -790332482),
+        (b"a-little-bit-long-string", -985981536),
+        (b"a-little-bit-longer-string", -1486304829),
+        (b"lkjh234lh9fiuh90y23oiuhsafujhadof229phr9h19h89h8", -58897971),
+        (b"abc", 479470107),
+    ];
+    for (key, expected) in kafka {
+        assert_eq!(cthash::murmur2_32(key, 0x9747b28c) as i32, expected);
+        let partitions = NonZeroU32::new(7).unwrap();
+        assert_eq!(cthash::kafka_partition(key, partitions), (expected & 0x7fffffff) as u32 % 7);
+    }
+
+    let data: [u8; 100] = core::array::from_fn(|i| (i as u8).wrapping_mul(29) ^ 0x5c);
+    for len in 0..=data.len() {
+        let data = &data[..len];
+        for seed in [0, 1, 0x9747b28c] {
+            let reference = murmur3::murmur3_32(&mut &data[..], seed).unwrap();
+            assert_eq!(cthash::murmur3_32(data, seed), reference, "{len}");
+            let reference = murmur3::murmur3_x64_128(&mut &data[..], seed).unwrap();
+            assert_eq!(cthash::murmur3_x64_128(data, seed), reference, "{len}");
+        }
+    }
+
+    // `Murmur3Token` test cases of the DataStax Python driver, the last two
+    // with tail bytes above 0x7f, which Cassandra sign-extends
+    assert_eq!(cthash::cassandra_token(b"123"), -7468325962851647638);
+    assert_eq!(cthash::cassandra_token(b"9223372036854775807"), 7162290910810015547);
+    assert_eq!(cthash::cassandra_token(&[0x10; 8]), 1446172840243228796);
+    assert_eq!(
+        cthash::cassandra_token(&[0x00, 0xff, 0x10, 0xfa, 0x99].repeat(10)),
+        5837342703291459765
+    );
+    assert_eq!(cthash::cassandra_token(&[0xfe; 8]), -8927430733708461935);
+
+    // same as the first half of the standard hash with an ASCII tail
+    for len in 0..=data.len() {
+        let key = &data[..len];
+        if key[len - len % 16..].is_ascii() {
+            assert_eq!(cthash::cassandra_token(key), cthash::murmur3_x64_128(key, 0) as u64 as i64, "{len}");
+        } else {
+            assert_ne!(cthash::cassandra_token(key), cthash::murmur3_x64_128(key, 0) as u64 as i64, "{len}");
+        }
+    }
+}
+
+#[test]
+fn fnv() {
+    // published vectors of the FNV test suite
+    assert_eq!(cthash::fnv1a_32_value(b"foobar"), 0xbf9cf968);
+    assert_eq!(cthash::fnv1_32_value(b"foobar"), 0x31f0b262);
+    assert_eq!(cthash::fnv1a_64_value(b"foobar"), 0x85944171f73967e8);
+    assert_eq!(cthash::fnv1_64_value(b"foobar"), 0x340d8765a4dda9c2);
+
+    // (FNV-1a, FNV-1) for 32, 64 and 128 bits
+    let expected = [
+        (
+            0x811c9dc5,
+            0xcbf29ce484222325,
+            0x6c62272e07bb014262b821756295c58d,
+            0x811c9dc5,
+            0xcbf29ce484222325,
+            0x6c62272e07bb014262b821756295c58d,
+        ),
+        (
+            0x1a47e90b,
+            0xe71fa2190541574b,
+            0xa68d622cec8b5822836dbc7977af7f3b,
+            0x439c2f4b,
+            0xd8dcca186bafadcb,
+            0xa68bb2a4348b5822836dbc78c6aee73b,
+        ),
+        (
+            0xccdc1355,
+            0xdd305304cdb45735,
+            0xf5c024b7317b79ef9345d4c902f1374d,
+            0xbb4e6c7d,
+            0xd3959c9ba1c9c65d,
+            0x2c97f978525d3c301281fdd98d08ab45,
+        ),
+        (
+            0x65ca6f2d,
+            0xf3692984963deb8d,
+            0x8f49aaca01e124c3ed28e47869c641a5,
+            0xbb13619d,
+            0x39a353522b642ffd,
+            0x1a70a0b0bbb1c590aa6dcc629a54d765,
+        ),
+        (
+            0x048fff90,
+            0xf3f9b7f5e7e47110,
+            0x68cce4cd885ea04239f02af30e297870,
+            0xe9c86c6e,
+            0xa8b2f3117de37ace,
+            0x185adb693e7c97844ecfa9497cb529b6,
+        ),
+        (
+            0xc36a94b9,
+            0xc8a3caf5cf572859,
+            0x68ccced78b5ea04239f02af3054ef621,
+            0xc9c5fb75,
+            0xa8afd3117de10e15,
+            0x185adb665e7c97844ecfa9497cb323ad,
+        ),
+        (
+            0x8569d985,
+            0x24c638d05c2865e5,
+            0x454e717f5ceb11a7bc4b37c352e07b4d,
+            0xa6dde905,
+            0xc2ee56404aeccc65,
+            0xbdf8b5a26e7a7e826e6b7b4e16359fcd,
+        ),
+    ];
+    for (data, (a32, a64, a128, h32, h64, h128)) in TEST_DATA.iter().zip(expected) {
+        assert_eq!(cthash::fnv1a_32_value(data), a32);
+        assert_eq!(cthash::fnv1a_64_value(data), a64);
+        assert_eq!(cthash::fnv1a_128_value(data), a128);
+        assert_eq!(cthash::fnv1_32_value(data), h32);
+        assert_eq!(cthash::fnv1_64_value(data), h64);
+        assert_eq!(cthash::fnv1_128_value(data), h128);
+
+        assert_eq!(cthash::fnv1a_32(data), a32.to_be_bytes());
+        assert_eq!(cthash::fnv1a_64(data), a64.to_be_bytes());
+        assert_eq!(cthash::fnv1a_128(data), a128.to_be_bytes());
+        assert_eq!(cthash::fnv1_32(data), h32.to_be_bytes());
+        assert_eq!(cthash::fnv1_64(data), h64.to_be_bytes());
+        assert_eq!(cthash::fnv1_128(data), h128.to_be_bytes());
+    }
+}
+
+#[test]
+fn jenkins() {
+    // self-test of lookup3.c: `hashlittle2` of an empty input with various initial values
+    assert_eq!(cthash::lookup3_hashlittle2_value(b"", 0), 0xdeadbeef_deadbeef);
+    assert_eq!(cthash::lookup3_hashlittle2_value(b"", 0xdeadbeef_00000000), 0xdeadbeef_bd5b7dde);
+    assert_eq!(cthash::lookup3_hashlittle2_value(b"", 0xdeadbeef_deadbeef), 0xbd5b7dde_9c093ccd);
+    assert_eq!(
+        cthash::lookup3_hashlittle2_value(b"Four score and seven years ago", 1) as u32,
+        0xcd628161
+    );
+    assert_eq!(
+        cthash::jenkins_oaat_value(b"The quick brown fox jumps over the lazy dog"),
+        0x519e91f5
+    );
+
+    // (one-at-a-time, lookup3 with seed 0, lookup3 with another seed)
+    let seed = 0x89abcdef_01234567;
+    let expected = [
+        (0x00000000, 0xdeadbeefdeadbeef, 0xdfd10456697cd245),
+        (0xed131f5b, 0x3c03be9e0e397631, 0xc03c3b50034d5afc),
+        (0x57fd76b5, 0x6781e0defd5cdeff, 0x15b04a5c2951b067),
+        (0x3b93f83b, 0xd43f0e98d92463e5, 0xc1a27e5e834b5cb9),
+        (0x519e91f5, 0x627c4e7964a2cd46, 0x8e3d2ab5a7773ebb),
+        (0xe5c2a24c, 0x8db5b84cbdca8d63, 0x78ae477979f1df5b),
+        (0x7d160476, 0xd4b6bbbfff31546f, 0xf7cff31de85c46a7),
+    ];
+    for (data, (oaat, lookup3, lookup3_seeded)) in TEST_DATA.iter().zip(expected) {
+        assert_eq!(cthash::jenkins_oaat_value(data), oaat);
+        assert_eq!(cthash::jenkins_oaat(data), oaat.to_be_bytes());
+        assert_eq!(cthash::lookup3_hashlittle2_value(data, 0), lookup3);
+        assert_eq!(cthash::lookup3_hashlittle2_value(data, seed), lookup3_seeded);
+        assert_eq!(cthash::lookup3_hashlittle2(data, seed), lookup3_seeded.to_be_bytes());
+    }
+
+    // lengths around the 12-byte blocks
+    let data: [u8; 40] = core::array::from_fn(|i| (i as u8).wrapping_mul(37).wrapping_add(11));
+    let expected: [u64; 41] = [
+        0xdfd10456697cd245,
+        0x85871a8bb38fc76d,
+        0x2906ca2f94e78882,
+        0x587e72bfbbf2ce1e,
+        0x0c24bbe0e34cef2a,
+        0x132670d21f01e2c4,
+        0x118b15b807751b50,
+        0x79fd497076ccffef,
+        0xce94c83cde8801ba,
+        0x2c14a706a38696e2,
+        0x7002d77da9e4802b,
+        0xb17f26542fcc11f7,
+        0x714910631d7a44b4,
+        0x7ff107a976a056da,
+        0xd8c786bee8762d7f,
+        0x9511aec12770e899,
+        0xcec70983b2c0fb91,
+        0xf082bd436333a192,
+        0x44262b1631001018,
+        0x436fe26e9515c25f,
+        0x3b5a1f684ba72ff6,
+        0x704414c1901cff90,
+        0xde3c49d25257f1b1,
+        0xc301a1b577cf5dd9,
+        0x097e9b06a6bc7825,
+        0x421c0cdb1ccf37fa,
+        0xc4a9dcba2f5636c3,
+        0x64be6001c69493f8,
+        0xb4564c657b4853e8,
+        0xd3f366ef87cf266c,
+        0xfc2fef8f792f13ae,
+        0x2c7d48ec4b262983,
+        0x14f82155e384b894,
+        0xf981b7039402dae2,
+        0xfe79fd756ac1fd38,
+        0x8803a77bdb777163,
+        0xac54b144e57a60c9,
+        0xf1ecc2c57116161d,
+        0x6975db7d3d667847,
+        0xad61ca97d51fbc37,
+        0xc8b61452a8215a00,
+    ];
+    for (len, expected) in expected.into_iter().enumerate() {
+        assert_eq!(cthash::lookup3_hashlittle2_value(&data[..len], seed), expected, "{len}");
+    }
+}
+
+#[test]
+fn cityhash() {
+    // input generator of city-test.cc, entry `i` hashes `data[i * i..][..i]`
+    let mut data = vec![0u8; 1 << 17];
+    let (mut a, mut b) = (9u64, 777u64);
+    for (i, out) in data.iter_mut().enumerate() {
+        a = a.wrapping_add(b);
+        b = b.wrapping_add(a);
+        a = (a ^ (a >> 41)).wrapping_mul(0xc3a5c85c97cb3127);
+        b = (b ^ (b >> 41)).wrapping_mul(0xc3a5c85c97cb3127).wrapping_add(i as u64);
+        *out = (b >> 37) as u8;
+    }
+
+    // (i, CityHash64, low and high word of CityHash128) rows of the city-test.cc table
+    let expected: [(usize, u64, u64, u64); 33] = [
+        (0, 0x9ae16a3b2f90404f, 0x3df09dfc64c09a2b, 0x3cb540c392e51e29),
+        (1, 0x541150e87f415e96, 0xc3cdc41e1df33513, 0x2c138ff2596d42f6),
+        (2, 0x0f3786a4b25827c1, 0x3149ba1dac77270d, 0x070e2e076e30703c),
+        (3, 0xef923a7a1af78eab, 0x2193fb7620cbf23b, 0x8b6a8ff06cda8302),
+        (4, 0x11df592596f41d88, 0x4d09e42f09cc3495, 0x666236631b9f253b),
+        (5, 0x831f448bdc5600b3, 0x0dc07df53b949c6b, 0xd2b11b2081aeb002),
+        (7, 0x1b5a063fb4c7f9f1, 0xb140a02ef5c97712, 0xb7d00ef065b51b33),
+        (8, 0xa0f10149a0e538d6, 0x26b6689960ccf81d, 0x55f23b27bb9efd94),
+        (9, 0xfb8d9c70660b910b, 0x98ec31113e5e35d2, 0x5e4aeb853f1b9aa7),
+        (12, 0xe3f6828b6017086d, 0xd93251758985ee6c, 0x32a9e9f82ba2a932),
+        (15, 0x44473e03be306c88, 0x3bab18b164396783, 0x047e385ff9d4c06f),
+        (16, 0x03ead5f21d344056, 0xac059617f5906673, 0x94d50d3dcd3069a7),
+        (17, 0x6abbfde37ee03b5b, 0xa4375590b8ae7c82, 0x168fd42f9ecae4ff),
+        (24, 0x36a097aa49519d97, 0x6ee1f817ce0b7aee, 0xe9dcb3507f0596ca),
+        (31, 0x55bdb0e71e3edebd, 0xb2e25964cd409117, 0xa010599d6287c412),
+        (32, 0x0782fa1b08b475e7, 0x9a8c431f500ef06e, 0xd848581a580b6c12),
+        (33, 0xc5dc19b876d37a80, 0x7870765b470b2c5d, 0x078a9103ff960d82),
+        (48, 0x584f28543864844f, 0x7a0b6dbab9a14e69, 0xc6d0a9d6b0e31ac4),
+        (63, 0x12807833c463737c, 0x910b610de7a967bf, 0x801bc862120f6bf5),
+        (64, 0xe88419922b87176f, 0xd1d44fe99451ef72, 0xec951ba8e51e3545),
+        (65, 0x105191e0ec8f7f60, 0xd3e86ac4f5eccfa4, 0x0e5399df2b106ca1),
+        (96, 0x930380a3741e862a, 0x07e7f61684080106, 0x837ace9794582976),
+        (127, 0xcbaa3cb8f64f54e0, 0x85b8e53f22e19507, 0xbb57137739ca486b),
+        (128, 0xb2e23e8116c2ba9f, 0xadc52dddb76f6e5e, 0x4aad4e925a962b68),
+        (129, 0x8aa77f52d7868eb9, 0x0ce030d15b5fe2f4, 0x86b4a7a0780c2431),
+        (160, 0xa90d81060932dbb0, 0xdd52fc14c8dd3143, 0x1bc7508516e40628),
+        (191, 0x053c1a66d0b13003, 0x57466046cf6896ed, 0x8ac37e0e8b25b0c6),
+        (192, 0x0d3a2efec0f047e9, 0xc2dcc9758c910171, 0xcb5cddaeff4ddb40),
+        (193, 0x43c64d7484f7f9b2, 0x3ee84d3d5b4ca00b, 0x5cbc6d701894c3f9),
+        (255, 0x915263c671b28809, 0xd17c928c5342477f, 0x745130b795254ad5),
+        (256, 0x2b67cdd38c307a5e, 0x6531c1fe32bcb417, 0x8c970d8df8cdbeb4),
+        (257, 0x2d107419073b9cd0, 0xffe319654c8e7ebc, 0x6a67b8f13ead5a72),
+        (298, 0x74c0b8a6821faafe, 0x967e970df9673d2a, 0xd465247cffa415c0),
+    ];
+    for (i, city64, low, high) in expected {
+        let input = &data[i * i..][..i];
+        assert_eq!(cthash::cityhash64(input), city64, "{i}");
+        assert_eq!(cthash::cityhash128(input), (high as u128) << 64 | low as u128, "{i}");
+    }
+}
+
+#[test]
+fn farmhash() {
+    // `SELECT FARM_FINGERPRINT('')` in BigQuery
+    assert_eq!(cthash::farmhash_fingerprint64(b"") as i64, -7286425919675154353);
+
+    let data: [u8; 300] = core::array::from_fn(|i| (i as u8).wrapping_mul(97) ^ 0x3a);
+    for len in 0..=data.len() {
+        // the `farmhash` crate runs one 64-byte block too many on these
+        // lengths, farmhash.cc stops after `(len - 1) / 64` blocks
+        if len > 64 && len.is_multiple_of(64) {
+            continue;
+        }
+        let data = &data[..len];
+        assert_eq!(cthash::farmhash_fingerprint64(data), farmhash::fingerprint64(data), "{len}");
+    }
+
+    // `farmhashna::Hash64` of farmhash.cc on its self-test input, which is the
+    // generator of city-test.cc, entry `len` hashing `data[len * len..][..len]`
+    let mut data = vec![0u8; 256 * 256 + 256];
+    let (mut a, mut b) = (9u64, 777u64);
+    for (i, byte) in data.iter_mut().enumerate() {
+        a = a.wrapping_add(b);
+        b = b.wrapping_add(a);
+        a = (a ^ (a >> 41)).wrapping_mul(0xc3a5c85c97cb3127);
+        b = (b ^ (b >> 41)).wrapping_mul(0xc3a5c85c97cb3127).wrapping_add(i as u64);
+        *byte = (b >> 37) as u8;
+    }
+    let expected = [
+        (127, 11027467577531522736),
+        (128, 17629800649466220701),
+        (129, 15345954539186508726),
+        (192, 13338803518511360161),
+        (256, 9210440767890242563),
+    ];
+    for (len, hash) in expected {
+        assert_eq!(cthash::farmhash_fingerprint64(&data[len * len..][..len]), hash, "{len}");
+    }
+}
+
+#[test]
+fn highwayhash() {
+    // `kExpected64`, `kExpected128` and `kExpected256` of the reference
+    // implementation, key and input bytes 0, 1, 2, ...
+    let key = [0x0706050403020100, 0x0f0e0d0c0b0a0908, 0x1716151413121110, 0x1f1e1d1c1b1a1918];
+    let data: [u8; 65] = core::array::from_fn(|i| i as u8);
+
+    let expected64: [u64; 65] = [
+        0x907a56de22c26e53,
+        0x7eab43aac7cddd78,
+        0xb8d0569ab0b53d62,
+        0x5c6befab8a463d80,
+        0xf205a46893007eda,
+        0x2b8a1668e4a94541,
+        0xbd4ccc325befca6f,
+        0x4d02ae1738f59482,
+        0xe1205108e55f3171,
+        0x32d2644ec77a1584,
+        0xf6e10acdb103a90b,
+        0xc3bbf4615b415c15,
+        0x243cc2040063fa9c,
+        0xa89a58ce65e641ff,
+        0x24b031a348455a23,
+        0x40793f86a449f33b,
+        0xcfab3489f97eb832,
+        0x19fe67d2c8c5c0e2,
+        0x04dd90a69c565cc2,
+        0x75d9518e2371c504,
+        0x38ad9b1141d3dd16,
+        0x0264432ccd8a70e0,
+        0xa9db5a6288683390,
+        0xd7b05492003f028c,
+        0x205f615aea59e51e,
+        0xeee0c89621052884,
+        0x1bfc1a93a7284f4f,
+        0x512175b5b70da91d,
+        0xf71f8976a0a2c639,
+        0xae093fef1f84e3e7,
+        0x22ca92b01161860f,
+        0x9fc7007ccf035a68,
+        0xa0c964d9ecd580fc,
+        0x2c90f73ca03181fc,
+        0x185cf84e5691eb9e,
+        0x4fc1f5ef2752aa9b,
+        0xf5b7391a5e0a33eb,
+        0xb9b84b83b4e96c9c,
+        0x5e42fe712a5cd9b4,
+        0xa150f2f90c3f97dc,
+        0x7fa522d75e2d637d,
+        0x181ad0cc0dffd32b,
+        0x3889ed981e854028,
+        0xfb4297e8c586ee2d,
+        0x6d064a45bb28059c,
+        0x90563609b3ec860c,
+        0x7aa4fce94097c666,
+        0x1326bac06b911e08,
+        0xb926168d2b154f34,
+        0x9919848945b1948d,
+        0xa2a98fc534825ebe,
+        0xe9809095213ef0b6,
+        0x582e5483707bc0e9,
+        0x086e9414a88a6af5,
+        0xee86b98d20f6743d,
+        0xf89b7ff609b1c0a7,
+        0x4c7d9cc19e22c3e8,
+        0x9a97005024562a6f,
+        0x5dd41cf423e6ebef,
+        0xdf13609c0468e227,
+        0x6e0da4f64188155a,
+        0xb755ba4b50d7d4a1,
+        0x887a3484647479bd,
+        0xab8eebe9bf2139a0,
+        0x75542c5d4cd2a6ff,
+    ];
+    for (len, hash) in expected64.into_iter().enumerate() {
+        assert_eq!(cthash::highwayhash64(&key, &data[..len]), hash, "{len}");
+    }
+
+    let expected128 =
+        [[0x0fed268f9d8ffec7, 0x33565e767f093e6f], [0xd6b0a8893681e7a8, 0xdc291df9eb9cdcb4], [0x3d15ad265a16da04, 0x78085638dc32e868]];
+    for (len, hash) in expected128.into_iter().enumerate() {
+        assert_eq!(cthash::highwayhash128(&key, &data[..len]), hash, "{len}");
+    }
+
+    let expected256 = [
+        [0xdd44482ac2c874f5, 0xd946017313c7351f, 0xb3aebeccb98714ff, 0x41da233145751df4],
+        [0xedb941bce45f8254, 0xe20d44ef3dcac60f, 0x72651b9bcb324a47, 0x2073624cb275e484],
+        [0x3fdff9df24afe454, 0x11c4bf1a1b0ae873, 0x115169cc6922597a, 0x1208f6590d33b42c],
+    ];
+    for (len, hash) in expected256.into_iter().enumerate() {
+        assert_eq!(cthash::highwayhash256(&key, &data[..len]), hash, "{len}");
+    }
+}
+
+#[test]
+fn wyhash() {
+    // `test_vector.cpp` inputs of wyhash, hashed with the seed counting up
+    let expected: [(&[u8], u64); 7] = [
+        (b"", 0x93228a4de0eec5a2),
+        (b"a", 0xc5bac3db178713c4),
+        (b"abc", 0xa97f2f7b1d9b3314),
+        (b"message digest", 0x786d1f1df3801df4),
+        (b"abcdefghijklmnopqrstuvwxyz", 0xdca5a8138ad37c87),
+        (
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+            0xb9e734f117cfaf70,
+        ),
+        (
+            b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+            0x6cc5eab49a92d617,
+        ),
+    ];
+    for (seed, (data, hash)) in expected.into_iter().enumerate() {
+        assert_eq!(cthash::wyhash(seed as u64, data), hash);
+    }
+
+    // evaluated by the compiler and at runtime
+    const KEY: u64 = cthash::wyhash(7, b"perfect hash key");
+    assert_eq!(cthash::wyhash(7, core::hint::black_box(b"perfect hash key")), KEY);
+}
+
+#[test]
+fn rapidhash() {
+    use rapidhash::v3::{RapidSecrets, rapidhash_v3_seeded};
+
+    let data: [u8; 500] = core::array::from_fn(|i| (i as u8).wrapping_mul(113) ^ 0x6d);
+    for len in 0..=data.len() {
+        let data = &data[..len];
+        for seed in [0, 1, 0x123456789abcdef] {
+            let reference = rapidhash_v3_seeded(data, &RapidSecrets::seed_cpp(seed));
+            assert_eq!(cthash::rapidhash(seed, data), reference, "{len}");
+        }
+    }
+
+    const KEY: u64 = cthash::rapidhash(7, b"perfect hash key");
+    assert_eq!(cthash::rapidhash(7, core::hint::black_box(b"perfect hash key")), KEY);
+}
+
+#[test]
+fn digest() {
+    use cthash::{Digest, ParseDigestError};
+
+    const H: Digest<20> = Digest::new(cthash::sha1(b"abc"));
+    const EXPECTED: Digest<20> = Digest::from_hex("a9993e364706816aba3e25717850c26c9cd0d89d");
+    const { assert!(H.eq(&EXPECTED)) };
+    const { assert!(!H.eq(&Digest::new([0; 20]))) };
+    assert_eq!(H, EXPECTED);
+    assert_eq!(H, cthash::sha1(b"abc"));
+
+    assert_eq!(H.to_string(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+    assert_eq!(format!("{H:x}"), "a9993e364706816aba3e25717850c26c9cd0d89d");
+    assert_eq!(format!("{H:#x}"), "0xa9993e364706816aba3e25717850c26c9cd0d89d");
+    assert_eq!(format!("{H:X}"), "A9993E364706816ABA3E25717850C26C9CD0D89D");
+    assert_eq!(format!("{H:?}"), "Digest(a9993e364706816aba3e25717850c26c9cd0d89d)");
+    assert_eq!(format!("{}", Digest::<0>::new([])), "");
+
+    let short = Digest::new([0xab, 0xcd]);
+    assert_eq!(format!("{short:8}|"), "abcd    |");
+    assert_eq!(format!("{short:>8}|"), "    abcd|");
+    assert_eq!(format!("{short:*^9x}|"), "**abcd***|");
+    assert_eq!(format!("{short:>#8X}|"), "  0xABCD|");
+    assert_eq!(format!("{short:2}|"), "abcd|");
+
+    assert_eq!("A9993E364706816ABA3E25717850C26C9CD0D89D".parse(), Ok(H));
+    assert_eq!("0xa9993e364706816aba3e25717850c26c9cd0d89d".parse(), Ok(H));
+    assert_eq!("a9993e36".parse::<Digest<20>>(), Err(ParseDigestError::InvalidLength));
+    assert_eq!("0x".parse::<Digest<1>>(), Err(ParseDigestError::InvalidLength));
+    assert_eq!("0X00".parse::<Digest<1>>(), Err(ParseDigestError::InvalidLength));
+    assert_eq!("g0".parse::<Digest<1>>(), Err(ParseDigestError::InvalidCharacter));
+    assert_eq!(" 0".parse::<Digest<1>>(), Err(ParseDigestError::InvalidCharacter));
+    assert_eq!("é".parse::<Digest<1>>(), Err(ParseDigestError::InvalidCharacter));
+    assert_eq!("".parse::<Digest<0>>(), Ok(Digest::new([])));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn digest_serde() {
+    use cthash::Digest;
+    use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_tokens};
+
+    let digest = Digest::new(cthash::md5(b""));
+    assert_tokens(&digest, &[Token::Str("d41d8cd98f00b204e9800998ecf8427e")]);
+    assert_de_tokens(&digest, &[Token::String("0xD41D8CD98F00B204E9800998ECF8427E")]);
+    assert_de_tokens_error::<Digest<16>>(&[Token::Str("d41d8cd9")], "hex digest has the wrong length");
+    assert_de_tokens_error::<Digest<16>>(&[Token::U8(0)], "invalid type: integer `0`, expected a hex string of 16 bytes");
+}
+
+#[test]
+fn encoding() {
+    use cthash::encoding::*;
+
+    // RFC 4648 test vectors
+    macro_rules! rfc4648 {
+        ($input:literal, $hex:literal, $base64:literal, $base32:literal, $base32hex:literal) => {
+            assert_eq!(hex($input), $hex.to_lowercase().as_str());
+            assert_eq!(hex_upper($input), $hex);
+            assert_eq!(base64($input), $base64);
+            assert_eq!(base64url($input), $base64);
+            assert_eq!(base64_nopad($input), $base64.trim_end_matches('='));
+            assert_eq!(base64url_nopad($input), $base64.trim_end_matches('='));
+            assert_eq!(base32($input), $base32);
+            assert_eq!(base32_nopad($input), $base32.trim_end_matches('='));
+            assert_eq!(base32hex($input), $base32hex);
+            assert_eq!(base32hex_nopad($input), $base32hex.trim_end_matches('='));
+        };
+    }
+    rfc4648!(b"", "", "", "", "");
+    rfc4648!(b"f", "66", "Zg==", "MY======", "CO======");
+    rfc4648!(b"fo", "666F", "Zm8=", "MZXQ====", "CPNG====");
+    rfc4648!(b"foo", "666F6F", "Zm9v", "MZXW6===", "CPNMU===");
+    rfc4648!(b"foob", "666F6F62", "Zm9vYg==", "MZXW6YQ=", "CPNMUOG=");
+    rfc4648!(b"fooba", "666F6F6261", "Zm9vYmE=", "MZXW6YTB", "CPNMUOJ1");
+    rfc4648!(b"foobar", "666F6F626172", "Zm9vYmFy", "MZXW6YTBOI======", "CPNMUOJ1E8======");
+
+    // the two base64 alphabets differ in their last characters
+    assert_eq!(base64(&[0xfb, 0xff]), "+/8=");
+    assert_eq!(base64url(&[0xfb, 0xff]), "-_8=");
+    assert_eq!(base64url_nopad(&[0xfb, 0xff]), "-_8");
+
+    // base58_encode_decode.json of Bitcoin Core
+    macro_rules! base58 {
+        ($hex:literal, $n:literal, $expected:literal) => {
+            let input = const_hex::decode_to_array::<_, $n>($hex).unwrap();
+            assert_eq!(base58(&input), $expected);
+        };
+    }
+    base58!("", 0, "");
+    base58!("61", 1, "2g");
+    base58!("626262", 3, "a3gV");
+    base58!("636363", 3, "aPEr");
+    base58!("73696d706c792061206c6f6e6720737472696e67", 20, "2cFupjhnEsSn59qHXstmK2ffpLv2");
+    base58!(
+        "00eb15231dfceb60925886b67d065299925915aeb172c06647",
+        25,
+        "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L"
+    );
+    base58!("516b6fcd0f", 5, "ABnLTmg");
+    base58!("bf4f89001e670274dd", 9, "3SEo3LWLoPntC");
+    base58!("572e4794", 4, "3EFU7m");
+    base58!("ecac89cad93923c02321", 10, "EJDM8drfXA6uyA");
+    base58!("10c8511e", 4, "Rt5zm");
+    base58!("00000000000000000000", 10, "1111111111");
+    // the longest encoding of a byte
+    assert_eq!(base58(&[0xff]), "5Q");
+    assert_eq!(base58(&[0xff; 64]).len(), 88);
+
+    // sized from the digest, encoded at compile time
+    const H: Base64<32> = base64url_nopad(&cthash::sha2_256(b"abc"));
+    const S: &str = H.as_str();
+    assert_eq!(S, "ungWv48Bz-pBQUDeXa4iI7ADYaOWF3qctBD_YfIAFa0");
+    assert_eq!(H.len(), 43);
+    assert_eq!(format!("{H}"), S);
+    assert_eq!(format!("{H:?}"), format!("{S:?}"));
+    assert!(base32::<0>(&[]).is_empty());
+}
+
+#[test]
+fn assert_digest() {
+    cthash::assert_digest!(cthash::md5, include_bytes!("../LICENSE-MIT"), "778ed62b0e3dbfb5ed0c3d270f64308a");
+    cthash::assert_digest!(
+        cthash::sha2_256,
+        include_bytes!("../LICENSE-MIT"),
+        "0x081EEB6D67A6CA1335D16C8BCE02F3CC7E690FBF9FA13DF9169AFA0E0EE53983",
+    );
+    cthash::assert_digest!(
+        cthash::sha3_256,
+        b"abc",
+        "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+    );
+
+    assert_eq!(
+        cthash::encoding::__digest_mismatch(&cthash::sha1(b"abc")),
+        "digest mismatch, the input hashes to a9993e364706816aba3e25717850c26c9cd0d89d"
+    );
+}
+
+#[test]
+fn hash_algorithm() {
+    use cthash::*;
+
+    fn hmac<H: HashAlgorithm>(key: &[u8], message: &[u8]) -> H::Output {
+        assert!(key.len() <= H::BLOCK_LEN);
+        let pad = |byte: u8| {
+            (0..H::BLOCK_LEN)
+                .map(|i| key.get(i).copied().unwrap_or(0) ^ byte)
+                .collect::<Vec<_>>()
+        };
+        let inner = H::hash(&[pad(0x36).as_slice(), message].concat());
+        H::hash(&[pad(0x5c).as_slice(), inner.as_ref()].concat())
+    }
+
+    fn check<H: HashAlgorithm>(name: &str, multihash: u64, oid_tail: u8, hmac_hex: &str) {
+        assert_eq!(H::NAME, name);
+        assert_eq!(H::MULTIHASH, Some(multihash));
+        let oid = H::OID.unwrap();
+        assert_eq!(oid.last(), Some(&oid_tail));
+        assert_eq!(oid.len(), oid[1] as usize + 2);
+        assert_eq!(H::hash(b"").as_ref().len(), H::OUTPUT_LEN);
+        assert_eq!(const_hex::encode(hmac::<H>(b"Jefe", b"what do ya want for nothing?")), hmac_hex);
+    }
+
+    // RFC 4231 test case 2, and the same key and message for the other algorithms
+    check::<Md5>("MD5", 0xd5, 0x05, "750c783e6ab0b503eaa86e310a5db738");
+    check::<Sha256>(
+        "SHA-256",
+        0x12,
+        0x01,
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+    );
+    check::<Sha512>(
+        "SHA-512",
+        0x13,
+        0x03,
+        "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+    );
+    check::<Sha3_512>(
+        "SHA3-512",
+        0x14,
+        0x0a,
+        "5a4bfeab6166427c7a3647b747292b8384537cdb89afb3bf5665e4c5e709350b287baec921fd7ca0ee7a0c31d022a95e1fc92ba9d77df883960275beb4e62024",
+    );
+
+    const H: [u8; 28] = Sha3_224::hash(b"abc");
+    assert_eq!(H, sha3_224(b"abc"));
+    assert_eq!(<Md4 as HashAlgorithm>::hash(b"abc"), md4(b"abc"));
+    assert_eq!(<Sha1 as HashAlgorithm>::hash(b"abc"), sha1(b"abc"));
+    assert_eq!(<Sha224 as HashAlgorithm>::hash(b"abc"), sha2_224(b"abc"));
+    assert_eq!(<Sha384 as HashAlgorithm>::hash(b"abc"), sha2_384(b"abc"));
+    assert_eq!(<Sha3_256 as HashAlgorithm>::hash(b"abc"), sha3_256(b"abc"));
+    assert_eq!(<Sha3_384 as HashAlgorithm>::hash(b"abc"), sha3_384(b"abc"));
+    assert_eq!(Sha1::OID, Some([0x06, 0x05, 0x2b, 0x0e, 0x03, 0x02, 0x1a].as_slice()));
+
+    // every marker implements the trait, metadata without an assignment is `None`
+    assert_eq!((Keccak256::MULTIHASH, Keccak256::OID), (Some(0x1b), None));
+    assert_eq!(
+        Md2::OID,
+        Some([0x06, 0x08, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x02].as_slice())
+    );
+    assert_eq!((Tiger2::MULTIHASH, AsconHash256::OID), (None, None));
+    let reference = {
+        use hmac::{Mac, SimpleHmac};
+        let mut mac = SimpleHmac::<sha3::Keccak256>::new_from_slice(b"Jefe").unwrap();
+        mac.update(b"what do ya want for nothing?");
+        <[u8; 32]>::from(mac.finalize().into_bytes())
+    };
+    assert_eq!(hmac::<Keccak256>(b"Jefe", b"what do ya want for nothing?"), reference);
+    assert_eq!(<Tiger as HashAlgorithm>::hash(b"abc"), tiger(b"abc"));
+    assert_eq!((Sha224::BLOCK_LEN, Sha3_256::BLOCK_LEN, Sha3_384::BLOCK_LEN), (64, 136, 104));
+
+    // the algorithms are plain values, e.g. for keys of a lookup table
+    fn marker<H: HashAlgorithm + Copy + Default + Eq + std::hash::Hash>() -> H {
+        H::default()
+    }
+    const ALGORITHM: Sha256 = Sha256;
+    let copy = ALGORITHM;
+    assert_eq!(copy, marker::<Sha256>());
+    assert_eq!(std::collections::HashSet::from([ALGORITHM, copy]).len(), 1);
+}
+
+#[test]
+fn hash_kind() {
+    use cthash::{HashKind, ParseHashKindError, hash};
+
+    for kind in HashKind::ALL {
+        assert_eq!(kind.name().parse(), Ok(kind));
+        assert_eq!(kind.to_string().to_uppercase().parse(), Ok(kind));
+        assert_eq!(hash(kind, b"abc").as_bytes().len(), kind.output_len());
+        assert_eq!(hash(kind, b"abc").kind(), kind);
+    }
+
+    for (name, kind) in [
+        ("sha-1", HashKind::Sha1),
+        ("SHA-256", HashKind::Sha256),
+        ("sha2_256", HashKind::Sha256),
+        ("sha2-512", HashKind::Sha512),
+        ("sha3_256", HashKind::Sha3_256),
+        ("SHA3-512", HashKind::Sha3_512),
+        ("keccak256", HashKind::Keccak256),
+        ("Ascon-Hash256", HashKind::AsconHash256),
+        ("BLAKE2b-512", HashKind::Blake2b512),
+    ] {
+        assert_eq!(name.parse(), Ok(kind), "{name}");
+    }
+    for name in ["", "sha", "sha2", "sha3", "sha-3-256x", "md6", "blake2s"] {
+        assert_eq!(name.parse::<HashKind>(), Err(ParseHashKindError), "{name}");
+    }
+
+    assert_eq!(hash(HashKind::Md2, b"abc").as_bytes(), cthash::md2(b"abc"));
+    assert_eq!(hash(HashKind::Sha256, b"abc").as_bytes(), cthash::sha2_256(b"abc"));
+    assert_eq!(hash(HashKind::Sha3_512, b"abc").as_bytes(), cthash::sha3_512(b"abc"));
+    assert_eq!(hash(HashKind::Keccak256, b"abc").as_bytes(), cthash::keccak_256(b"abc"));
+    assert_eq!(hash(HashKind::Tiger2, b"abc").as_bytes(), cthash::tiger2(b"abc"));
+
+    const H: cthash::DynDigest = hash(HashKind::Sha1, b"abc");
+    assert_eq!(H.to_string(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+    assert_eq!(format!("{H:#X}"), "0xA9993E364706816ABA3E25717850C26C9CD0D89D");
+    assert_eq!(format!("{H:?}"), "DynDigest(sha1, a9993e364706816aba3e25717850c26c9cd0d89d)");
+    assert_eq!(format!("{:>12}|", hash(HashKind::Md5, b"abc")).len(), 33);
+    assert_eq!(
+        format!("{H:_<44x}|"),
+        format!("{:_<44x}|", cthash::Digest::new(cthash::sha1(b"abc")))
+    );
+}
+
+#[test]
+fn incremental() {
+    use cthash::*;
+
+    let data: Vec<u8> = (0..600u32).map(|i| (i * 7 + 3) as u8).collect();
+
+    macro_rules! check {
+        ($hasher:ty, $hash:path) => {
+            for len in [0, 1, 55, 56, 63, 64, 65, 71, 72, 103, 104, 111, 112, 127, 128, 129, 135, 136, 143, 144, 145, 300, 600] {
+                let input = &data[..len];
+                for step in [1, 3, 64, 71, 200] {
+                    let mut hasher = <$hasher>::new();
+                    input.chunks(step).for_each(|chunk| hasher.update(chunk));
+                    assert_eq!(
+                        hasher.finalize(),
+                        $hash(input),
+                        "{} of {len} bytes in steps of {step}",
+                        stringify!($hasher)
+                    );
+                }
+                let mut hasher = <$hasher>::default();
+                hasher.update(&[]);
+                hasher.update(input);
+                hasher.update(&[]);
+                assert_eq!(hasher.finalize(), $hash(input));
+            }
+        };
+    }
+
+    check!(Md4Hasher, md4);
+    check!(Md5Hasher, md5);
+    check!(Sha1Hasher, sha1);
+    check!(Sha224Hasher, sha2_224);
+    check!(Sha256Hasher, sha2_256);
+    check!(Sha384Hasher, sha2_384);
+    check!(Sha512Hasher, sha2_512);
+    check!(Sha3_224Hasher, sha3_224);
+    check!(Sha3_256Hasher, sha3_256);
+    check!(Sha3_384Hasher, sha3_384);
+    check!(Sha3_512Hasher, sha3_512);
+    check!(Keccak224Hasher, keccak_224);
+    check!(Keccak256Hasher, keccak_256);
+    check!(Keccak384Hasher, keccak_384);
+    check!(Keccak512Hasher, keccak_512);
+    check!(Md2Hasher, md2);
+    check!(TigerHasher, tiger);
+    check!(Tiger2Hasher, tiger2);
+    check!(AsconHash256Hasher, ascon_hash256);
+    check!(Blake2b512Hasher, blake2b);
+
+    for kind in HashKind::ALL {
+        let mut hasher = DynHasher::new(kind);
+        assert_eq!(hasher.kind(), kind);
+        data.chunks(37).for_each(|chunk| hasher.update(chunk));
+        assert_eq!(hasher.finalize(), hash(kind, &data), "{kind}");
+    }
+
+    const H: [u8; 32] = {
+        let mut hasher = Keccak256Hasher::new();
+        hasher.update(b"transfer(address,");
+        hasher.update(b"uint256)");
+        hasher.finalize()
+    };
+    assert_eq!(H[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn digest_traits() {
+    use hmac::{Hmac, Mac, SimpleHmac};
+    use sha2::digest::{Digest, FixedOutputReset, Reset};
+
+    let data: Vec<u8> = (0..600u32).map(|i| (i * 13 + 1) as u8).collect();
+
+    fn check<A: Digest + FixedOutputReset + Reset, B: Digest>(data: &[u8]) {
+        for len in [0, 1, 55, 64, 111, 128, 136, 200, 600] {
+            let mut a = A::new();
+            data[..len].chunks(17).for_each(|chunk| Digest::update(&mut a, chunk));
+            assert_eq!(a.finalize_reset()[..], B::digest(&data[..len])[..]);
+            Digest::update(&mut a, b"stale");
+            Reset::reset(&mut a);
+            Digest::update(&mut a, &data[..len]);
+            assert_eq!(Digest::finalize(a)[..], B::digest(&data[..len])[..]);
+        }
+    }
+
+    check::<cthash::Md4Hasher, md4::Md4>(&data);
+    check::<cthash::Md5Hasher, md5::Md5>(&data);
+    check::<cthash::Sha1Hasher, sha1::Sha1>(&data);
+    check::<cthash::Sha224Hasher, sha2::Sha224>(&data);
+    check::<cthash::Sha256Hasher, sha2::Sha256>(&data);
+    check::<cthash::Sha384Hasher, sha2::Sha384>(&data);
+    check::<cthash::Sha512Hasher, sha2::Sha512>(&data);
+    check::<cthash::Sha3_224Hasher, sha3::Sha3_224>(&data);
+    check::<cthash::Sha3_256Hasher, sha3::Sha3_256>(&data);
+    check::<cthash::Sha3_384Hasher, sha3::Sha3_384>(&data);
+    check::<cthash::Sha3_512Hasher, sha3::Sha3_512>(&data);
+    check::<cthash::Keccak224Hasher, sha3::Keccak224>(&data);
+    check::<cthash::Keccak256Hasher, sha3::Keccak256>(&data);
+    check::<cthash::Keccak384Hasher, sha3::Keccak384>(&data);
+    check::<cthash::Keccak512Hasher, sha3::Keccak512>(&data);
+    check::<cthash::Blake2b512Hasher, blake2::Blake2b512>(&data);
+
+    // HMAC with a key longer than the block, hashed first
+    let key = [0xaa; 131];
+    let mut ours = SimpleHmac::<cthash::Sha256Hasher>::new_from_slice(&key).unwrap();
+    let mut theirs = Hmac::<sha2::Sha256>::new_from_slice(&key).unwrap();
+    ours.update(&data);
+    theirs.update(&data);
+    assert_eq!(ours.finalize().into_bytes(), theirs.finalize().into_bytes());
+
+    let mut ours = SimpleHmac::<cthash::Sha3_512Hasher>::new_from_slice(b"key").unwrap();
+    let mut theirs = Hmac::<sha3::Sha3_512>::new_from_slice(b"key").unwrap();
+    ours.update(&data);
+    theirs.update(&data);
+    assert_eq!(ours.finalize().into_bytes(), theirs.finalize().into_bytes());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn io_adapters() {
+    use cthash::{DynHasher, HashKind, hash, hash_reader};
+    use std::io::{self, Read};
+
+    let data: Vec<u8> = (0..10000u32).map(|i| (i * 31 + 7) as u8).collect();
+
+    let mut hasher = cthash::Sha256Hasher::new();
+    io::copy(&mut &data[..], &mut hasher).unwrap();
+    assert_eq!(hasher.finalize(), cthash::sha2_256(&data));
+
+    let mut hasher = DynHasher::new(HashKind::Sha3_384);
+    io::copy(&mut &data[..], &mut hasher).unwrap();
+    assert_eq!(hasher.finalize(), hash(HashKind::Sha3_384, &data));
+
+    // short reads with interruptions in between
+    struct Choppy<'a>(&'a [u8], bool);
+
+    impl Read for Choppy<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.1 = !self.1;
+            if self.1 {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            let n = buf.len().min(self.0.len()).min(1000);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    for kind in [HashKind::Md5, HashKind::Sha1, HashKind::Sha512, HashKind::Sha3_256] {
+        assert_eq!(hash_reader(kind, Choppy(&data, false)).unwrap(), hash(kind, &data));
+    }
+    assert_eq!(hash_reader(HashKind::Sha256, io::empty()).unwrap(), hash(HashKind::Sha256, b""));
+
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    assert_eq!(hash_reader(HashKind::Md5, Broken).unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn build_digests() {
+    use cthash::HashKind;
+    use cthash::build::{emit_digests, write_digests};
+
+    let dir = std::env::temp_dir().join(format!("cthash-build-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("assets")).unwrap();
+    let abc = dir.join("assets/2fa.key");
+    let large = dir.join("logo-v2.png");
+    let data: Vec<u8> = (0..200_000u32).map(|i| (i * 31 + 7) as u8).collect();
+    std::fs::write(&abc, b"abc").unwrap();
+    std::fs::write(&large, &data).unwrap();
+
+    let mut module = Vec::new();
+    write_digests(&mut module, &[&abc], &[HashKind::Md5, HashKind::Sha3_256]).unwrap();
+    let expected = format!(
+        "// @generated by cthash {}, do not edit.
+
+/// MD5 of {:?}.
+pub const _2FA_MD5: [u8; 16] = [
+    0x90, 0x01, 0x50, 0x98, 0x3c, 0xd2, 0x4f, 0xb0, 0xd6, 0x96, 0x3f, 0x7d, 0x28, 0xe1, 0x7f, 0x72,
+];
+
+/// SHA3-256 of {1:?}.
+pub const _2FA_SHA3_256: [u8; 32] = [
+    0x3a, 0x98, 0x5d, 0xa7, 0x4f, 0xe2, 0x25, 0xb2, 0x04, 0x5c, 0x17, 0x2d, 0x6b, 0xd3, 0x90, 0xbd,
+    0x85, 0x5f, 0x08, 0x6e, 0x3e, 0x9d, 0x52, 0x5b, 0x46, 0xbf, 0xe2, 0x45, 0x11, 0x43, 0x15, 0x32,
+];
+",
+        env!("CARGO_PKG_VERSION"),
+        abc
+    );
+    assert_eq!(String::from_utf8(module).unwrap(), expected);
+
+    // the constants hold the digests of the const functions
+    let out = dir.join("digests.rs");
+    emit_digests(&out, &[&large], &HashKind::ALL).unwrap();
+    let module = std::fs::read_to_string(&out).unwrap();
+    let bytes = |name: &str| -> Vec<u8> {
+        let start = module.find(&format!("pub const {name}:")).unwrap();
+        let body = &module[start..][module[start..].find("= [").unwrap() + 3..];
+        let body = &body[..body.find("];").unwrap()];
+        body.split(',')
+            .map(str::trim)
+            .filter(|b| !b.is_empty())
+            .map(|b| u8::from_str_radix(b.trim_start_matches("0x"), 16).unwrap())
+            .collect()
+    };
+    assert_eq!(bytes("LOGO_V2_SHA256"), cthash::sha2_256(&data));
+    assert_eq!(bytes("LOGO_V2_BLAKE2B"), cthash::blake2b::<64>(&data));
+    for kind in HashKind::ALL {
+        let name = format!(
+            "LOGO_V2_{}",
+            kind.name().to_uppercase().replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+        );
+        assert_eq!(bytes(&name), cthash::hash(kind, &data).as_bytes(), "{kind}");
+    }
+
+    // a line break in the path stays inside the doc comment
+    let evil = dir.join("evil\npub const X: u8 = 0;\n.bin");
+    std::fs::write(&evil, b"").unwrap();
+    let mut module = Vec::new();
+    write_digests(&mut module, &[&evil], &[HashKind::Md5]).unwrap();
+    let module = String::from_utf8(module).unwrap();
+    assert!(module.lines().all(|line| !line.starts_with("pub const X")), "{module}");
+    assert_eq!(module.lines().filter(|line| line.starts_with("pub const")).count(), 1);
+
+    let err = write_digests(Vec::new(), &[&abc, &abc], &[HashKind::Sha256]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    let err = write_digests(Vec::new(), &[dir.join("missing.bin")], &[HashKind::Sha256]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    assert!(err.to_string().contains("missing.bin"), "{err}");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "embedded-io")]
+#[test]
+fn embedded_io_adapters() {
+    use cthash::{DynHasher, HashKind, hash, hash_embedded_reader};
+    use embedded_io::Write;
+
+    let data: Vec<u8> = (0..1000u32).map(|i| (i * 31 + 7) as u8).collect();
+
+    let mut hasher = cthash::Sha3_512Hasher::new();
+    hasher.write_all(&data).unwrap();
+    assert_eq!(hasher.finalize(), cthash::sha3_512(&data));
+
+    let mut hasher = DynHasher::new(HashKind::Md5);
+    hasher.write_all(&data).unwrap();
+    assert_eq!(hasher.finalize(), hash(HashKind::Md5, &data));
+
+    assert_eq!(
+        hash_embedded_reader(HashKind::Sha1, &data[..]).unwrap(),
+        hash(HashKind::Sha1, &data)
+    );
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn cli() {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    const ABC_MD5: &str = "900150983cd24fb0d6963f7d28e17f72";
+    const HELLO_SHA256: &str = "5891b5b522d5df086d0ff0b110fbd9d21bb4fc7163af34d08286a2e846f6be03";
+
+    let dir = std::env::temp_dir().join(format!("cthash-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("a.txt"), b"abc").unwrap();
+    std::fs::write(dir.join("b c.txt"), b"hello\n").unwrap();
+    std::fs::write(dir.join("x\\y"), b"abc").unwrap();
+
+    let run = |bin: &std::path::Path, args: &[&str], stdin: &[u8]| {
+        let mut child = Command::new(bin)
+            .args(args)
+            .current_dir(&dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child.stdin.take().unwrap().write_all(stdin).unwrap();
+        let output = child.wait_with_output().unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        (output.status.code(), stdout, stderr)
+    };
+    let bin = std::path::Path::new(env!("CARGO_BIN_EXE_cthash"));
+    let cthash = |args: &[&str], stdin: &[u8]| run(bin, args, stdin);
+
+    // GNU, BSD and NUL-terminated output, file names escaped as coreutils does
+    assert_eq!(
+        cthash(&["a.txt", "b c.txt", "x\\y"], b""),
+        (
+            Some(0),
+            format!("{ABC_SHA256}  a.txt\n{HELLO_SHA256}  b c.txt\n\\{ABC_SHA256}  x\\\\y\n"),
+            String::new()
+        )
+    );
+    assert_eq!(cthash(&["-b", "-a", "md5", "a.txt"], b"").1, format!("{ABC_MD5} *a.txt\n"));
+    assert_eq!(
+        cthash(&["--tag", "--algorithm=md5", "a.txt"], b"").1,
+        format!("MD5 (a.txt) = {ABC_MD5}\n")
+    );
+    assert_eq!(
+        cthash(&["-a", "sha3-256", "--tag", "-"], b"abc").1,
+        format!("SHA3-256 (-) = {}\n", cthash::hash(cthash::HashKind::Sha3_256, b"abc"))
+    );
+    assert_eq!(
+        cthash(&["-a", "blake2b", "--tag"], b"abc").1,
+        format!("BLAKE2b (-) = {}\n", const_hex::encode(cthash::blake2b::<64>(b"abc")))
+    );
+    assert_eq!(cthash(&["-z", "x\\y"], b"").1, format!("{ABC_SHA256}  x\\y\0"));
+
+    let (code, stdout, stderr) = cthash(&["missing", "a.txt"], b"");
+    assert_eq!((code, stdout), (Some(1), format!("{ABC_SHA256}  a.txt\n")));
+    assert_eq!(stderr, "cthash: missing: No such file or directory\n");
+
+    let (code, _, stderr) = cthash(&["-a", "md6", "a.txt"], b"");
+    assert_eq!(code, Some(1));
+    assert!(stderr.starts_with("cthash: invalid algorithm 'md6'\n"), "{stderr}");
+    assert_eq!(cthash(&["--quiet", "a.txt"], b"").0, Some(1));
+    assert_eq!(cthash(&["-c", "--tag", "a.txt"], b"").0, Some(1));
+
+    // verification
+    let sums = format!("{ABC_SHA256}  a.txt\n{HELLO_SHA256} *b c.txt\n\\{ABC_SHA256}  x\\\\y\n");
+    assert_eq!(
+        cthash(&["-c"], sums.as_bytes()),
+        (Some(0), "a.txt: OK\nb c.txt: OK\n\\x\\\\y: OK\n".into(), String::new())
+    );
+    assert_eq!(
+        cthash(&["--check", "--quiet", "-"], sums.as_bytes()),
+        (Some(0), String::new(), String::new())
+    );
+
+    let sums = format!("{ABC_SHA256}  b c.txt\n# comment\n{HELLO_SHA256}  gone\ngarbage\n{ABC_SHA256}  a.txt\n");
+    let (code, stdout, stderr) = cthash(&["-c", "-w"], sums.as_bytes());
+    assert_eq!(code, Some(1));
+    assert_eq!(stdout, "b c.txt: FAILED\ngone: FAILED open or read\na.txt: OK\n");
+    assert_eq!(
+        stderr,
+        "cthash: gone: No such file or directory\n\
+         cthash: 'standard input': 4: improperly formatted SHA256 checksum line\n\
+         cthash: WARNING: 1 line is improperly formatted\n\
+         cthash: WARNING: 1 listed file could not be read\n\
+         cthash: WARNING: 1 computed checksum did NOT match\n"
+    );
+    assert_eq!(
+        cthash(&["-c", "--status"], sums.as_bytes()),
+        (Some(1), String::new(), "cthash: gone: No such file or directory\n".into())
+    );
+
+    // missing files and improperly formatted lines only fail on request
+    let sums = format!("{ABC_SHA256}  a.txt\n{ABC_SHA256}  gone\nnot a checksum\n");
+    assert_eq!(cthash(&["-c", "--ignore-missing"], sums.as_bytes()).0, Some(0));
+    assert_eq!(cthash(&["-c", "--ignore-missing", "--strict"], sums.as_bytes()).0, Some(1));
+    let (code, _, stderr) = cthash(&["-c", "--ignore-missing"], format!("{ABC_SHA256}  gone\n").as_bytes());
+    assert_eq!(
+        (code, stderr.as_str()),
+        (Some(1), "cthash: 'standard input': no file was verified\n")
+    );
+    let (code, _, stderr) = cthash(&["-c"], b"garbage\n");
+    assert_eq!(
+        (code, stderr.as_str()),
+        (Some(1), "cthash: 'standard input': no properly formatted checksum lines found\n")
+    );
+
+    // BSD lines of any algorithm unless one is chosen
+    let sums = format!("MD5 (a.txt) = {ABC_MD5}\nSHA256 (b c.txt) = {HELLO_SHA256}\n");
+    assert_eq!(
+        cthash(&["-c"], sums.as_bytes()),
+        (Some(0), "a.txt: OK\nb c.txt: OK\n".into(), String::new())
+    );
+    assert_eq!(cthash(&["-c", "-a", "md5"], sums.as_bytes()).1, "a.txt: OK\n");
+
+    // Rust module of digest constants, same as `cthash::build`
+    let (code, stdout, stderr) = cthash(&["emit-digests", "-a", "md5", "--algorithm=sha256", "a.txt", "b c.txt"], b"");
+    let mut module = Vec::new();
+    let kinds = [cthash::HashKind::Md5, cthash::HashKind::Sha256];
+    cthash::build::write_digests(&mut module, &["a.txt", "b c.txt"].map(|name| dir.join(name)), &kinds).unwrap();
+    let module = String::from_utf8(module)
+        .unwrap()
+        .replace(&format!("{}{}", dir.display(), std::path::MAIN_SEPARATOR), "");
+    assert_eq!((code, stdout, stderr), (Some(0), module, String::new()));
+    assert!(
+        cthash(&["emit-digests", "a.txt"], b"")
+            .1
+            .contains("pub const A_SHA256: [u8; 32] = [\n    0xba, 0x78,")
+    );
+    cthash(&["emit-digests", "-o", "digests.rs", "a.txt"], b"");
+    assert!(std::fs::read_to_string(dir.join("digests.rs")).unwrap().contains("A_SHA256"));
+    let (code, stdout, stderr) = cthash(&["emit-digests", "-o", "digests.rs", "a.txt", "missing"], b"");
+    assert_eq!((code, stdout.as_str()), (Some(1), ""));
+    assert!(stderr.starts_with("cthash: missing: "), "{stderr}");
+    assert!(std::fs::read_to_string(dir.join("digests.rs")).unwrap().contains("A_SHA256"));
+    assert_eq!(cthash(&["emit-digests"], b"").0, Some(1));
+
+    // invoked through a link named after the tool it replaces
+    #[cfg(unix)]
+    {
+        let link = |name: &str| {
+            let path = dir.join(name);
+            let _ = std::fs::remove_file(&path);
+            std::os::unix::fs::symlink(bin, &path).unwrap();
+            path
+        };
+        assert_eq!(run(&link("md5sum"), &["a.txt"], b"").1, format!("{ABC_MD5}  a.txt\n"));
+        assert_eq!(
+            run(&link("b2sum"), &[], b"abc").1,
+            format!("{}  -\n", const_hex::encode(cthash::blake2b::<64>(b"abc")))
+        );
+        let sha3sum = link("sha3sum");
+        assert_eq!(
+            run(&sha3sum, &["-a", "256"], b"abc").1,
+            format!("{}  -\n", const_hex::encode(cthash::sha3_256(b"abc")))
+        );
+        assert_eq!(
+            run(&sha3sum, &[], b"abc").1,
+            format!("{}  -\n", const_hex::encode(cthash::sha3_224(b"abc")))
+        );
+        let md5sum = link("md5sum");
+        assert_eq!(
+            run(&md5sum, &["-c"], format!("SHA256 (a.txt) = {ABC_SHA256}\n").as_bytes()).0,
+            Some(1)
+        );
+        assert_eq!(run(&md5sum, &["-a", "sha1"], b"").0, Some(1));
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}